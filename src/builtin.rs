@@ -0,0 +1,232 @@
+use crate::validation_adapters::{at_least::AtLeastIter, at_most::AtMostIter, ensure::EnsureIter};
+
+/// A ready-made error type for quick validations.
+///
+/// The adapters in this crate are generic over the error type, and expect
+/// a factory that builds it. For simple cases (scripts, tests, prototypes)
+/// it is often easier to use `BuiltinErr` together with the methods of the
+/// [`Builtin`] trait, which wire up the factories for you.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuiltinErr<T> {
+    /// An element was found after the maximal count was reached,
+    /// see [`at_most_builtin`](Builtin::at_most_builtin).
+    TooMany { index: usize, element: T },
+    /// The iteration ended before the minimal count was reached,
+    /// see [`at_least_builtin`](Builtin::at_least_builtin).
+    TooFew { len: usize },
+    /// An element was outside the allowed bounds,
+    /// see [`between_builtin`](Builtin::between_builtin).
+    OutOfBounds { element: T },
+    /// An element failed a test, see [`ensure_builtin`](Builtin::ensure_builtin).
+    Invalid { index: usize, element: T },
+}
+
+type IndexedFactory<T> = fn(usize, T) -> BuiltinErr<T>;
+type LenFactory<T> = fn(usize) -> BuiltinErr<T>;
+
+pub trait Builtin<T>: Iterator<Item = Result<T, BuiltinErr<T>>> + Sized {
+    /// Same as [`at_most`](crate::AtMost::at_most), failing with
+    /// [`BuiltinErr::TooMany`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Builtin, BuiltinErr};
+    ///
+    /// let mut iter = (0..3).map(Ok).at_most_builtin(2);
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(BuiltinErr::TooMany { index: 2, element: 2 }))
+    /// );
+    /// ```
+    fn at_most_builtin(
+        self,
+        max_count: usize,
+    ) -> AtMostIter<Self, T, BuiltinErr<T>, IndexedFactory<T>> {
+        AtMostIter::new(self, max_count, |index, element| BuiltinErr::TooMany {
+            index,
+            element,
+        })
+    }
+
+    /// Same as [`at_least`](crate::AtLeast::at_least), failing with
+    /// [`BuiltinErr::TooFew`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Builtin, BuiltinErr};
+    ///
+    /// let mut iter = (0..1).map(Ok).at_least_builtin(2);
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Err(BuiltinErr::TooFew { len: 1 })));
+    /// ```
+    fn at_least_builtin(
+        self,
+        min_count: usize,
+    ) -> AtLeastIter<Self, T, BuiltinErr<T>, LenFactory<T>> {
+        AtLeastIter::new(self, min_count, |len| BuiltinErr::TooFew { len })
+    }
+
+    /// Same as [`ensure`](crate::Ensure::ensure), failing with
+    /// [`BuiltinErr::Invalid`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Builtin, BuiltinErr};
+    ///
+    /// let mut iter = (0..2).map(Ok).ensure_builtin(|i| i % 2 == 0);
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(BuiltinErr::Invalid { index: 1, element: 1 }))
+    /// );
+    /// ```
+    fn ensure_builtin<F>(self, test: F) -> EnsureIter<Self, T, BuiltinErr<T>, F, IndexedFactory<T>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        EnsureIter::new(self, test, |index, element| BuiltinErr::Invalid {
+            index,
+            element,
+        })
+    }
+
+    /// Fails any element outside of the inclusive range `[lower, upper]`
+    /// with [`BuiltinErr::OutOfBounds`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Builtin, BuiltinErr};
+    ///
+    /// let mut iter = [1, 5, 3].into_iter().map(Ok).between_builtin(1, 3);
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err(BuiltinErr::OutOfBounds { element: 5 })));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    fn between_builtin(
+        self,
+        lower: T,
+        upper: T,
+    ) -> EnsureIter<Self, T, BuiltinErr<T>, impl Fn(&T) -> bool, IndexedFactory<T>>
+    where
+        T: PartialOrd,
+    {
+        EnsureIter::new(
+            self,
+            move |element| lower <= *element && *element <= upper,
+            |_, element| BuiltinErr::OutOfBounds { element },
+        )
+    }
+}
+
+impl<I, T> Builtin<T> for I where I: Iterator<Item = Result<T, BuiltinErr<T>>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Builtin, BuiltinErr};
+
+    #[test]
+    fn test_builtin_too_many() {
+        let results = (0..4).map(Ok).at_most_builtin(2).collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(1),
+                Err(BuiltinErr::TooMany {
+                    index: 2,
+                    element: 2
+                }),
+                Err(BuiltinErr::TooMany {
+                    index: 3,
+                    element: 3
+                })
+            ]
+        )
+    }
+
+    #[test]
+    fn test_builtin_too_few() {
+        let results = (0..2).map(Ok).at_least_builtin(3).collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(0), Ok(1), Err(BuiltinErr::TooFew { len: 2 })]
+        )
+    }
+
+    #[test]
+    fn test_builtin_out_of_bounds() {
+        let results = [-1, 0, 10, 11]
+            .into_iter()
+            .map(Ok)
+            .between_builtin(0, 10)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(BuiltinErr::OutOfBounds { element: -1 }),
+                Ok(0),
+                Ok(10),
+                Err(BuiltinErr::OutOfBounds { element: 11 })
+            ]
+        )
+    }
+
+    #[test]
+    fn test_builtin_invalid() {
+        let results = (0..3)
+            .map(Ok)
+            .ensure_builtin(|i| i % 2 == 0)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(BuiltinErr::Invalid {
+                    index: 1,
+                    element: 1
+                }),
+                Ok(2)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_builtins_chain() {
+        let results = (0..5)
+            .map(Ok)
+            .ensure_builtin(|i| *i != 1)
+            .at_most_builtin(2)
+            .at_least_builtin(10)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(BuiltinErr::Invalid {
+                    index: 1,
+                    element: 1
+                }),
+                Ok(2),
+                Err(BuiltinErr::TooMany {
+                    index: 3,
+                    element: 3
+                }),
+                Err(BuiltinErr::TooMany {
+                    index: 4,
+                    element: 4
+                }),
+                Err(BuiltinErr::TooFew { len: 5 })
+            ]
+        )
+    }
+}
@@ -0,0 +1,92 @@
+use std::{marker::PhantomData, str::Split};
+
+#[derive(Debug, Clone)]
+pub struct FieldsIter<'a, E> {
+    split: Split<'a, char>,
+    _err: PhantomData<E>,
+}
+
+impl<'a, E> Iterator for FieldsIter<'a, E> {
+    type Item = Result<&'a str, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.split.next().map(|field| Ok(field.trim()))
+    }
+}
+
+/// Splits a delimited line into a validation iterator over its trimmed fields.
+///
+/// `fields(line, delimiter)` is a starting point for validating a single
+/// row of delimiter separated values (such as CSV). Every field is
+/// wrapped in `Ok(field)`, with surrounding whitespace removed, so that the
+/// result can be chained directly into the validation adapters.
+///
+/// Splitting follows [`str::split`]: empty fields are yielded as `Ok("")`,
+/// a trailing delimiter produces a trailing empty field, and an empty line
+/// produces a single empty field. Use [`ensure`](crate::Ensure::ensure) to
+/// reject empty fields if needed.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use validiter::fields;
+///
+/// let mut iter = fields::<()>("1.2, ,3.0,", ',');
+///
+/// assert_eq!(iter.next(), Some(Ok("1.2")));
+/// assert_eq!(iter.next(), Some(Ok("")));
+/// assert_eq!(iter.next(), Some(Ok("3.0")));
+/// assert_eq!(iter.next(), Some(Ok(""))); // trailing delimiter
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// Validating the number of fields in a row:
+/// ```
+/// use validiter::{fields, AtMost, Ensure};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum RowErr {
+///     EmptyField(usize),
+///     TooManyFields,
+/// }
+///
+/// let row = fields("a, b, , d", ',')
+///     .ensure(|field| !field.is_empty(), |i, _| RowErr::EmptyField(i))
+///     .at_most(3, |_, _| RowErr::TooManyFields)
+///     .collect::<Result<Vec<_>, _>>();
+///
+/// assert_eq!(row, Err(RowErr::EmptyField(2)));
+/// ```
+pub fn fields<E>(line: &str, delimiter: char) -> FieldsIter<'_, E> {
+    FieldsIter {
+        split: line.split(delimiter),
+        _err: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fields;
+
+    #[test]
+    fn test_fields_are_trimmed() {
+        let results = fields::<()>(" a,b ,  c  ", ',').collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok("a"), Ok("b"), Ok("c")])
+    }
+
+    #[test]
+    fn test_fields_trailing_delimiter() {
+        let results = fields::<()>("a;b;", ';').collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok("a"), Ok("b"), Ok("")])
+    }
+
+    #[test]
+    fn test_fields_empty_fields() {
+        let results = fields::<()>(",,", ',').collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(""), Ok(""), Ok("")]);
+
+        let results = fields::<()>("", ',').collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok("")])
+    }
+}
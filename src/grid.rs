@@ -0,0 +1,172 @@
+//! Validation of grids whose rows and columns hold distinct values.
+//!
+//! The [`validate_grid`] function collects an iteration of rows into a
+//! `Vec<Vec<T>>`, while making sure no value repeats within a row or within
+//! a column, as in latin squares and sudoku-like puzzles.
+
+use std::hash::Hash;
+
+use crate::{ConstOver, UniqueBy};
+
+/// The error returned from [`validate_grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridErr<T, E> {
+    /// The row at index `row` has `len` cells, when the first row had
+    /// `expected` cells.
+    Jagged {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// The cell at `row`, `column` repeats a value which appeared earlier
+    /// in its row.
+    RowDuplicate { row: usize, column: usize, value: T },
+    /// The cell at `row`, `column` repeats a value which appeared earlier
+    /// in its column.
+    ColumnDuplicate { row: usize, column: usize, value: T },
+    /// An error already present in a row.
+    Cell(E),
+}
+
+/// Collects an iteration of rows into a grid, validating that every row
+/// and every column holds distinct values.
+///
+/// `validate_grid(rows)` takes an iteration of rows, where every row is an
+/// iteration of `Result<T, E>` cells. Each row is checked with
+/// [`unique_by`](crate::UniqueBy::unique_by) as it is collected, and once
+/// all rows are collected, each column is checked the same way. The first
+/// error encountered is returned as a [`GridErr`]:
+/// 1. [`GridErr::Cell`] for errors already in the rows, and
+///    [`GridErr::RowDuplicate`] for repeated values in a row, in the order
+///    they appear.
+/// 2. [`GridErr::Jagged`] if a row is not as long as the first row.
+/// 3. [`GridErr::ColumnDuplicate`] for repeated values in a column, checking
+///    the columns from left to right.
+///
+/// A grid without rows is valid. The grid does not have to be square.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use validiter::grid::{validate_grid, GridErr};
+///
+/// let square = vec![vec![1, 2, 3], vec![2, 3, 1], vec![3, 1, 2]];
+/// let rows = square.iter().map(|row| row.iter().map(|cell| Ok::<_, ()>(*cell)));
+/// assert_eq!(validate_grid(rows), Ok(square.clone()));
+///
+/// let not_latin = vec![vec![1, 2], vec![1, 3]];
+/// let rows = not_latin.iter().map(|row| row.iter().map(|cell| Ok::<_, ()>(*cell)));
+/// assert_eq!(
+///     validate_grid(rows),
+///     Err(GridErr::ColumnDuplicate { row: 1, column: 0, value: 1 })
+/// );
+/// ```
+pub fn validate_grid<R, T, E>(rows: R) -> Result<Vec<Vec<T>>, GridErr<T, E>>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator<Item = Result<T, E>>,
+    T: Eq + Hash + Clone,
+{
+    let grid = rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.into_iter()
+                .map(|cell| cell.map_err(GridErr::Cell))
+                .unique_by(
+                    |cell| cell.clone(),
+                    |j, value, _| GridErr::RowDuplicate {
+                        row: i,
+                        column: j,
+                        value,
+                    },
+                )
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .const_over(
+            |row| row.len(),
+            |row, _, len, expected| GridErr::Jagged {
+                row,
+                len,
+                expected: *expected,
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let width = grid.first().map_or(0, |row| row.len());
+    for j in 0..width {
+        grid.iter()
+            .map(|row| Ok(&row[j]))
+            .unique_by(
+                |cell| *cell,
+                |i, value, _| GridErr::ColumnDuplicate {
+                    row: i,
+                    column: j,
+                    value: value.clone(),
+                },
+            )
+            .try_for_each(|cell| cell.map(drop))?;
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_grid, GridErr};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Corrupt,
+    }
+
+    fn ok_rows(rows: Vec<Vec<u8>>) -> impl Iterator<Item = Vec<Result<u8, TestErr>>> {
+        rows.into_iter()
+            .map(|row| row.into_iter().map(Ok).collect())
+    }
+
+    #[test]
+    fn test_validate_grid_valid() {
+        let rows = vec![vec![1, 2, 3, 4], vec![2, 1, 4, 3], vec![3, 4, 1, 2]];
+        assert_eq!(validate_grid(ok_rows(rows.clone())), Ok(rows))
+    }
+
+    #[test]
+    fn test_validate_grid_column_duplicate() {
+        let grid = validate_grid(ok_rows(vec![vec![1, 2, 3], vec![2, 3, 1], vec![3, 2, 1]]));
+        assert_eq!(
+            grid,
+            Err(GridErr::ColumnDuplicate {
+                row: 2,
+                column: 1,
+                value: 2
+            })
+        )
+    }
+
+    #[test]
+    fn test_validate_grid_row_errors() {
+        let grid = validate_grid(ok_rows(vec![vec![1, 2], vec![2, 2]]));
+        assert_eq!(
+            grid,
+            Err(GridErr::RowDuplicate {
+                row: 1,
+                column: 1,
+                value: 2
+            })
+        );
+
+        let grid = validate_grid(ok_rows(vec![vec![1, 2], vec![2]]));
+        assert_eq!(
+            grid,
+            Err(GridErr::Jagged {
+                row: 1,
+                len: 1,
+                expected: 2
+            })
+        );
+
+        let grid = validate_grid(vec![vec![Ok(1)], vec![Err(TestErr::Corrupt)]]);
+        assert_eq!(grid, Err(GridErr::Cell(TestErr::Corrupt)))
+    }
+}
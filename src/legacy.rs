@@ -0,0 +1,274 @@
+//! Compatibility layer for code written against the enum based error API.
+//!
+//! Earlier versions of this crate used a crate-provided [`ValidErr`] error
+//! type, with adapters taking a description string instead of an error
+//! factory. The methods of the [`Legacy`] trait mimic these adapters on top
+//! of the current factory based ones, so such code can be migrated
+//! gradually.
+
+use crate::validation_adapters::{
+    at_least::AtLeastIter, at_most::AtMostIter, const_over::ConstOverIter, ensure::EnsureIter,
+    look_back::LookBackIter,
+};
+
+/// The legacy validation error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidErr<E> {
+    /// An error which is not related to any specific element.
+    Description(String),
+    /// An error caused by `element`.
+    WithElement(E, String),
+}
+
+pub trait Legacy<T>: Iterator<Item = Result<T, ValidErr<T>>> + Sized {
+    /// Same as [`ensure`](crate::Ensure::ensure), failing with
+    /// `ValidErr::WithElement(element, desc)`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::legacy::{Legacy, ValidErr};
+    ///
+    /// let mut iter = (0..2).map(Ok).ensure_desc(|i| *i == 0, "not zero");
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(ValidErr::WithElement(1, "not zero".to_string())))
+    /// );
+    /// ```
+    fn ensure_desc<F>(
+        self,
+        test: F,
+        desc: &str,
+    ) -> EnsureIter<Self, T, ValidErr<T>, F, impl Fn(usize, T) -> ValidErr<T>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let desc = desc.to_string();
+        EnsureIter::new(self, test, move |_, element| {
+            ValidErr::WithElement(element, desc.clone())
+        })
+    }
+
+    /// Same as [`at_most`](crate::AtMost::at_most), failing with
+    /// `ValidErr::WithElement(element, desc)`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::legacy::{Legacy, ValidErr};
+    ///
+    /// let mut iter = (0..2).map(Ok).at_most_desc(1, "too many");
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(ValidErr::WithElement(1, "too many".to_string())))
+    /// );
+    /// ```
+    fn at_most_desc(
+        self,
+        max_count: usize,
+        desc: &str,
+    ) -> AtMostIter<Self, T, ValidErr<T>, impl Fn(usize, T) -> ValidErr<T>> {
+        let desc = desc.to_string();
+        AtMostIter::new(self, max_count, move |_, element| {
+            ValidErr::WithElement(element, desc.clone())
+        })
+    }
+
+    /// Same as [`at_least`](crate::AtLeast::at_least), failing with
+    /// `ValidErr::Description(desc)`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::legacy::{Legacy, ValidErr};
+    ///
+    /// let mut iter = (0..1).map(Ok).at_least_desc(2, "too few");
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(ValidErr::Description("too few".to_string())))
+    /// );
+    /// ```
+    fn at_least_desc(
+        self,
+        min_count: usize,
+        desc: &str,
+    ) -> AtLeastIter<Self, T, ValidErr<T>, impl Fn(usize) -> ValidErr<T>> {
+        let desc = desc.to_string();
+        AtLeastIter::new(self, min_count, move |_| {
+            ValidErr::Description(desc.clone())
+        })
+    }
+
+    /// Same as [`const_over`](crate::ConstOver::const_over), failing with
+    /// `ValidErr::WithElement(element, desc)`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::legacy::{Legacy, ValidErr};
+    ///
+    /// let mut iter = [2, 4, 5].into_iter().map(Ok).const_over_desc(|i| i % 2, "parity changed");
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(ValidErr::WithElement(5, "parity changed".to_string())))
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn const_over_desc<A, M>(
+        self,
+        extractor: M,
+        desc: &str,
+    ) -> ConstOverIter<Self, T, ValidErr<T>, A, M, impl Fn(usize, T, A, &A) -> ValidErr<T>>
+    where
+        A: PartialEq,
+        M: Fn(&T) -> A,
+    {
+        let desc = desc.to_string();
+        ConstOverIter::new(self, extractor, move |_, element, _, _: &A| {
+            ValidErr::WithElement(element, desc.clone())
+        })
+    }
+
+    /// Same as [`look_back`](crate::LookBack::look_back), failing with
+    /// `ValidErr::WithElement(element, desc)`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::legacy::{Legacy, ValidErr};
+    ///
+    /// let mut iter = [1, 2, 0]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .look_back_desc(1, |i| *i, |i, prev| i > prev, "not increasing");
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(ValidErr::WithElement(0, "not increasing".to_string())))
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn look_back_desc<A, M, F>(
+        self,
+        steps: usize,
+        extractor: M,
+        test: F,
+        desc: &str,
+    ) -> LookBackIter<Self, T, ValidErr<T>, A, M, F, impl Fn(usize, T, &A) -> ValidErr<T>>
+    where
+        M: Fn(&T) -> A,
+        F: Fn(&T, &A) -> bool,
+    {
+        let desc = desc.to_string();
+        LookBackIter::new(self, steps, extractor, test, move |_, element, _: &A| {
+            ValidErr::WithElement(element, desc.clone())
+        })
+    }
+}
+
+impl<I, T> Legacy<T> for I where I: Iterator<Item = Result<T, ValidErr<T>>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Legacy, ValidErr};
+    use crate::Ensure;
+
+    fn with_element<T>(element: T, desc: &str) -> ValidErr<T> {
+        ValidErr::WithElement(element, desc.to_string())
+    }
+
+    #[test]
+    fn test_legacy_ensure_desc() {
+        let results = (0..3)
+            .map(Ok)
+            .ensure_desc(|i| i % 2 == 0, "odd")
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Err(with_element(1, "odd")), Ok(2)])
+    }
+
+    #[test]
+    fn test_legacy_at_most_desc() {
+        let results = (0..3)
+            .map(Ok)
+            .at_most_desc(1, "too many")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(with_element(1, "too many")),
+                Err(with_element(2, "too many"))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_legacy_at_least_desc() {
+        let results = (0..1)
+            .map(Ok)
+            .at_least_desc(2, "too few")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(0), Err(ValidErr::Description("too few".to_string()))]
+        )
+    }
+
+    #[test]
+    fn test_legacy_const_over_desc() {
+        let results = ["a", "b", "cc"]
+            .into_iter()
+            .map(Ok)
+            .const_over_desc(|s| s.len(), "length changed")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok("a"), Ok("b"), Err(with_element("cc", "length changed"))]
+        )
+    }
+
+    #[test]
+    fn test_legacy_look_back_desc() {
+        let results = [0, 1, 0, 0]
+            .into_iter()
+            .map(Ok)
+            .look_back_desc(2, |i| *i, |i, prev| i == prev, "broken cycle")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(0), Ok(1), Ok(0), Err(with_element(0, "broken cycle"))]
+        )
+    }
+
+    #[test]
+    fn test_legacy_mixes_with_factory_adapters() {
+        let results = (0..3)
+            .map(Ok)
+            .ensure(
+                |i| *i != 0,
+                |_, i| ValidErr::WithElement(i, "zero".to_string()),
+            )
+            .at_least_desc(5, "too few")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(with_element(0, "zero")),
+                Ok(1),
+                Ok(2),
+                Err(ValidErr::Description("too few".to_string()))
+            ]
+        )
+    }
+}
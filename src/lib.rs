@@ -4,9 +4,214 @@ pub(crate) mod validation_adapters {
     pub(crate) mod const_over;
     pub(crate) mod look_back;
     pub(crate) mod ensure;
+    pub(crate) mod validation_peekable;
+    pub(crate) mod strictly_increasing_unique;
+    pub(crate) mod covers;
+    pub(crate) mod finite;
+    pub(crate) mod in_range;
+    pub(crate) mod unique_within;
+    pub(crate) mod or_default;
+    pub(crate) mod len_between;
+    pub(crate) mod increasing_by_at_least;
+    pub(crate) mod within_rolling_mean;
+    pub(crate) mod not_in_set;
+    pub(crate) mod retry;
+    pub(crate) mod tee_errors;
+    pub(crate) mod char_len;
+    pub(crate) mod deps_precede;
+    pub(crate) mod skip_while_err;
+    pub(crate) mod must_cover;
+    pub(crate) mod look_back_n;
+    pub(crate) mod ensure_keeping_err;
+    pub(crate) mod alternating;
+    pub(crate) mod prefix_sum_nonnegative;
+    #[cfg(feature = "fallible-iterator")]
+    pub(crate) mod into_fallible;
+    pub(crate) mod index_offset;
+    pub(crate) mod ensure_sampled;
+    #[cfg(feature = "jsonschema")]
+    pub(crate) mod ensure_json_schema;
+    pub(crate) mod chunks_sorted;
+    pub(crate) mod ensure_referential_integrity;
+    pub(crate) mod rate_limit;
+    pub(crate) mod explain;
+    pub(crate) mod flatten_validation;
+    pub(crate) mod no_gaps;
+    pub(crate) mod round_robin_order;
+    pub(crate) mod on_error_control;
+    pub(crate) mod expect_header;
+    pub(crate) mod increasing_with_resets;
+    pub(crate) mod ascii_only;
+    pub(crate) mod depth_step_at_most;
+    pub(crate) mod count_limited;
+    pub(crate) mod const_over_window;
+    pub(crate) mod at_least_msg;
+    pub(crate) mod len_from_header;
+    pub(crate) mod within_tolerance_of_first;
+    pub(crate) mod validate_groups;
+    pub(crate) mod track_positions;
+    pub(crate) mod checkpoint_every;
+    pub(crate) mod const_case;
+    #[cfg(feature = "regex")]
+    pub(crate) mod matches_regex;
+    pub(crate) mod unique_by;
+    pub(crate) mod and_then_validate;
+    pub(crate) mod stop_at_first_error;
+    pub(crate) mod sorted_lexicographic;
+    pub(crate) mod flags_subset;
+    pub(crate) mod unique_within_groups;
+    pub(crate) mod alternating_sign;
+    pub(crate) mod non_overlapping;
+    pub(crate) mod fixed_width_fields;
+    pub(crate) mod require_keys;
+    pub(crate) mod prefix_free;
+    pub(crate) mod ensure_in_map;
+    pub(crate) mod within_rolling_median;
+    pub(crate) mod parses_as;
+    pub(crate) mod ensure_memoized;
+    pub(crate) mod increasing_with_tolerance;
+    pub(crate) mod no_cycles;
+    pub(crate) mod valid_transitions;
+    pub(crate) mod matched_markers;
+    pub(crate) mod max_decimal_places;
+    pub(crate) mod ensure_shared;
+    pub(crate) mod ensure_by_version;
+    pub(crate) mod try_validate;
+    pub(crate) mod tlv_lengths;
+    pub(crate) mod sample_without_replacement;
+    pub(crate) mod strings_sorted;
+    pub(crate) mod group_sum_between;
+    pub(crate) mod validate_eager;
+    pub(crate) mod trimmed;
+    pub(crate) mod queue_depth_within;
+    #[cfg(feature = "serde_json")]
+    pub(crate) mod const_json_type;
+    pub(crate) mod with_deadline;
 }
+pub(crate) mod validation_terminals {
+    pub(crate) mod split_at_first_error;
+    pub(crate) mod drain_valid_into;
+    pub(crate) mod verify_checksum;
+    pub(crate) mod group_into_btree;
+    pub(crate) mod counts;
+    pub(crate) mod collect_until_error;
+    pub(crate) mod assert_within_std_dev;
+    pub(crate) mod collect_with_errors;
+    pub(crate) mod assert_sum_equals;
+    pub(crate) mod send_all;
+    pub(crate) mod assert_sum_within;
+    pub(crate) mod collect_errors_by;
+    pub(crate) mod validate_all;
+    pub(crate) mod import_summary;
+}
+pub(crate) mod builtin;
+pub mod legacy;
+pub mod matrix;
+pub mod grid;
+pub(crate) mod fields;
+pub(crate) mod zip_validate;
 pub use validation_adapters::ensure::Ensure;
 pub use validation_adapters::at_least::AtLeast;
 pub use validation_adapters::at_most::AtMost;
 pub use validation_adapters::const_over::ConstOver;
 pub use validation_adapters::look_back::LookBack;
+pub use builtin::{Builtin, BuiltinErr};
+pub use validation_adapters::validation_peekable::ValidationPeekable;
+pub use validation_adapters::strictly_increasing_unique::{IncreaseViolation, StrictlyIncreasingUnique};
+pub use validation_adapters::covers::{Covers, MissedBound};
+pub use validation_terminals::split_at_first_error::SplitAtFirstError;
+pub use fields::fields;
+pub use validation_adapters::finite::Finite;
+pub use validation_terminals::drain_valid_into::DrainValidInto;
+pub use validation_adapters::in_range::InRange;
+pub use validation_adapters::unique_within::UniqueWithin;
+pub use validation_adapters::or_default::OrDefault;
+pub use validation_terminals::verify_checksum::VerifyChecksum;
+pub use validation_adapters::len_between::LenBetween;
+pub use zip_validate::zip_validate;
+pub use validation_terminals::group_into_btree::GroupIntoBTree;
+pub use validation_adapters::increasing_by_at_least::IncreasingByAtLeast;
+pub use validation_adapters::within_rolling_mean::WithinRollingMean;
+pub use validation_terminals::counts::{Counts, ValidationCounts};
+pub use validation_adapters::not_in_set::NotInSet;
+pub use validation_adapters::retry::Retry;
+pub use validation_adapters::tee_errors::TeeErrors;
+pub use validation_adapters::char_len::CharLen;
+pub use validation_adapters::deps_precede::DepsPrecede;
+pub use validation_adapters::skip_while_err::SkipWhileErr;
+pub use validation_adapters::must_cover::MustCover;
+pub use validation_adapters::look_back_n::LookBackN;
+pub use validation_adapters::ensure_keeping_err::EnsureKeepingErr;
+pub use validation_adapters::alternating::Alternating;
+pub use validation_adapters::prefix_sum_nonnegative::PrefixSumNonnegative;
+#[cfg(feature = "fallible-iterator")]
+pub use validation_adapters::into_fallible::IntoFallible;
+pub use validation_adapters::index_offset::WithIndexOffset;
+pub use validation_adapters::ensure_sampled::EnsureSampled;
+pub use validation_terminals::collect_until_error::CollectUntilError;
+#[cfg(feature = "jsonschema")]
+pub use validation_adapters::ensure_json_schema::EnsureJsonSchema;
+pub use validation_adapters::chunks_sorted::ChunksSorted;
+pub use validation_adapters::ensure_referential_integrity::EnsureReferentialIntegrity;
+pub use validation_adapters::rate_limit::RateLimit;
+pub use validation_adapters::explain::{Explain, Explainer, Named};
+pub use validation_adapters::flatten_validation::FlattenValidation;
+pub use validation_adapters::no_gaps::NoGaps;
+pub use validation_terminals::assert_within_std_dev::{AssertWithinStdDev, StatErr};
+pub use validation_adapters::round_robin_order::RoundRobinOrder;
+pub use validation_adapters::on_error_control::{ErrorControl, OnErrorControl};
+pub use validation_adapters::expect_header::ExpectHeader;
+pub use validation_adapters::increasing_with_resets::IncreasingWithResets;
+pub use validation_terminals::collect_with_errors::CollectWithErrors;
+pub use validation_adapters::ascii_only::AsciiOnly;
+pub use validation_adapters::depth_step_at_most::DepthStepAtMost;
+pub use validation_adapters::count_limited::CountLimited;
+pub use validation_terminals::assert_sum_equals::AssertSumEquals;
+pub use validation_adapters::const_over_window::ConstOverWindow;
+pub use validation_adapters::at_least_msg::AtLeastMsg;
+pub use validation_adapters::len_from_header::LenFromHeader;
+pub use validation_adapters::within_tolerance_of_first::WithinToleranceOfFirst;
+pub use validation_adapters::validate_groups::ValidateGroups;
+pub use validation_adapters::track_positions::TrackPositions;
+pub use validation_adapters::checkpoint_every::CheckpointEvery;
+pub use validation_adapters::const_case::{CaseClass, ConstCase};
+#[cfg(feature = "regex")]
+pub use validation_adapters::matches_regex::MatchesRegex;
+pub use validation_adapters::unique_by::UniqueBy;
+pub use validation_adapters::and_then_validate::AndThenValidate;
+pub use validation_adapters::stop_at_first_error::StopAtFirstError;
+pub use validation_adapters::sorted_lexicographic::SortedLexicographic;
+pub use validation_adapters::flags_subset::FlagsSubset;
+pub use validation_adapters::unique_within_groups::UniqueWithinGroups;
+pub use validation_terminals::send_all::SendAll;
+pub use validation_terminals::assert_sum_within::AssertSumWithin;
+pub use validation_adapters::alternating_sign::AlternatingSign;
+pub use validation_adapters::non_overlapping::NonOverlapping;
+pub use validation_terminals::collect_errors_by::CollectErrorsBy;
+pub use validation_adapters::fixed_width_fields::FixedWidthFields;
+pub use validation_adapters::require_keys::RequireKeys;
+pub use validation_terminals::validate_all::ValidateAll;
+pub use validation_adapters::prefix_free::PrefixFree;
+pub use validation_adapters::ensure_in_map::EnsureInMap;
+pub use validation_adapters::within_rolling_median::WithinRollingMedian;
+pub use validation_adapters::parses_as::ParsesAs;
+pub use validation_adapters::ensure_memoized::EnsureMemoized;
+pub use validation_adapters::increasing_with_tolerance::IncreasingWithTolerance;
+pub use validation_adapters::no_cycles::NoCycles;
+pub use validation_adapters::valid_transitions::ValidTransitions;
+pub use validation_terminals::import_summary::{ImportSummarize, ImportSummary};
+pub use validation_adapters::matched_markers::{MarkerKind, MatchedMarkers};
+pub use validation_adapters::max_decimal_places::MaxDecimalPlaces;
+pub use validation_adapters::ensure_shared::{EnsureShared, SharedValidator};
+pub use validation_adapters::ensure_by_version::{EnsureByVersion, VersionRules};
+pub use validation_adapters::try_validate::TryValidate;
+pub use validation_adapters::tlv_lengths::TlvLengths;
+pub use validation_adapters::sample_without_replacement::{SampleFailure, SampleWithoutReplacement};
+pub use validation_adapters::strings_sorted::StringsSorted;
+pub use validation_adapters::group_sum_between::GroupSumBetween;
+pub use validation_adapters::validate_eager::ValidateEager;
+pub use validation_adapters::trimmed::Trimmed;
+pub use validation_adapters::queue_depth_within::QueueDepthWithin;
+#[cfg(feature = "serde_json")]
+pub use validation_adapters::const_json_type::ConstJsonType;
+pub use validation_adapters::with_deadline::WithDeadline;
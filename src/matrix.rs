@@ -0,0 +1,179 @@
+//! Validation of two dimensional data.
+//!
+//! The [`validate_matrix`] function collects an iteration of rows into a
+//! `Vec<Vec<T>>`, while making sure the result is a valid matrix: it has at
+//! least one row and one column, all rows have the same length, and every
+//! cell passes a test.
+
+use crate::{AtLeast, ConstOver, Ensure};
+
+/// The error returned from [`validate_matrix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixErr<E> {
+    /// The matrix has no rows.
+    NoRows,
+    /// The row at the given index has no columns.
+    NoColumns(usize),
+    /// The row at index `row` has `len` cells, when the first row had
+    /// `expected` cells.
+    Jagged {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// A cell error, either returned from the cell factory or already
+    /// present in a row.
+    Cell(E),
+}
+
+/// Collects an iteration of rows into a matrix, validating its shape
+/// and its cells.
+///
+/// `validate_matrix(rows, test, factory)` takes an iteration of rows,
+/// where every row is an iteration of `Result<T, E>` cells. `test` is
+/// applied to every cell wrapped in `Ok`, and if it fails, `factory` is
+/// called on the row index, the column index and the cell. The first error
+/// encountered is returned as a [`MatrixErr`]:
+/// 1. [`MatrixErr::Cell`] for errors from `factory`, or errors already
+///    in the rows.
+/// 2. [`MatrixErr::NoColumns`] if a row is empty.
+/// 3. [`MatrixErr::NoRows`] if there are no rows at all.
+/// 4. [`MatrixErr::Jagged`] if a row is not as long as the first row.
+///
+/// Rows are validated in order, so an error in a row is returned before
+/// checking that the row has the right length.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use validiter::matrix::{validate_matrix, MatrixErr};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum CellErr {
+///     NotAFloat(String),
+///     Negative(usize, usize, f64),
+/// }
+///
+/// let parse = |csv: &str| {
+///     let rows = csv.lines().map(|line| {
+///         line.split(',')
+///             .map(|s| s.trim().parse::<f64>().map_err(|_| CellErr::NotAFloat(s.to_string())))
+///     });
+///     validate_matrix(rows, |cell| *cell >= 0.0, CellErr::Negative)
+/// };
+///
+/// assert_eq!(parse("1.2, 3.0\n4.2, 0.5"), Ok(vec![vec![1.2, 3.0], vec![4.2, 0.5]]));
+/// assert_eq!(
+///     parse("1.0, 2.0\n3.0"),
+///     Err(MatrixErr::Jagged { row: 1, len: 1, expected: 2 })
+/// );
+/// assert_eq!(
+///     parse("1.0, -2.0"),
+///     Err(MatrixErr::Cell(CellErr::Negative(0, 1, -2.0)))
+/// );
+/// ```
+pub fn validate_matrix<R, T, E, F, Factory>(
+    rows: R,
+    test: F,
+    factory: Factory,
+) -> Result<Vec<Vec<T>>, MatrixErr<E>>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, usize, T) -> E,
+{
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.into_iter()
+                .map(|cell| cell.map_err(MatrixErr::Cell))
+                .ensure(&test, |j, cell| MatrixErr::Cell(factory(i, j, cell)))
+                .at_least(1, |_| MatrixErr::NoColumns(i))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .at_least(1, |_| MatrixErr::NoRows)
+        .const_over(
+            |row| row.len(),
+            |row, _, len, expected| MatrixErr::Jagged {
+                row,
+                len,
+                expected: *expected,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_matrix, MatrixErr};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Negative(usize, usize, i32),
+        Corrupt,
+    }
+
+    fn ok_rows(rows: Vec<Vec<i32>>) -> impl Iterator<Item = Vec<Result<i32, TestErr>>> {
+        rows.into_iter()
+            .map(|row| row.into_iter().map(Ok).collect())
+    }
+
+    #[test]
+    fn test_validate_matrix_valid() {
+        let mat = validate_matrix(
+            ok_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+            |v| *v >= 0,
+            TestErr::Negative,
+        );
+        assert_eq!(mat, Ok(vec![vec![1, 2, 3], vec![4, 5, 6]]))
+    }
+
+    #[test]
+    fn test_validate_matrix_jagged() {
+        let mat = validate_matrix(
+            ok_rows(vec![vec![1, 2], vec![3, 4], vec![5]]),
+            |v| *v >= 0,
+            TestErr::Negative,
+        );
+        assert_eq!(
+            mat,
+            Err(MatrixErr::Jagged {
+                row: 2,
+                len: 1,
+                expected: 2
+            })
+        )
+    }
+
+    #[test]
+    fn test_validate_matrix_empty() {
+        let mat = validate_matrix(ok_rows(vec![]), |v| *v >= 0, TestErr::Negative);
+        assert_eq!(mat, Err(MatrixErr::NoRows));
+
+        let mat = validate_matrix(
+            ok_rows(vec![vec![1], vec![]]),
+            |v| *v >= 0,
+            TestErr::Negative,
+        );
+        assert_eq!(mat, Err(MatrixErr::NoColumns(1)))
+    }
+
+    #[test]
+    fn test_validate_matrix_cell_errors() {
+        let mat = validate_matrix(
+            ok_rows(vec![vec![1, 2], vec![3, -4]]),
+            |v| *v >= 0,
+            TestErr::Negative,
+        );
+        assert_eq!(mat, Err(MatrixErr::Cell(TestErr::Negative(1, 1, -4))));
+
+        let mat = validate_matrix(
+            vec![vec![Ok(1)], vec![Err(TestErr::Corrupt)]],
+            |v| *v >= 0,
+            TestErr::Negative,
+        );
+        assert_eq!(mat, Err(MatrixErr::Cell(TestErr::Corrupt)))
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct AlternatingIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    iter: IndexOffset<I>,
+    previous: Option<bool>,
+    classify: C,
+    factory: Factory,
+}
+
+impl<I, T, E, C, Factory> AlternatingIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        classify: C,
+        factory: Factory,
+    ) -> AlternatingIter<I, T, E, C, Factory> {
+        AlternatingIter {
+            iter: IndexOffset::new(iter),
+            previous: None,
+            classify,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, C, Factory> Iterator for AlternatingIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let class = (self.classify)(&val);
+                match self.previous {
+                    Some(prev) if prev == class => Some(Err((self.factory)(i, val))),
+                    _ => {
+                        self.previous = Some(class);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, C, Factory> WithIndexOffset for AlternatingIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait Alternating<T, E, C, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    C: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    /// Fails an iteration if its elements do not alternate between
+    /// two classes.
+    ///
+    /// `alternating(classify, factory)` assigns each element to one of two
+    /// classes using `classify`. If the class of an element is different
+    /// from the class of the last valid element, the element is wrapped in
+    /// `Ok(element)`. Otherwise, `factory` is called on the index of the
+    /// error and the element. The first valid element always passes, and
+    /// sets the initial class.
+    ///
+    /// Elements that failed the validation do not change the expected class,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::Alternating;
+    ///
+    /// let mut iter = ["req", "res", "req", "req", "res"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .alternating(|msg| *msg == "req", |i, msg| (i, msg));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("req")));
+    /// assert_eq!(iter.next(), Some(Ok("res")));
+    /// assert_eq!(iter.next(), Some(Ok("req")));
+    /// assert_eq!(iter.next(), Some(Err((3, "req"))));
+    /// assert_eq!(iter.next(), Some(Ok("res")));
+    /// ```
+    fn alternating(self, classify: C, factory: Factory) -> AlternatingIter<Self, T, E, C, Factory> {
+        AlternatingIter::new(self, classify, factory)
+    }
+}
+
+impl<I, T, E, C, Factory> Alternating<T, E, C, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alternating, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        NotAlternating(usize, char),
+        IsUnknown(usize, char),
+    }
+
+    #[test]
+    fn test_alternating_ok() {
+        if "ABABAB"
+            .chars()
+            .map(Ok)
+            .alternating(|c| *c == 'A', TestErr::NotAlternating)
+            .any(|res| res.is_err())
+        {
+            panic!("alternating failed on an alternating iteration")
+        }
+    }
+
+    #[test]
+    fn test_alternating_repeated_class() {
+        let results = "AAB"
+            .chars()
+            .map(Ok)
+            .alternating(|c| *c == 'A', TestErr::NotAlternating)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok('A'), Err(TestErr::NotAlternating(1, 'A')), Ok('B')]
+        )
+    }
+
+    #[test]
+    fn test_alternating_ignores_errors() {
+        let results = "AxBBA"
+            .chars()
+            .map(Ok)
+            .ensure(|c| c.is_uppercase(), TestErr::IsUnknown)
+            .alternating(|c| *c == 'A', TestErr::NotAlternating)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('A'),
+                Err(TestErr::IsUnknown(1, 'x')),
+                Ok('B'),
+                Err(TestErr::NotAlternating(3, 'B')),
+                Ok('A')
+            ]
+        )
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct AlternatingSignIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64) -> E,
+{
+    iter: IndexOffset<I>,
+    previous_positive: Option<bool>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> AlternatingSignIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        extractor: M,
+        factory: Factory,
+    ) -> AlternatingSignIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            previous_positive: None,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for AlternatingSignIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                // zero and NaN have no sign, and are skipped
+                if extraction == 0.0 || extraction.is_nan() {
+                    return Some(Ok(val));
+                }
+                let positive = extraction > 0.0;
+                match self.previous_positive == Some(positive) {
+                    true => Some(Err((self.factory)(i, val, extraction))),
+                    false => {
+                        self.previous_positive = Some(positive);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for AlternatingSignIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait AlternatingSign<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64) -> E,
+{
+    /// Fails an iteration if the signs of the values computed by `extractor`
+    /// do not strictly alternate.
+    ///
+    /// `alternating_sign(extractor, factory)` compares the sign of the value
+    /// extracted from each element wrapped in `Ok` to the sign of the last
+    /// valid element. If the signs differ, the element is wrapped in
+    /// `Ok(element)`. Otherwise, `factory` is called on the index of the
+    /// error, the element and the extracted value. The first element with
+    /// a sign is always wrapped in `Ok`.
+    ///
+    /// Zero (of either sign) and `NaN` are neither positive nor negative.
+    /// Elements with such values are always wrapped in `Ok`, and are skipped
+    /// when looking for the previous sign, so `[1, 0, -1]` is alternating but
+    /// `[1, 0, 1]` is not.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::AlternatingSign;
+    ///
+    /// let mut iter = [0.5, -0.2, 0.0, 0.1, 0.3]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .alternating_sign(|v| *v, |i, _, v| (i, v));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0.5)));
+    /// assert_eq!(iter.next(), Some(Ok(-0.2)));
+    /// assert_eq!(iter.next(), Some(Ok(0.0)));
+    /// assert_eq!(iter.next(), Some(Ok(0.1)));
+    /// assert_eq!(iter.next(), Some(Err((4, 0.3))));
+    /// ```
+    fn alternating_sign(
+        self,
+        extractor: M,
+        factory: Factory,
+    ) -> AlternatingSignIter<Self, T, E, M, Factory> {
+        AlternatingSignIter::new(self, extractor, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> AlternatingSign<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AlternatingSign;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        SameSign(usize, i32),
+        Corrupt,
+    }
+
+    fn same_sign(index: usize, val: i32, _: f64) -> TestErr {
+        TestErr::SameSign(index, val)
+    }
+
+    #[test]
+    fn test_alternating_sign_ok() {
+        if [1, -1, 1, -1]
+            .into_iter()
+            .map(Ok)
+            .alternating_sign(|v| *v as f64, same_sign)
+            .any(|res| res.is_err())
+        {
+            panic!("alternating sign failed on an alternating iteration")
+        }
+    }
+
+    #[test]
+    fn test_alternating_sign_same_sign_fails() {
+        let results = [1, 2]
+            .into_iter()
+            .map(Ok)
+            .alternating_sign(|v| *v as f64, same_sign)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(1), Err(TestErr::SameSign(1, 2))])
+    }
+
+    #[test]
+    fn test_alternating_sign_skips_zeros_and_errors() {
+        let results = [Ok(-3), Ok(0), Err(TestErr::Corrupt), Ok(-1), Ok(2), Ok(0)]
+            .into_iter()
+            .alternating_sign(|v| *v as f64, same_sign)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(-3),
+                Ok(0),
+                Err(TestErr::Corrupt),
+                Err(TestErr::SameSign(3, -1)),
+                Ok(2),
+                Ok(0)
+            ]
+        )
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct AndThenValidateIter<I, T, E, A, D, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    D: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, A) -> E,
+{
+    iter: IndexOffset<I>,
+    derive: D,
+    test: F,
+    factory: Factory,
+}
+
+impl<I, T, E, A, D, F, Factory> AndThenValidateIter<I, T, E, A, D, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    D: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        derive: D,
+        test: F,
+        factory: Factory,
+    ) -> AndThenValidateIter<I, T, E, A, D, F, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            derive,
+            test,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, D, F, Factory> Iterator for AndThenValidateIter<I, T, E, A, D, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    D: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let derived = (self.derive)(&val);
+                match (self.test)(&val, &derived) {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val, derived))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, A, D, F, Factory> WithIndexOffset for AndThenValidateIter<I, T, E, A, D, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    D: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait AndThenValidate<T, E, A, D, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    D: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, A) -> E,
+{
+    /// Fails an element which does not pass a test against a value derived
+    /// from it.
+    ///
+    /// `and_then_validate(derive, test, factory)` calls `derive` on each
+    /// element wrapped in `Ok`, and then calls `test` on the element and the
+    /// derived value. If `test` returns `true`, the element is wrapped in
+    /// `Ok(element)`. Otherwise, `factory` is called on the index of the error,
+    /// the element and the derived value.
+    ///
+    /// Since previous adapters already turned invalid elements into errors,
+    /// chaining `and_then_validate` after them runs the dependent test only
+    /// on elements which passed.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{AndThenValidate, Ensure};
+    ///
+    /// let mut iter = ["4:abcd", "3:ab", "x"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure(|s| s.contains(':'), |i, _| (i, None))
+    ///     .and_then_validate(
+    ///         |s| s.split_once(':').unwrap(),
+    ///         |_, (len, body)| len.parse() == Ok(body.len()),
+    ///         |i, _, (len, _)| (i, Some(len)),
+    ///     );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("4:abcd")));
+    /// assert_eq!(iter.next(), Some(Err((1, Some("3")))));
+    /// assert_eq!(iter.next(), Some(Err((2, None))));
+    /// ```
+    fn and_then_validate(
+        self,
+        derive: D,
+        test: F,
+        factory: Factory,
+    ) -> AndThenValidateIter<Self, T, E, A, D, F, Factory> {
+        AndThenValidateIter::new(self, derive, test, factory)
+    }
+}
+
+impl<I, T, E, A, D, F, Factory> AndThenValidate<T, E, A, D, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    D: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AndThenValidate, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Unparsable(usize, &'static str),
+        BadChecksum(usize, &'static str, u32),
+    }
+
+    fn digit_sum(s: &&'static str) -> u32 {
+        s.chars().filter_map(|c| c.to_digit(10)).sum()
+    }
+
+    #[test]
+    fn test_and_then_validate_dependent_test_fails() {
+        let results = ["123", "2a", "14", "55"]
+            .into_iter()
+            .map(Ok)
+            .ensure(
+                |s| s.chars().all(|c| c.is_ascii_digit()),
+                TestErr::Unparsable,
+            )
+            .and_then_validate(digit_sum, |_, sum| sum % 2 == 0, TestErr::BadChecksum)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("123"),
+                Err(TestErr::Unparsable(1, "2a")),
+                Err(TestErr::BadChecksum(2, "14", 5)),
+                Ok("55")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_and_then_validate_test_sees_element() {
+        if ["ab", "cd"]
+            .into_iter()
+            .map(Ok::<_, TestErr>)
+            .and_then_validate(
+                |s| s.len(),
+                |s, len| s.chars().count() == *len,
+                |_, _, _| unreachable!(),
+            )
+            .any(|res| res.is_err())
+        {
+            panic!("and then validate failed on valid elements")
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct AsciiOnlyIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<u8, E>>,
+    Factory: Fn(usize, u8) -> E,
+{
+    iter: IndexOffset<I>,
+    factory: Factory,
+}
+
+impl<I, E, Factory> AsciiOnlyIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<u8, E>>,
+    Factory: Fn(usize, u8) -> E,
+{
+    pub(crate) fn new(iter: I, factory: Factory) -> AsciiOnlyIter<I, E, Factory> {
+        AsciiOnlyIter {
+            iter: IndexOffset::new(iter),
+            factory,
+        }
+    }
+}
+
+impl<I, E, Factory> Iterator for AsciiOnlyIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<u8, E>>,
+    Factory: Fn(usize, u8) -> E,
+{
+    type Item = Result<u8, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(byte))) => match byte.is_ascii() {
+                true => Some(Ok(byte)),
+                false => Some(Err((self.factory)(i, byte))),
+            },
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, E, Factory> WithIndexOffset for AsciiOnlyIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<u8, E>>,
+    Factory: Fn(usize, u8) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait AsciiOnly<E, Factory>: Iterator<Item = Result<u8, E>> + Sized
+where
+    Factory: Fn(usize, u8) -> E,
+{
+    /// Fails any byte which is not ASCII.
+    ///
+    /// `ascii_only(factory)` wraps every byte lower than `0x80` in
+    /// `Ok(byte)`, and calls `factory` on the index of the error and the
+    /// byte for any other byte. This is a cheap check to run on raw input
+    /// before any further text processing, when only ASCII text is allowed.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::AsciiOnly;
+    ///
+    /// let mut iter = "aé".bytes().map(Ok).ascii_only(|i, byte| (i, byte));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(b'a')));
+    /// assert_eq!(iter.next(), Some(Err((1, 0xc3))));
+    /// assert_eq!(iter.next(), Some(Err((2, 0xa9))));
+    /// ```
+    fn ascii_only(self, factory: Factory) -> AsciiOnlyIter<Self, E, Factory> {
+        AsciiOnlyIter::new(self, factory)
+    }
+}
+
+impl<I, E, Factory> AsciiOnly<E, Factory> for I
+where
+    I: Iterator<Item = Result<u8, E>>,
+    Factory: Fn(usize, u8) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AsciiOnly;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        NotAscii(usize, u8),
+        Unreadable,
+    }
+
+    #[test]
+    fn test_ascii_only_ok() {
+        if (0..0x80u8)
+            .map(Ok)
+            .ascii_only(TestErr::NotAscii)
+            .any(|res| res.is_err())
+        {
+            panic!("ascii only failed on ascii bytes")
+        }
+    }
+
+    #[test]
+    fn test_ascii_only_high_byte() {
+        let results = [b'o', b'k', 0x80, b'!']
+            .into_iter()
+            .map(Ok)
+            .ascii_only(TestErr::NotAscii)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(b'o'),
+                Ok(b'k'),
+                Err(TestErr::NotAscii(2, 0x80)),
+                Ok(b'!')
+            ]
+        )
+    }
+
+    #[test]
+    fn test_ascii_only_ignores_errors() {
+        let results = [Err(TestErr::Unreadable), Ok(0xff)]
+            .into_iter()
+            .ascii_only(TestErr::NotAscii)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Err(TestErr::Unreadable), Err(TestErr::NotAscii(1, 0xff))]
+        )
+    }
+}
@@ -0,0 +1,117 @@
+use crate::validation_adapters::at_least::AtLeastIter;
+
+pub trait AtLeastMsg<T, E>: Iterator<Item = Result<T, E>> + Sized
+where
+    E: Clone,
+{
+    /// Same as [`at_least`](crate::AtLeast::at_least), failing with a
+    /// clone of `msg` instead of calling a factory.
+    ///
+    /// `at_least_msg(n, msg)` is a shorthand for
+    /// `at_least(n, |_| msg.clone())`, for when the error does not depend on
+    /// the length of the iteration.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::AtLeastMsg;
+    ///
+    /// let mut iter = (0..1).map(Ok).at_least_msg(2, "too few");
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Err("too few")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn at_least_msg(self, n: usize, msg: E) -> AtLeastIter<Self, T, E, impl Fn(usize) -> E> {
+        AtLeastIter::new(self, n, move |_| msg.clone())
+    }
+
+    /// Fails an empty iteration with a clone of `msg`.
+    ///
+    /// `non_empty_msg(msg)` is a shorthand for `at_least_msg(1, msg)`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::AtLeastMsg;
+    ///
+    /// let mut iter = (0..0).map(Ok::<i32, _>).non_empty_msg("empty");
+    ///
+    /// assert_eq!(iter.next(), Some(Err("empty")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn non_empty_msg(self, msg: E) -> AtLeastIter<Self, T, E, impl Fn(usize) -> E> {
+        self.at_least_msg(1, msg)
+    }
+}
+
+impl<I, T, E> AtLeastMsg<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtLeastMsg, Ensure};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestErr {
+        TooFew,
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_at_least_msg_clones_message() {
+        let results = (0..2)
+            .map(Ok)
+            .at_least_msg(3, "need 3".to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(1), Err("need 3".to_string())])
+    }
+
+    #[test]
+    fn test_at_least_msg_with_enum_variant() {
+        let results = (0..4)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .at_least_msg(3, TestErr::TooFew)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::IsOdd(1, 1)),
+                Ok(2),
+                Err(TestErr::IsOdd(3, 3)),
+                Err(TestErr::TooFew)
+            ]
+        );
+
+        if (0..3)
+            .map(Ok)
+            .at_least_msg(3, TestErr::TooFew)
+            .any(|res| res.is_err())
+        {
+            panic!("at least msg failed on a long enough iteration")
+        }
+    }
+
+    #[test]
+    fn test_non_empty_msg() {
+        let results = (0..0)
+            .map(Ok::<i32, _>)
+            .non_empty_msg(TestErr::TooFew)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::TooFew)]);
+
+        let results = (0..1)
+            .map(Ok)
+            .non_empty_msg(TestErr::TooFew)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0)])
+    }
+}
@@ -1,4 +1,4 @@
-use std::iter::Enumerate;
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
 
 #[derive(Debug, Clone)]
 pub struct AtMostIter<I, T, E, Factory>
@@ -6,7 +6,7 @@ where
     I: Iterator<Item = Result<T, E>>,
     Factory: Fn(usize, T) -> E,
 {
-    iter: Enumerate<I>,
+    iter: IndexOffset<I>,
     max_count: usize,
     counter: usize,
     factory: Factory,
@@ -19,7 +19,7 @@ where
 {
     pub(crate) fn new(iter: I, max_count: usize, factory: Factory) -> AtMostIter<I, T, E, Factory> {
         AtMostIter {
-            iter: iter.enumerate(),
+            iter: IndexOffset::new(iter),
             max_count,
             counter: 0,
             factory,
@@ -49,6 +49,17 @@ where
     }
 }
 
+impl<I, T, E, Factory> WithIndexOffset for AtMostIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
 pub trait AtMost<T, E, Factory>: Iterator<Item = Result<T, E>> + Sized
 where
     Factory: Fn(usize, T) -> E,
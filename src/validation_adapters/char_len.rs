@@ -0,0 +1,217 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone, Copy)]
+enum CharLenBound {
+    Min(usize),
+    Max(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct CharLenIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    iter: IndexOffset<I>,
+    bound: CharLenBound,
+    factory: Factory,
+}
+
+impl<I, T, E, Factory> CharLenIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    fn new(iter: I, bound: CharLenBound, factory: Factory) -> CharLenIter<I, T, E, Factory> {
+        CharLenIter {
+            iter: IndexOffset::new(iter),
+            bound,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, Factory> Iterator for CharLenIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let len = val.as_ref().chars().count();
+                let in_bound = match self.bound {
+                    CharLenBound::Min(limit) => len >= limit,
+                    CharLenBound::Max(limit) => len <= limit,
+                };
+                match in_bound {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val, len))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, Factory> WithIndexOffset for CharLenIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait CharLen<T, E, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: AsRef<str>,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    /// Fails any string element with more than `limit` characters.
+    ///
+    /// `max_char_len(limit, factory)` counts the `char`s of each element,
+    /// and wraps it in `Ok(element)` if there are at most `limit` of them.
+    /// Otherwise, `factory` is called on the index of the error, the element,
+    /// and its character count.
+    ///
+    /// Characters are counted with [`str::chars`], and not by byte length, so
+    /// multibyte characters count as a single character.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::CharLen;
+    ///
+    /// let mut iter = ["héllo", "hello!"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .max_char_len(5, |i, _, len| (i, len));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("héllo"))); // 6 bytes, but 5 chars
+    /// assert_eq!(iter.next(), Some(Err((1, 6))));
+    /// ```
+    fn max_char_len(self, limit: usize, factory: Factory) -> CharLenIter<Self, T, E, Factory> {
+        CharLenIter::new(self, CharLenBound::Max(limit), factory)
+    }
+
+    /// Fails any string element with less than `limit` characters.
+    ///
+    /// `min_char_len(limit, factory)` counts the `char`s of each element,
+    /// and wraps it in `Ok(element)` if there are at least `limit` of them.
+    /// Otherwise, `factory` is called on the index of the error, the element,
+    /// and its character count.
+    ///
+    /// Characters are counted with [`str::chars`], and not by byte length, so
+    /// multibyte characters count as a single character.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::CharLen;
+    ///
+    /// let mut iter = ["ab", "日本"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .min_char_len(3, |i, _, len| (i, len));
+    ///
+    /// assert_eq!(iter.next(), Some(Err((0, 2))));
+    /// assert_eq!(iter.next(), Some(Err((1, 2)))); // 6 bytes, but 2 chars
+    /// ```
+    fn min_char_len(self, limit: usize, factory: Factory) -> CharLenIter<Self, T, E, Factory> {
+        CharLenIter::new(self, CharLenBound::Min(limit), factory)
+    }
+}
+
+impl<I, T, E, Factory> CharLen<T, E, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T, usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CharLen;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadLen(usize, String, usize),
+        Other,
+    }
+
+    #[test]
+    fn test_max_char_len_counts_chars_not_bytes() {
+        let line = "ñandú".to_string();
+        assert!(line.len() > 5);
+        let results = [line.clone(), "abcdef".to_string()]
+            .into_iter()
+            .map(Ok)
+            .max_char_len(5, TestErr::BadLen)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(line), Err(TestErr::BadLen(1, "abcdef".to_string(), 6))]
+        )
+    }
+
+    #[test]
+    fn test_min_char_len() {
+        let results = ["", "ab", "abc"]
+            .into_iter()
+            .map(|s| Ok(s.to_string()))
+            .min_char_len(2, TestErr::BadLen)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::BadLen(0, "".to_string(), 0)),
+                Ok("ab".to_string()),
+                Ok("abc".to_string())
+            ]
+        )
+    }
+
+    #[test]
+    fn test_char_len_bounds_chain() {
+        let results = ["a", "ab", "abc", "abcd"]
+            .into_iter()
+            .map(|s| Ok(s.to_string()))
+            .min_char_len(2, TestErr::BadLen)
+            .max_char_len(3, TestErr::BadLen)
+            .map(|res| res.is_ok())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![false, true, true, false])
+    }
+
+    #[test]
+    fn test_char_len_ignores_errors() {
+        let results = [Err(TestErr::Other), Ok("abc".to_string())]
+            .into_iter()
+            .max_char_len(0, TestErr::BadLen)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Other),
+                Err(TestErr::BadLen(1, "abc".to_string(), 3))
+            ]
+        )
+    }
+}
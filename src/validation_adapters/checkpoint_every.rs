@@ -0,0 +1,139 @@
+#[derive(Debug, Clone)]
+pub struct CheckpointEveryIter<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(usize, usize),
+{
+    iter: I,
+    k: usize,
+    seen: usize,
+    errors: usize,
+    on_checkpoint: F,
+}
+
+impl<I, T, E, F> CheckpointEveryIter<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(usize, usize),
+{
+    pub(crate) fn new(iter: I, k: usize, on_checkpoint: F) -> CheckpointEveryIter<I, T, E, F> {
+        CheckpointEveryIter {
+            iter,
+            k,
+            seen: 0,
+            errors: 0,
+            on_checkpoint,
+        }
+    }
+}
+
+impl<I, T, E, F> Iterator for CheckpointEveryIter<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(usize, usize),
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.seen += 1;
+        if item.is_err() {
+            self.errors += 1;
+        }
+        // prevent modulo 0 div
+        if self.k != 0 && self.seen % self.k == 0 {
+            (self.on_checkpoint)(self.seen, self.errors);
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait CheckpointEvery<T, E, F>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: FnMut(usize, usize),
+{
+    /// Reports the progress of an iteration every `k` elements.
+    ///
+    /// `checkpoint_every(k, on_checkpoint)` yields every element unchanged,
+    /// and after every `k` elements calls `on_checkpoint` on the number of
+    /// elements seen so far and the number of them that were wrapped in
+    /// `Err`. This allows reporting progress of long validations without
+    /// collecting them. No checkpoint is reported for a final partial batch
+    /// of less than `k` elements, and if `k == 0` no checkpoint is ever
+    /// reported.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{CheckpointEvery, Ensure};
+    ///
+    /// let mut checkpoints = Vec::new();
+    /// let count = (0..7)
+    ///     .map(Ok)
+    ///     .ensure(|i| i % 3 != 0, |i, _| i)
+    ///     .checkpoint_every(3, |seen, errors| checkpoints.push((seen, errors)))
+    ///     .count();
+    ///
+    /// assert_eq!(count, 7);
+    /// assert_eq!(checkpoints, vec![(3, 1), (6, 2)]);
+    /// ```
+    fn checkpoint_every(self, k: usize, on_checkpoint: F) -> CheckpointEveryIter<Self, T, E, F> {
+        CheckpointEveryIter::new(self, k, on_checkpoint)
+    }
+}
+
+impl<I, T, E, F> CheckpointEvery<T, E, F> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(usize, usize),
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CheckpointEvery, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_checkpoint_every_fires_per_batch() {
+        let mut checkpoints = Vec::new();
+        let results = (0..10)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .checkpoint_every(4, |seen, errors| checkpoints.push((seen, errors)))
+            .collect::<Vec<_>>();
+        assert_eq!(results.len(), 10);
+        assert_eq!(checkpoints, vec![(4, 2), (8, 4)])
+    }
+
+    #[test]
+    fn test_checkpoint_every_passes_items_through() {
+        let mut fired = 0;
+        let results = (0..3)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .checkpoint_every(1, |_, _| fired += 1)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Err(TestErr::IsOdd(1, 1)), Ok(2)]);
+        assert_eq!(fired, 3)
+    }
+
+    #[test]
+    fn test_checkpoint_every_zero_never_fires() {
+        let mut fired = 0;
+        (0..5)
+            .map(Ok::<_, TestErr>)
+            .checkpoint_every(0, |_, _| fired += 1)
+            .for_each(drop);
+        assert_eq!(fired, 0)
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct ChunksSortedIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    iter: IndexOffset<I>,
+    chunk_size: usize,
+    position: usize,
+    previous: Option<A>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, Factory> ChunksSortedIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        chunk_size: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> ChunksSortedIter<I, T, E, A, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            chunk_size,
+            position: 0,
+            previous: None,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> Iterator for ChunksSortedIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, item) = self.iter.next()?;
+        // with chunk_size == 0 nothing is validated
+        if self.chunk_size == 0 {
+            return Some(item);
+        }
+        // chunks are split by position, which does not include the index offset
+        if self.position % self.chunk_size == 0 {
+            self.previous = None;
+        }
+        self.position += 1;
+        match item {
+            Ok(val) => {
+                let extraction = (self.extractor)(&val);
+                match &self.previous {
+                    Some(prev) if extraction >= *prev => {
+                        self.previous = Some(extraction);
+                        Some(Ok(val))
+                    }
+                    Some(prev) => Some(Err((self.factory)(i, val, extraction, prev))),
+                    None => {
+                        self.previous = Some(extraction);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            err => Some(err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, A, M, Factory> WithIndexOffset for ChunksSortedIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait ChunksSorted<T, E, A, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    /// Fails an iteration if the values computed by `extractor` are not
+    /// sorted within each chunk of `chunk_size` elements.
+    ///
+    /// `chunks_sorted(chunk_size, extractor, factory)` splits the iteration
+    /// into consecutive chunks of `chunk_size` elements, by position, and
+    /// compares the value extracted from each element to the value extracted
+    /// from the last valid element of the same chunk. If the current value is
+    /// greater or equal, the element is wrapped in `Ok(element)`. Otherwise,
+    /// `factory` is called on the index of the error, the element, the
+    /// extracted value, and the previous value. The first valid element of
+    /// each chunk always passes, so chunks don't need to be ordered relative
+    /// to each other.
+    ///
+    /// If `chunk_size` is 0, all elements are yielded unchanged. Elements
+    /// that failed the validation do not become the new baseline. Values
+    /// already wrapped in `Result::Err` are ignored, but still take up their
+    /// position in a chunk.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::ChunksSorted;
+    ///
+    /// let mut iter = [1, 5, 9, 2, 7, 3]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .chunks_sorted(3, |v| *v, |i, _, v, prev| (i, v, *prev));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(5)));
+    /// assert_eq!(iter.next(), Some(Ok(9)));
+    /// assert_eq!(iter.next(), Some(Ok(2))); // a new chunk starts
+    /// assert_eq!(iter.next(), Some(Ok(7)));
+    /// assert_eq!(iter.next(), Some(Err((5, 3, 7))));
+    /// ```
+    fn chunks_sorted(
+        self,
+        chunk_size: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> ChunksSortedIter<Self, T, E, A, M, Factory> {
+        ChunksSortedIter::new(self, chunk_size, extractor, factory)
+    }
+}
+
+impl<I, T, E, A, M, Factory> ChunksSorted<T, E, A, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunksSorted, WithIndexOffset};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Unsorted(usize, i32, i32),
+        Corrupt,
+    }
+
+    fn unsorted(index: usize, _: i32, extraction: i32, prev: &i32) -> TestErr {
+        TestErr::Unsorted(index, extraction, *prev)
+    }
+
+    #[test]
+    fn test_chunks_sorted_ok() {
+        if [1, 2, 3, 0, 0, 5, -4, 8, 9]
+            .into_iter()
+            .map(Ok)
+            .chunks_sorted(3, |v| *v, unsorted)
+            .any(|res| res.is_err())
+        {
+            panic!("chunks sorted failed on sorted chunks")
+        }
+    }
+
+    #[test]
+    fn test_chunks_sorted_inversion_in_one_chunk() {
+        let results = [1, 2, 3, 10, 8, 9, 4, 5, 6]
+            .into_iter()
+            .map(Ok)
+            .chunks_sorted(3, |v| *v, unsorted)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Ok(2),
+                Ok(3),
+                Ok(10),
+                Err(TestErr::Unsorted(4, 8, 10)),
+                Err(TestErr::Unsorted(5, 9, 10)),
+                Ok(4),
+                Ok(5),
+                Ok(6)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_chunks_sorted_index_offset_keeps_chunks() {
+        let results = [1, 2, 3, 10, 8, 9, 4, 5, 6]
+            .into_iter()
+            .map(Ok)
+            .chunks_sorted(3, |v| *v, unsorted)
+            .with_index_offset(1)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Ok(2),
+                Ok(3),
+                Ok(10),
+                Err(TestErr::Unsorted(5, 8, 10)),
+                Err(TestErr::Unsorted(6, 9, 10)),
+                Ok(4),
+                Ok(5),
+                Ok(6)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_chunks_sorted_does_nothing_on_0() {
+        if [3, 2, 1]
+            .into_iter()
+            .map(Ok)
+            .chunks_sorted(0, |v| *v, unsorted)
+            .any(|res| res.is_err())
+        {
+            panic!("chunks sorted failed when it should not be validating anything")
+        }
+    }
+
+    #[test]
+    fn test_chunks_sorted_ignores_errors() {
+        let results = [Ok(2), Err(TestErr::Corrupt), Ok(1), Ok(0)]
+            .into_iter()
+            .chunks_sorted(2, |v| *v, unsorted)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(2),
+                Err(TestErr::Corrupt),
+                Ok(1),
+                Err(TestErr::Unsorted(3, 0, 1))
+            ]
+        )
+    }
+}
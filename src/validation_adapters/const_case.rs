@@ -0,0 +1,150 @@
+use crate::validation_adapters::const_over::ConstOverIter;
+
+/// The capitalization scheme of a token, as computed by
+/// [`const_case`](crate::ConstCase::const_case).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseClass {
+    /// No uppercase characters, such as `"abc"` or `"123"`.
+    Lower,
+    /// At least one uppercase character, and no lowercase characters,
+    /// such as `"ABC"` or `"A"`.
+    Upper,
+    /// An uppercase first character followed by lowercase characters only,
+    /// such as `"Abc"`.
+    Title,
+    /// Any other mix of uppercase and lowercase characters.
+    Mixed,
+}
+
+impl CaseClass {
+    fn of(token: &str) -> CaseClass {
+        let has_upper = token.chars().any(char::is_uppercase);
+        let has_lower = token.chars().any(char::is_lowercase);
+        match (has_upper, has_lower) {
+            (false, _) => CaseClass::Lower,
+            (true, false) => CaseClass::Upper,
+            (true, true) => {
+                let mut chars = token.chars();
+                match chars.next().is_some_and(char::is_uppercase) && !chars.any(char::is_uppercase)
+                {
+                    true => CaseClass::Title,
+                    false => CaseClass::Mixed,
+                }
+            }
+        }
+    }
+}
+
+pub trait ConstCase<T, E>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: AsRef<str>,
+{
+    /// Fails an iteration if its tokens do not share the same
+    /// capitalization scheme.
+    ///
+    /// `const_case(factory)` is a shorthand for
+    /// [`const_over`](crate::ConstOver::const_over) with an extractor
+    /// computing the [`CaseClass`] of each token. The first valid token sets
+    /// the expected scheme, and every token of a different scheme is failed
+    /// by calling `factory` on the index of the error, the token, its case
+    /// class and the expected case class.
+    ///
+    /// Single letter uppercase tokens are classified as
+    /// [`Upper`](CaseClass::Upper), and tokens without any cased characters
+    /// are classified as [`Lower`](CaseClass::Lower).
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{CaseClass, ConstCase};
+    ///
+    /// let mut iter = ["Foo", "Bar", "baz"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .const_case(|i, _, class, expected| (i, class, *expected));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("Foo")));
+    /// assert_eq!(iter.next(), Some(Ok("Bar")));
+    /// assert_eq!(iter.next(), Some(Err((2, CaseClass::Lower, CaseClass::Title))));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn const_case<Factory>(
+        self,
+        factory: Factory,
+    ) -> ConstOverIter<Self, T, E, CaseClass, impl Fn(&T) -> CaseClass, Factory>
+    where
+        Factory: Fn(usize, T, CaseClass, &CaseClass) -> E,
+    {
+        ConstOverIter::new(self, |token: &T| CaseClass::of(token.as_ref()), factory)
+    }
+}
+
+impl<I, T, E> ConstCase<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CaseClass;
+    use crate::ConstCase;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        WrongCase(usize, &'static str, CaseClass),
+    }
+
+    fn wrong_case(index: usize, token: &'static str, class: CaseClass, _: &CaseClass) -> TestErr {
+        TestErr::WrongCase(index, token, class)
+    }
+
+    #[test]
+    fn test_const_case_fails_on_uppercase() {
+        let results = ["abc", "def", "GHI"]
+            .into_iter()
+            .map(Ok)
+            .const_case(wrong_case)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("abc"),
+                Ok("def"),
+                Err(TestErr::WrongCase(2, "GHI", CaseClass::Upper))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_const_case_title() {
+        let results = ["Abc", "Def", "GhI", "Jkl"]
+            .into_iter()
+            .map(Ok)
+            .const_case(wrong_case)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("Abc"),
+                Ok("Def"),
+                Err(TestErr::WrongCase(2, "GhI", CaseClass::Mixed)),
+                Ok("Jkl")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_case_class_of() {
+        assert_eq!(CaseClass::of("abc"), CaseClass::Lower);
+        assert_eq!(CaseClass::of("42"), CaseClass::Lower);
+        assert_eq!(CaseClass::of("ABC"), CaseClass::Upper);
+        assert_eq!(CaseClass::of("A"), CaseClass::Upper);
+        assert_eq!(CaseClass::of("Abc"), CaseClass::Title);
+        assert_eq!(CaseClass::of("aBc"), CaseClass::Mixed);
+    }
+}
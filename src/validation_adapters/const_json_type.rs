@@ -0,0 +1,126 @@
+use serde_json::Value;
+
+use crate::validation_adapters::const_over::ConstOverIter;
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+pub trait ConstJsonType<E>: Iterator<Item = Result<Value, E>> + Sized {
+    /// Fails an iteration of JSON values if they are not all of the same
+    /// JSON type.
+    ///
+    /// `const_json_type(factory)` is a shorthand for
+    /// [`const_over`](crate::ConstOver::const_over) with an extractor
+    /// computing the type name of each [`Value`](serde_json::Value): one of
+    /// `"null"`, `"boolean"`, `"number"`, `"string"`, `"array"` or
+    /// `"object"`. The first valid value sets the expected type, and every
+    /// value of a different type is failed by calling `factory` on the index
+    /// of the error, the value, its type name and the expected type name.
+    ///
+    /// All numbers share the same type, whether they are integers or floats.
+    /// `null` is a type of its own, so a nullable column fails on its first
+    /// `null`.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// This method is only available with the `serde_json` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use serde_json::json;
+    /// use validiter::ConstJsonType;
+    ///
+    /// let mut iter = [json!(1), json!(2.5), json!("3")]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .const_json_type(|i, _, found, expected| (i, found, *expected));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(json!(1))));
+    /// assert_eq!(iter.next(), Some(Ok(json!(2.5))));
+    /// assert_eq!(iter.next(), Some(Err((2, "string", "number"))));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn const_json_type<Factory>(
+        self,
+        factory: Factory,
+    ) -> ConstOverIter<Self, Value, E, &'static str, impl Fn(&Value) -> &'static str, Factory>
+    where
+        Factory: Fn(usize, Value, &'static str, &&'static str) -> E,
+    {
+        ConstOverIter::new(self, json_type_name, factory)
+    }
+}
+
+impl<I, E> ConstJsonType<E> for I where I: Iterator<Item = Result<Value, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::ConstJsonType;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        WrongType(usize, Value, &'static str, &'static str),
+        Unparsable,
+    }
+
+    fn wrong_type(
+        index: usize,
+        value: Value,
+        found: &'static str,
+        expected: &&'static str,
+    ) -> TestErr {
+        TestErr::WrongType(index, value, found, expected)
+    }
+
+    #[test]
+    fn test_const_json_type_string_in_numbers() {
+        let results = [json!(1), json!(-2), json!("3"), json!(4.5)]
+            .into_iter()
+            .map(Ok)
+            .const_json_type(wrong_type)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(json!(1)),
+                Ok(json!(-2)),
+                Err(TestErr::WrongType(2, json!("3"), "string", "number")),
+                Ok(json!(4.5))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_const_json_type_ignores_errors() {
+        let results = [
+            Err(TestErr::Unparsable),
+            Ok(json!({"a": 1})),
+            Ok(json!(null)),
+            Ok(json!({})),
+        ]
+        .into_iter()
+        .const_json_type(wrong_type)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Unparsable),
+                Ok(json!({"a": 1})),
+                Err(TestErr::WrongType(2, json!(null), "null", "object")),
+                Ok(json!({}))
+            ]
+        )
+    }
+}
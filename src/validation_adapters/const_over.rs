@@ -1,4 +1,4 @@
-use std::iter::Enumerate;
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
 
 #[derive(Debug, Clone)]
 pub struct ConstOverIter<I, T, E, A, M, Factory>
@@ -8,7 +8,7 @@ where
     M: Fn(&T) -> A,
     Factory: Fn(usize, T, A, &A) -> E,
 {
-    iter: Enumerate<I>,
+    iter: IndexOffset<I>,
     stored_value: Option<A>,
     extractor: M,
     factory: Factory,
@@ -27,7 +27,7 @@ where
         factory: Factory,
     ) -> ConstOverIter<I, T, E, A, M, Factory> {
         Self {
-            iter: iter.enumerate(),
+            iter: IndexOffset::new(iter),
             stored_value: None,
             extractor,
             factory,
@@ -65,6 +65,19 @@ where
     }
 }
 
+impl<I, T, E, A, M, Factory> WithIndexOffset for ConstOverIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialEq,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
 pub trait ConstOver<T, E, A, M, Factory>: Iterator<Item = Result<T, E>> + Sized
 where
     A: PartialEq,
@@ -0,0 +1,254 @@
+use std::collections::VecDeque;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct ConstOverWindowIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialEq,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    iter: IndexOffset<I>,
+    history: usize,
+    window: VecDeque<A>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, Factory> ConstOverWindowIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialEq,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        n: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> ConstOverWindowIter<I, T, E, A, M, Factory> {
+        let history = n.saturating_sub(1);
+        Self {
+            iter: IndexOffset::new(iter),
+            history,
+            window: VecDeque::new(),
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> Iterator for ConstOverWindowIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialEq,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // a window of 0 or 1 elements is always constant
+        if self.history == 0 {
+            return self.iter.next().map(|(_, item)| item);
+        }
+
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                if let Some(differing) = self.window.iter().find(|key| **key != extraction) {
+                    let err = (self.factory)(i, val, extraction, differing);
+                    // start over, so the elements after a change are only
+                    // compared to each other
+                    self.window.clear();
+                    return Some(Err(err));
+                }
+                if self.window.len() == self.history {
+                    self.window.pop_front();
+                }
+                self.window.push_back(extraction);
+                Some(Ok(val))
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, A, M, Factory> WithIndexOffset for ConstOverWindowIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialEq,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait ConstOverWindow<T, E, A, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    A: PartialEq,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    /// Fails an iteration if `extractor` does not give the same result
+    /// for every `n` consecutive elements.
+    ///
+    /// `const_over_window(n, extractor, factory)` keeps the values
+    /// extracted from up to `n - 1` of the last valid elements, and
+    /// compares the value extracted from each element to all of them. If it
+    /// equals all of them, the element is wrapped in `Ok(element)`.
+    /// Otherwise, `factory` is called on the index of the error, the
+    /// element, the extracted value, and the first value in the window that
+    /// differs from it.
+    ///
+    /// Unlike [`const_over`](crate::ConstOver::const_over), the value may
+    /// change over the iteration. Each change is reported once: when an
+    /// element fails, the window is emptied, and the elements after it are
+    /// only compared to each other. If `n` is 0 or 1, all elements are
+    /// yielded unchanged.
+    ///
+    /// Elements that failed the validation do not enter the window, and
+    /// values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::ConstOverWindow;
+    ///
+    /// let mut iter = [1, 1, 1, 2, 1]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .const_over_window(3, |v| *v, |i, _, v, differing| (i, v, *differing));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err((3, 2, 1))));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// ```
+    fn const_over_window(
+        self,
+        n: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> ConstOverWindowIter<Self, T, E, A, M, Factory> {
+        ConstOverWindowIter::new(self, n, extractor, factory)
+    }
+}
+
+impl<I, T, E, A, M, Factory> ConstOverWindow<T, E, A, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialEq,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ConstOverWindow;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Varies(usize, char, char),
+        Corrupt,
+    }
+
+    fn varies(index: usize, _: char, extraction: char, differing: &char) -> TestErr {
+        TestErr::Varies(index, extraction, *differing)
+    }
+
+    #[test]
+    fn test_const_over_window_flat_run() {
+        if "aaaaaa"
+            .chars()
+            .map(Ok)
+            .const_over_window(3, |c| *c, varies)
+            .any(|res| res.is_err())
+        {
+            panic!("const over window failed on a constant iteration")
+        }
+    }
+
+    #[test]
+    fn test_const_over_window_single_deviation() {
+        let results = "aaaba"
+            .chars()
+            .map(Ok)
+            .const_over_window(3, |c| *c, varies)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('a'),
+                Ok('a'),
+                Ok('a'),
+                Err(TestErr::Varies(3, 'b', 'a')),
+                Ok('a')
+            ]
+        )
+    }
+
+    #[test]
+    fn test_const_over_window_filling_and_small_windows() {
+        let results = "abb"
+            .chars()
+            .map(Ok)
+            .const_over_window(2, |c| *c, varies)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok('a'), Err(TestErr::Varies(1, 'b', 'a')), Ok('b')]
+        );
+
+        let results = "abbbbb"
+            .chars()
+            .map(Ok)
+            .const_over_window(3, |c| *c, varies)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('a'),
+                Err(TestErr::Varies(1, 'b', 'a')),
+                Ok('b'),
+                Ok('b'),
+                Ok('b'),
+                Ok('b')
+            ]
+        );
+
+        for n in [0, 1] {
+            if "abc"
+                .chars()
+                .map(Ok)
+                .const_over_window(n, |c| *c, varies)
+                .any(|res| res.is_err())
+            {
+                panic!("const over window failed with a window of {n}")
+            }
+        }
+    }
+
+    #[test]
+    fn test_const_over_window_ignores_errors() {
+        let results = [Ok('a'), Err(TestErr::Corrupt), Ok('a')]
+            .into_iter()
+            .const_over_window(2, |c| *c, varies)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok('a'), Err(TestErr::Corrupt), Ok('a')])
+    }
+}
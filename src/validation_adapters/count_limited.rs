@@ -0,0 +1,207 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct CountLimitedIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    iter: IndexOffset<I>,
+    max_count: usize,
+    frequencies: HashMap<K, usize>,
+    key_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, K, M, Factory> CountLimitedIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        key_of: M,
+        max_count: usize,
+        factory: Factory,
+    ) -> CountLimitedIter<I, T, E, K, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            max_count,
+            frequencies: HashMap::new(),
+            key_of,
+            factory,
+        }
+    }
+
+    /// The number of valid elements seen so far for each key.
+    pub fn frequencies(&self) -> &HashMap<K, usize> {
+        &self.frequencies
+    }
+}
+
+impl<I, T, E, K, M, Factory> Iterator for CountLimitedIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let key = (self.key_of)(&val);
+                let count = self.frequencies.get(&key).copied().unwrap_or(0);
+                match count < self.max_count {
+                    true => {
+                        self.frequencies.insert(key, count + 1);
+                        Some(Ok(val))
+                    }
+                    false => Some(Err((self.factory)(i, val, key))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, K, M, Factory> WithIndexOffset for CountLimitedIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait CountLimited<T, E, K, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    /// Fails any element whose key already appeared `max_count` times.
+    ///
+    /// `count_limited(key_of, max_count, factory)` counts the valid elements
+    /// for each key computed by `key_of`. If fewer than `max_count` elements
+    /// with the same key were seen before, the element is wrapped in
+    /// `Ok(element)` and counted. Otherwise, `factory` is called on the index
+    /// of the error, the element, and its key. A `max_count` of 1 requires
+    /// all keys to be unique.
+    ///
+    /// The frequency of every key is kept in a map, which can be inspected
+    /// at any point of the iteration with
+    /// [`frequencies`](CountLimitedIter::frequencies).
+    ///
+    /// Elements that failed the validation are not counted, and values
+    /// already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::CountLimited;
+    ///
+    /// let votes = ["alice", "bob", "alice", "alice"];
+    /// let mut iter = votes
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .count_limited(|voter| *voter, 2, |i, _, voter| (i, voter));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("alice")));
+    /// assert_eq!(iter.next(), Some(Ok("bob")));
+    /// assert_eq!(iter.next(), Some(Ok("alice")));
+    /// assert_eq!(iter.next(), Some(Err((3, "alice"))));
+    /// assert_eq!(iter.frequencies()["alice"], 2);
+    /// ```
+    fn count_limited(
+        self,
+        key_of: M,
+        max_count: usize,
+        factory: Factory,
+    ) -> CountLimitedIter<Self, T, E, K, M, Factory> {
+        CountLimitedIter::new(self, key_of, max_count, factory)
+    }
+}
+
+impl<I, T, E, K, M, Factory> CountLimited<T, E, K, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::CountLimited;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        TooFrequent(usize, char),
+        Corrupt,
+    }
+
+    fn too_frequent(index: usize, _: char, key: char) -> TestErr {
+        TestErr::TooFrequent(index, key)
+    }
+
+    #[test]
+    fn test_count_limited_fails_on_last_occurrence() {
+        let results = "abacaba"
+            .chars()
+            .map(Ok)
+            .count_limited(|c| *c, 3, too_frequent)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('a'),
+                Ok('b'),
+                Ok('a'),
+                Ok('c'),
+                Ok('a'),
+                Ok('b'),
+                Err(TestErr::TooFrequent(6, 'a'))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_count_limited_frequencies() {
+        let mut iter = [Ok('x'), Err(TestErr::Corrupt), Ok('y'), Ok('x'), Ok('x')]
+            .into_iter()
+            .count_limited(|c| *c, 2, too_frequent);
+        let results = iter.by_ref().collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('x'),
+                Err(TestErr::Corrupt),
+                Ok('y'),
+                Ok('x'),
+                Err(TestErr::TooFrequent(4, 'x'))
+            ]
+        );
+        assert_eq!(iter.frequencies(), &HashMap::from([('x', 2), ('y', 1)]))
+    }
+}
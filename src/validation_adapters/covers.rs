@@ -0,0 +1,218 @@
+/// The bounds that were not reached in a
+/// [`covers`](crate::Covers::covers) validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissedBound {
+    Lower,
+    Upper,
+    Both,
+}
+
+#[derive(Debug, Clone)]
+pub struct CoversIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, MissedBound) -> E,
+{
+    iter: I,
+    lower: A,
+    upper: A,
+    lower_covered: bool,
+    upper_covered: bool,
+    enumeration_counter: usize,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, Factory> CoversIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, MissedBound) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        lower: A,
+        upper: A,
+        extractor: M,
+        factory: Factory,
+    ) -> CoversIter<I, T, E, A, M, Factory> {
+        CoversIter {
+            iter,
+            lower,
+            upper,
+            lower_covered: false,
+            upper_covered: false,
+            enumeration_counter: 0,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> Iterator for CoversIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, MissedBound) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.iter.next() {
+            Some(Ok(val)) => {
+                let extraction = (self.extractor)(&val);
+                if extraction <= self.lower {
+                    self.lower_covered = true;
+                }
+                if extraction >= self.upper {
+                    self.upper_covered = true;
+                }
+                Some(Ok(val))
+            }
+            None => {
+                let missed = match (self.lower_covered, self.upper_covered) {
+                    (true, true) => return None,
+                    (false, true) => MissedBound::Lower,
+                    (true, false) => MissedBound::Upper,
+                    (false, false) => MissedBound::Both,
+                };
+                self.lower_covered = true;
+                self.upper_covered = true;
+                Some(Err((self.factory)(self.enumeration_counter, missed)))
+            }
+            other => other,
+        };
+        self.enumeration_counter += 1;
+        item
+    }
+}
+
+pub trait Covers<T, E, A, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, MissedBound) -> E,
+{
+    /// Fails a validation iterator if the values computed by `extractor`
+    /// do not span the range `[lower, upper]`.
+    ///
+    /// `covers(lower, upper, extractor, factory)` yields all elements
+    /// unchanged, while tracking whether some value extracted from an element
+    /// was lesser or equal to `lower`, and whether some value was greater or equal
+    /// to `upper`. If at the end of the iteration one of these was not
+    /// seen, a new element is added to the end of the iteration with the
+    /// value returned from calling `factory` on the length of the iterator
+    /// and the [`MissedBound`].
+    ///
+    /// Like [`at_least`](crate::AtLeast::at_least), the `covers` adapter
+    /// cannot handle short-circuiting of iterators.
+    ///
+    /// Elements already wrapped in `Result::Err` are not counted towards
+    /// covering the range, but are counted in the length provided to `factory`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Covers, MissedBound};
+    ///
+    /// let mut iter = [3, 0, 7].into_iter().map(Ok).covers(0, 10, |v| *v, |len, missed| (len, missed));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(7)));
+    /// assert_eq!(iter.next(), Some(Err((3, MissedBound::Upper))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn covers(
+        self,
+        lower: A,
+        upper: A,
+        extractor: M,
+        factory: Factory,
+    ) -> CoversIter<Self, T, E, A, M, Factory> {
+        CoversIter::new(self, lower, upper, extractor, factory)
+    }
+}
+
+impl<I, T, E, A, M, Factory> Covers<T, E, A, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, MissedBound) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Covers, MissedBound};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        NotCovered(usize, MissedBound),
+        IsNegative(i32),
+    }
+
+    #[test]
+    fn test_covers_on_success() {
+        let results = [5, 10, 0, 3]
+            .into_iter()
+            .map(Ok)
+            .covers(0, 10, |i| *i, TestErr::NotCovered)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(5), Ok(10), Ok(0), Ok(3)])
+    }
+
+    #[test]
+    fn test_covers_missing_upper_bound() {
+        let results = (0..10)
+            .map(Ok)
+            .covers(0, 10, |i| *i, TestErr::NotCovered)
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(results, Err(TestErr::NotCovered(10, MissedBound::Upper)))
+    }
+
+    #[test]
+    fn test_covers_missing_both_bounds() {
+        let results = (1..9)
+            .map(Ok)
+            .covers(0, 10, |i| *i, TestErr::NotCovered)
+            .last();
+        assert_eq!(
+            results,
+            Some(Err(TestErr::NotCovered(8, MissedBound::Both)))
+        );
+
+        let empty = (0..0)
+            .map(Ok)
+            .covers(0, 10, |i| *i, TestErr::NotCovered)
+            .collect::<Vec<_>>();
+        assert_eq!(empty, vec![Err(TestErr::NotCovered(0, MissedBound::Both))])
+    }
+
+    #[test]
+    fn test_covers_ignores_errors() {
+        let results = [-1, 10, 1]
+            .into_iter()
+            .map(|i| match i < 0 {
+                true => Err(TestErr::IsNegative(i)),
+                false => Ok(i),
+            })
+            .covers(0, 10, |i| *i, TestErr::NotCovered)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::IsNegative(-1)),
+                Ok(10),
+                Ok(1),
+                Err(TestErr::NotCovered(3, MissedBound::Lower))
+            ]
+        )
+    }
+}
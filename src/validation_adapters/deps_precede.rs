@@ -0,0 +1,222 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct DepsPrecedeIter<I, T, E, K, Id, Deps, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    Id: Fn(&T) -> K,
+    Deps: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    iter: IndexOffset<I>,
+    seen: HashSet<K>,
+    id_of: Id,
+    deps_of: Deps,
+    factory: Factory,
+}
+
+impl<I, T, E, K, Id, Deps, Factory> DepsPrecedeIter<I, T, E, K, Id, Deps, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    Id: Fn(&T) -> K,
+    Deps: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        id_of: Id,
+        deps_of: Deps,
+        factory: Factory,
+    ) -> DepsPrecedeIter<I, T, E, K, Id, Deps, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            seen: HashSet::new(),
+            id_of,
+            deps_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, K, Id, Deps, Factory> Iterator for DepsPrecedeIter<I, T, E, K, Id, Deps, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    Id: Fn(&T) -> K,
+    Deps: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let missing = (self.deps_of)(&val)
+                    .into_iter()
+                    .find(|dep| !self.seen.contains(dep));
+                match missing {
+                    Some(dep) => Some(Err((self.factory)(i, val, dep))),
+                    None => {
+                        self.seen.insert((self.id_of)(&val));
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, K, Id, Deps, Factory> WithIndexOffset
+    for DepsPrecedeIter<I, T, E, K, Id, Deps, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    Id: Fn(&T) -> K,
+    Deps: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait DepsPrecede<T, E, K, Id, Deps, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash,
+    Id: Fn(&T) -> K,
+    Deps: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    /// Fails any element which depends on an element that did not
+    /// appear earlier in the iteration.
+    ///
+    /// `deps_precede(id_of, deps_of, factory)` remembers the id of every
+    /// valid element, as computed by `id_of`. For each element, the ids it
+    /// depends on are computed by `deps_of`. If all of them were already
+    /// seen, the element is wrapped in `Ok(element)`, and its id is remembered.
+    /// Otherwise, `factory` is called on the index of the error, the element,
+    /// and the first missing dependency.
+    ///
+    /// An element can't depend on itself. The ids of all valid elements are
+    /// kept in memory until the iteration is dropped.
+    ///
+    /// Elements that failed the validation are not remembered, so elements
+    /// depending on them will fail as well. Values already wrapped in
+    /// `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::DepsPrecede;
+    ///
+    /// let build_order = [("core", vec![]), ("app", vec!["core", "ui"]), ("ui", vec!["core"])];
+    /// let mut iter = build_order
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .deps_precede(|(id, _)| *id, |(_, deps)| deps.clone(), |i, _, missing| (i, missing));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(("core", vec![]))));
+    /// assert_eq!(iter.next(), Some(Err((1, "ui"))));
+    /// assert_eq!(iter.next(), Some(Ok(("ui", vec!["core"]))));
+    /// ```
+    fn deps_precede(
+        self,
+        id_of: Id,
+        deps_of: Deps,
+        factory: Factory,
+    ) -> DepsPrecedeIter<Self, T, E, K, Id, Deps, Factory> {
+        DepsPrecedeIter::new(self, id_of, deps_of, factory)
+    }
+}
+
+impl<I, T, E, K, Id, Deps, Factory> DepsPrecede<T, E, K, Id, Deps, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    Id: Fn(&T) -> K,
+    Deps: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DepsPrecede;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        MissingDep(usize, u32, u32),
+        Other,
+    }
+
+    type Node = (u32, Vec<u32>);
+
+    fn missing_dep(index: usize, node: Node, dep: u32) -> TestErr {
+        TestErr::MissingDep(index, node.0, dep)
+    }
+
+    #[test]
+    fn test_deps_precede_ordered() {
+        let nodes: Vec<Node> = vec![(1, vec![]), (2, vec![1]), (3, vec![1, 2]), (4, vec![])];
+        if nodes
+            .into_iter()
+            .map(Ok)
+            .deps_precede(|n| n.0, |n| n.1.clone(), missing_dep)
+            .any(|res| res.is_err())
+        {
+            panic!("deps precede failed on a correctly ordered iteration")
+        }
+    }
+
+    #[test]
+    fn test_deps_precede_forward_reference() {
+        let nodes: Vec<Node> = vec![(1, vec![]), (2, vec![1, 3]), (3, vec![1])];
+        let results = nodes
+            .into_iter()
+            .map(Ok)
+            .deps_precede(|n| n.0, |n| n.1.clone(), missing_dep)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((1, vec![])),
+                Err(TestErr::MissingDep(1, 2, 3)),
+                Ok((3, vec![1]))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_deps_precede_self_and_failed_dependencies() {
+        let nodes: Vec<Node> = vec![(1, vec![1]), (2, vec![1])];
+        let results = nodes
+            .into_iter()
+            .map(Ok)
+            .deps_precede(|n| n.0, |n| n.1.clone(), missing_dep)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::MissingDep(0, 1, 1)),
+                Err(TestErr::MissingDep(1, 2, 1))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_deps_precede_ignores_errors() {
+        let results = [Err(TestErr::Other), Ok((1, vec![]))]
+            .into_iter()
+            .deps_precede(|n: &Node| n.0, |n| n.1.clone(), missing_dep)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::Other), Ok((1, vec![]))])
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct DepthStepAtMostIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    iter: IndexOffset<I>,
+    max_step: usize,
+    previous: Option<usize>,
+    depth_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> DepthStepAtMostIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        depth_of: M,
+        max_step: usize,
+        factory: Factory,
+    ) -> DepthStepAtMostIter<I, T, E, M, Factory> {
+        DepthStepAtMostIter {
+            iter: IndexOffset::new(iter),
+            max_step,
+            previous: None,
+            depth_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for DepthStepAtMostIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let depth = (self.depth_of)(&val);
+                match self.previous {
+                    Some(prev) if depth > prev.saturating_add(self.max_step) => {
+                        Some(Err((self.factory)(i, val, depth, prev)))
+                    }
+                    _ => {
+                        self.previous = Some(depth);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for DepthStepAtMostIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait DepthStepAtMost<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    /// Fails any element which is nested deeper than `max_step` levels
+    /// below the previous element.
+    ///
+    /// `depth_step_at_most(depth_of, max_step, factory)` compares the depth
+    /// computed by `depth_of` for each element to the depth of the last valid
+    /// element. If the depth is at most `max_step` greater, the element is
+    /// wrapped in `Ok(element)`. Otherwise, `factory` is called on the index
+    /// of the error, the element, its depth, and the previous depth.
+    /// Decreasing the depth by any number of levels is always allowed, and
+    /// the first valid element sets the initial depth.
+    ///
+    /// This is useful for indentation based hierarchies, such as outlines,
+    /// where a child can't skip a level.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::DepthStepAtMost;
+    ///
+    /// let outline = "1. fruit\n  a. apple\n      i. red\n  b. pear\nvegetables";
+    /// let mut iter = outline
+    ///     .lines()
+    ///     .map(Ok)
+    ///     .depth_step_at_most(
+    ///         |line| (line.len() - line.trim_start().len()) / 2,
+    ///         1,
+    ///         |i, _, depth, prev| (i, depth, prev),
+    ///     );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("1. fruit")));
+    /// assert_eq!(iter.next(), Some(Ok("  a. apple")));
+    /// assert_eq!(iter.next(), Some(Err((2, 3, 1))));
+    /// assert_eq!(iter.next(), Some(Ok("  b. pear")));
+    /// assert_eq!(iter.next(), Some(Ok("vegetables")));
+    /// ```
+    fn depth_step_at_most(
+        self,
+        depth_of: M,
+        max_step: usize,
+        factory: Factory,
+    ) -> DepthStepAtMostIter<Self, T, E, M, Factory> {
+        DepthStepAtMostIter::new(self, depth_of, max_step, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> DepthStepAtMost<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DepthStepAtMost;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        SkippedLevel(usize, usize, usize),
+        Corrupt,
+    }
+
+    fn skipped(index: usize, _: usize, depth: usize, prev: usize) -> TestErr {
+        TestErr::SkippedLevel(index, depth, prev)
+    }
+
+    #[test]
+    fn test_depth_step_at_most_ok() {
+        if [0, 1, 2, 2, 0, 1, 2, 3, 1]
+            .into_iter()
+            .map(Ok)
+            .depth_step_at_most(|d| *d, 1, skipped)
+            .any(|res| res.is_err())
+        {
+            panic!("depth step at most failed on a valid outline")
+        }
+    }
+
+    #[test]
+    fn test_depth_step_at_most_skipped_level() {
+        let results = [0, 1, 3, 2, 4]
+            .into_iter()
+            .map(Ok)
+            .depth_step_at_most(|d| *d, 1, skipped)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(1),
+                Err(TestErr::SkippedLevel(2, 3, 1)),
+                Ok(2),
+                Err(TestErr::SkippedLevel(4, 4, 2))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_depth_step_at_most_larger_step_and_errors() {
+        let results = [Ok(0), Err(TestErr::Corrupt), Ok(2), Ok(5)]
+            .into_iter()
+            .depth_step_at_most(|d| *d, 2, skipped)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::Corrupt),
+                Ok(2),
+                Err(TestErr::SkippedLevel(3, 5, 2))
+            ]
+        )
+    }
+}
@@ -1,4 +1,4 @@
-use std::iter::Enumerate;
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
 
 #[derive(Debug, Clone)]
 pub struct EnsureIter<I, T, E, F, Factory>
@@ -7,7 +7,7 @@ where
     F: Fn(&T) -> bool,
     Factory: Fn(usize, T) -> E,
 {
-    iter: Enumerate<I>,
+    iter: IndexOffset<I>,
     validation: F,
     factory: Factory,
 }
@@ -20,7 +20,7 @@ where
 {
     pub(crate) fn new(iter: I, validation: F, factory: Factory) -> EnsureIter<I, T, E, F, Factory> {
         EnsureIter {
-            iter: iter.enumerate(),
+            iter: IndexOffset::new(iter),
             validation,
             factory,
         }
@@ -47,6 +47,18 @@ where
     }
 }
 
+impl<I, T, E, F, Factory> WithIndexOffset for EnsureIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
 pub trait Ensure<T, E, F, Factory>: Iterator<Item = Result<T, E>> + Sized
 where
     F: Fn(&T) -> bool,
@@ -63,6 +75,14 @@ where
     ///
     /// Values already wrapped in `Result::Err` are ignored.
     ///
+    /// The index given to `factory` counts the elements `ensure` receives,
+    /// starting at 0 from the point in the chain where it is applied. So
+    /// after elements are removed upstream, for example with
+    /// [`Iterator::filter`], the index is the position after the filtering,
+    /// and not the position in the original source. To report positions in
+    /// the source, enumerate it before filtering, and carry the position in
+    /// the element.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -110,6 +130,21 @@ where
     /// assert_eq!(iter.next(), Some(Err(0)));
     /// ```
     ///
+    /// Indices are counted after filtering:
+    /// ```
+    /// # use validiter::Ensure;
+    ///
+    /// let mut iter = (0..10)
+    ///     .filter(|i| i % 3 == 0) // 0, 3, 6, 9
+    ///     .map(Ok)
+    ///     .ensure(|i| *i < 5, |index, val| (index, val));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), Some(Err((2, 6))));
+    /// assert_eq!(iter.next(), Some(Err((3, 9))));
+    /// ```
+    ///
     /// [`Err(ValidErr::Invalid(element))`](crate::valid_result::ValidErr)
     fn ensure(self, test: F, factory: Factory) -> EnsureIter<Self, T, E, F, Factory> {
         EnsureIter::new(self, test, factory)
@@ -157,4 +192,23 @@ mod tests {
             .next();
         assert_eq!(v, Some(Err(TestErr::Err1(0, 0))))
     }
+
+    #[test]
+    fn test_ensure_indices_after_filter_start_at_0() {
+        let results = (0..10)
+            .filter(|i| *i >= 5)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, |err_index, i| TestErr::IsOdd(err_index, i))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::IsOdd(0, 5)),
+                Ok(6),
+                Err(TestErr::IsOdd(2, 7)),
+                Ok(8),
+                Err(TestErr::IsOdd(4, 9))
+            ]
+        )
+    }
 }
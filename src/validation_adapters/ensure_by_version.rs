@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+/// The rules of [`ensure_by_version`](crate::EnsureByVersion::ensure_by_version),
+/// mapping each known version to the test of its elements.
+pub type VersionRules<T> = HashMap<u32, Box<dyn Fn(&T) -> bool>>;
+
+pub struct EnsureByVersionIter<I, T, E, V, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    V: Fn(&T) -> u32,
+    Factory: Fn(usize, T, u32, bool) -> E,
+{
+    iter: IndexOffset<I>,
+    version_of: V,
+    rules: VersionRules<T>,
+    factory: Factory,
+}
+
+impl<I, T, E, V, Factory> EnsureByVersionIter<I, T, E, V, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    V: Fn(&T) -> u32,
+    Factory: Fn(usize, T, u32, bool) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        version_of: V,
+        rules: VersionRules<T>,
+        factory: Factory,
+    ) -> EnsureByVersionIter<I, T, E, V, Factory> {
+        EnsureByVersionIter {
+            iter: IndexOffset::new(iter),
+            version_of,
+            rules,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, V, Factory> Iterator for EnsureByVersionIter<I, T, E, V, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    V: Fn(&T) -> u32,
+    Factory: Fn(usize, T, u32, bool) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let version = (self.version_of)(&val);
+                match self.rules.get(&version) {
+                    Some(rule) if rule(&val) => Some(Ok(val)),
+                    Some(_) => Some(Err((self.factory)(i, val, version, true))),
+                    None => Some(Err((self.factory)(i, val, version, false))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, V, Factory> WithIndexOffset for EnsureByVersionIter<I, T, E, V, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    V: Fn(&T) -> u32,
+    Factory: Fn(usize, T, u32, bool) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait EnsureByVersion<T, E, V, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    V: Fn(&T) -> u32,
+    Factory: Fn(usize, T, u32, bool) -> E,
+{
+    /// Validates each element with the rule registered for its version.
+    ///
+    /// `ensure_by_version(version_of, rules, factory)` looks up the version
+    /// returned by `version_of` for each element wrapped in `Ok` in `rules`.
+    /// If a rule exists and returns `true` for the element, the element is
+    /// wrapped in `Ok(element)`. Otherwise, `factory` is called on the index of
+    /// the error, the element, its version, and whether a rule exists for that
+    /// version - `false` means the version is unknown.
+    ///
+    /// This allows validating iterations which mix records of several
+    /// versions of a format, each with its own constraints.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{EnsureByVersion, VersionRules};
+    ///
+    /// let mut rules: VersionRules<(u32, &str)> = VersionRules::new();
+    /// rules.insert(1, Box::new(|(_, body)| !body.is_empty()));
+    /// rules.insert(2, Box::new(|(_, body)| body.starts_with('{')));
+    ///
+    /// let mut iter = [(1, "a"), (2, "a"), (3, "{}")]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure_by_version(|(v, _)| *v, rules, |i, _, v, known| (i, v, known));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok((1, "a"))));
+    /// assert_eq!(iter.next(), Some(Err((1, 2, true))));
+    /// assert_eq!(iter.next(), Some(Err((2, 3, false))));
+    /// ```
+    fn ensure_by_version(
+        self,
+        version_of: V,
+        rules: VersionRules<T>,
+        factory: Factory,
+    ) -> EnsureByVersionIter<Self, T, E, V, Factory> {
+        EnsureByVersionIter::new(self, version_of, rules, factory)
+    }
+}
+
+impl<I, T, E, V, Factory> EnsureByVersion<T, E, V, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    V: Fn(&T) -> u32,
+    Factory: Fn(usize, T, u32, bool) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VersionRules;
+    use crate::EnsureByVersion;
+
+    #[derive(Debug, PartialEq)]
+    struct Record {
+        version: u32,
+        fields: Vec<&'static str>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Invalid(usize, u32),
+        UnknownVersion(usize, u32),
+        Corrupt,
+    }
+
+    fn record(version: u32, fields: &[&'static str]) -> Record {
+        Record {
+            version,
+            fields: fields.to_vec(),
+        }
+    }
+
+    fn version_err(index: usize, _: Record, version: u32, known: bool) -> TestErr {
+        match known {
+            true => TestErr::Invalid(index, version),
+            false => TestErr::UnknownVersion(index, version),
+        }
+    }
+
+    fn rules() -> VersionRules<Record> {
+        let mut rules: VersionRules<Record> = VersionRules::new();
+        rules.insert(1, Box::new(|r| r.fields.len() == 2));
+        rules.insert(2, Box::new(|r| r.fields.len() == 3));
+        rules
+    }
+
+    #[test]
+    fn test_ensure_by_version_dispatches_rules() {
+        let results = [
+            Ok(record(1, &["a", "b"])),
+            Ok(record(2, &["a", "b"])),
+            Ok(record(2, &["a", "b", "c"])),
+            Err(TestErr::Corrupt),
+            Ok(record(7, &["a", "b"])),
+        ]
+        .into_iter()
+        .ensure_by_version(|r| r.version, rules(), version_err)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(record(1, &["a", "b"])),
+                Err(TestErr::Invalid(1, 2)),
+                Ok(record(2, &["a", "b", "c"])),
+                Err(TestErr::Corrupt),
+                Err(TestErr::UnknownVersion(4, 7))
+            ]
+        )
+    }
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct EnsureInMapIter<'a, I, T, E, K, V, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    F: Fn(&V, &T) -> bool,
+    Factory: Fn(usize, T, K, Option<&'a V>) -> E,
+{
+    iter: IndexOffset<I>,
+    map: &'a HashMap<K, V>,
+    key_of: M,
+    value_check: F,
+    factory: Factory,
+}
+
+impl<'a, I, T, E, K, V, M, F, Factory> EnsureInMapIter<'a, I, T, E, K, V, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    F: Fn(&V, &T) -> bool,
+    Factory: Fn(usize, T, K, Option<&'a V>) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        map: &'a HashMap<K, V>,
+        key_of: M,
+        value_check: F,
+        factory: Factory,
+    ) -> EnsureInMapIter<'a, I, T, E, K, V, M, F, Factory> {
+        EnsureInMapIter {
+            iter: IndexOffset::new(iter),
+            map,
+            key_of,
+            value_check,
+            factory,
+        }
+    }
+}
+
+impl<'a, I, T, E, K, V, M, F, Factory> Iterator
+    for EnsureInMapIter<'a, I, T, E, K, V, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    F: Fn(&V, &T) -> bool,
+    Factory: Fn(usize, T, K, Option<&'a V>) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let key = (self.key_of)(&val);
+                match self.map.get(&key) {
+                    Some(value) if (self.value_check)(value, &val) => Some(Ok(val)),
+                    found => Some(Err((self.factory)(i, val, key, found))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, T, E, K, V, M, F, Factory> WithIndexOffset
+    for EnsureInMapIter<'a, I, T, E, K, V, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    F: Fn(&V, &T) -> bool,
+    Factory: Fn(usize, T, K, Option<&'a V>) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait EnsureInMap<'a, T, E, K, V, M, F, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash,
+    V: 'a,
+    M: Fn(&T) -> K,
+    F: Fn(&V, &T) -> bool,
+    Factory: Fn(usize, T, K, Option<&'a V>) -> E,
+{
+    /// Fails any element whose key is missing from a lookup table, or whose
+    /// entry in the table does not pass a check.
+    ///
+    /// `ensure_in_map(map, key_of, value_check, factory)` looks up the key
+    /// computed by `key_of` for each element wrapped in `Ok`. If the key is
+    /// found, and `value_check` returns `true` for its value and the element,
+    /// the element is wrapped in `Ok(element)`. Otherwise, `factory` is called
+    /// on the index of the error, the element, the key, and the value found
+    /// in the map - `None` if the key is missing, and `Some(value)` if the
+    /// value failed the check.
+    ///
+    /// The map is borrowed, so it can be shared by several validations. Pass
+    /// `|_, _| true` as `value_check` to only check that the key exists.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::HashMap;
+    /// use validiter::EnsureInMap;
+    ///
+    /// let stock = HashMap::from([("apple", 3), ("pear", 0)]);
+    /// let orders = [("apple", 2), ("pear", 1), ("plum", 1)];
+    /// let mut iter = orders.into_iter().map(Ok).ensure_in_map(
+    ///     &stock,
+    ///     |(item, _)| *item,
+    ///     |available, (_, amount)| available >= amount,
+    ///     |i, _, item, available| (i, item, available.copied()),
+    /// );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(("apple", 2))));
+    /// assert_eq!(iter.next(), Some(Err((1, "pear", Some(0)))));
+    /// assert_eq!(iter.next(), Some(Err((2, "plum", None))));
+    /// ```
+    fn ensure_in_map(
+        self,
+        map: &'a HashMap<K, V>,
+        key_of: M,
+        value_check: F,
+        factory: Factory,
+    ) -> EnsureInMapIter<'a, Self, T, E, K, V, M, F, Factory> {
+        EnsureInMapIter::new(self, map, key_of, value_check, factory)
+    }
+}
+
+impl<'a, I, T, E, K, V, M, F, Factory> EnsureInMap<'a, T, E, K, V, M, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    V: 'a,
+    M: Fn(&T) -> K,
+    F: Fn(&V, &T) -> bool,
+    Factory: Fn(usize, T, K, Option<&'a V>) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::EnsureInMap;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        UnknownCountry(usize, &'static str),
+        WrongCurrency(usize, &'static str, &'static str),
+        Corrupt,
+    }
+
+    type Payment = (&'static str, &'static str);
+
+    fn currencies() -> HashMap<&'static str, &'static str> {
+        HashMap::from([("US", "USD"), ("FR", "EUR"), ("JP", "JPY")])
+    }
+
+    fn payment_err(
+        index: usize,
+        _: Payment,
+        country: &'static str,
+        currency: Option<&&'static str>,
+    ) -> TestErr {
+        match currency {
+            None => TestErr::UnknownCountry(index, country),
+            Some(currency) => TestErr::WrongCurrency(index, country, currency),
+        }
+    }
+
+    #[test]
+    fn test_ensure_in_map_missing_and_invalid() {
+        let table = currencies();
+        let results = [("US", "USD"), ("XX", "USD"), ("FR", "USD"), ("JP", "JPY")]
+            .into_iter()
+            .map(Ok)
+            .ensure_in_map(
+                &table,
+                |(country, _)| *country,
+                |currency, (_, paid)| currency == paid,
+                payment_err,
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(("US", "USD")),
+                Err(TestErr::UnknownCountry(1, "XX")),
+                Err(TestErr::WrongCurrency(2, "FR", "EUR")),
+                Ok(("JP", "JPY"))
+            ]
+        );
+        // the table is only borrowed
+        assert_eq!(table.len(), 3)
+    }
+
+    #[test]
+    fn test_ensure_in_map_ignores_errors() {
+        let table = currencies();
+        let results = [Err(TestErr::Corrupt), Ok(("US", "USD"))]
+            .into_iter()
+            .ensure_in_map(&table, |(country, _)| *country, |_, _| true, payment_err)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::Corrupt), Ok(("US", "USD"))])
+    }
+}
@@ -0,0 +1,176 @@
+use jsonschema::Validator;
+use serde_json::Value;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct EnsureJsonSchemaIter<'a, I, E, Factory>
+where
+    I: Iterator<Item = Result<Value, E>>,
+    Factory: Fn(usize, Value, String) -> E,
+{
+    iter: IndexOffset<I>,
+    schema: &'a Validator,
+    factory: Factory,
+}
+
+impl<'a, I, E, Factory> EnsureJsonSchemaIter<'a, I, E, Factory>
+where
+    I: Iterator<Item = Result<Value, E>>,
+    Factory: Fn(usize, Value, String) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        schema: &'a Validator,
+        factory: Factory,
+    ) -> EnsureJsonSchemaIter<'a, I, E, Factory> {
+        EnsureJsonSchemaIter {
+            iter: IndexOffset::new(iter),
+            schema,
+            factory,
+        }
+    }
+}
+
+impl<I, E, Factory> Iterator for EnsureJsonSchemaIter<'_, I, E, Factory>
+where
+    I: Iterator<Item = Result<Value, E>>,
+    Factory: Fn(usize, Value, String) -> E,
+{
+    type Item = Result<Value, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let failure = self.schema.validate(&val).err().map(|err| err.to_string());
+                match failure {
+                    None => Some(Ok(val)),
+                    Some(description) => Some(Err((self.factory)(i, val, description))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, E, Factory> WithIndexOffset for EnsureJsonSchemaIter<'_, I, E, Factory>
+where
+    I: Iterator<Item = Result<Value, E>>,
+    Factory: Fn(usize, Value, String) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait EnsureJsonSchema<'a, E, Factory>: Iterator<Item = Result<Value, E>> + Sized
+where
+    Factory: Fn(usize, Value, String) -> E,
+{
+    /// Fails any JSON document which does not conform to a JSON Schema.
+    ///
+    /// `ensure_json_schema(compiled_schema, factory)` validates each
+    /// [`Value`](serde_json::Value) wrapped in `Ok` against a precompiled
+    /// [`Validator`](jsonschema::Validator). If the document is valid, it is
+    /// wrapped in `Ok(document)`. Otherwise, `factory` is called on the index
+    /// of the error, the document, and a description of the first schema
+    /// violation found in it.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// This method is only available with the `jsonschema` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use serde_json::json;
+    /// use validiter::EnsureJsonSchema;
+    ///
+    /// let schema = jsonschema::validator_for(&json!({"type": "object", "required": ["id"]})).unwrap();
+    /// let mut iter = [json!({"id": 1}), json!({"name": "x"})]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure_json_schema(&schema, |i, _, _| i);
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(json!({"id": 1}))));
+    /// assert_eq!(iter.next(), Some(Err(1)));
+    /// ```
+    fn ensure_json_schema(
+        self,
+        compiled_schema: &'a Validator,
+        factory: Factory,
+    ) -> EnsureJsonSchemaIter<'a, Self, E, Factory> {
+        EnsureJsonSchemaIter::new(self, compiled_schema, factory)
+    }
+}
+
+impl<'a, I, E, Factory> EnsureJsonSchema<'a, E, Factory> for I
+where
+    I: Iterator<Item = Result<Value, E>>,
+    Factory: Fn(usize, Value, String) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::EnsureJsonSchema;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        SchemaViolation(usize, Value, String),
+        Unparsable,
+    }
+
+    fn schema() -> jsonschema::Validator {
+        jsonschema::validator_for(&json!({
+            "type": "object",
+            "properties": {"id": {"type": "integer"}},
+            "required": ["id"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ensure_json_schema_fails_at_index() {
+        let schema = schema();
+        let results = [
+            json!({"id": 1}),
+            json!({"id": 2}),
+            json!({"id": "3"}),
+            json!({"id": 4}),
+        ]
+        .into_iter()
+        .map(Ok)
+        .ensure_json_schema(&schema, TestErr::SchemaViolation)
+        .collect::<Vec<_>>();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0], Ok(json!({"id": 1})));
+        assert_eq!(results[1], Ok(json!({"id": 2})));
+        assert!(
+            matches!(&results[2], Err(TestErr::SchemaViolation(2, doc, desc)) if *doc == json!({"id": "3"}) && desc.contains("integer"))
+        );
+        assert_eq!(results[3], Ok(json!({"id": 4})));
+    }
+
+    #[test]
+    fn test_ensure_json_schema_ignores_errors() {
+        let schema = schema();
+        let results = [Err(TestErr::Unparsable), Ok(json!({"id": 1}))]
+            .into_iter()
+            .ensure_json_schema(&schema, TestErr::SchemaViolation)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Err(TestErr::Unparsable), Ok(json!({"id": 1}))]
+        )
+    }
+}
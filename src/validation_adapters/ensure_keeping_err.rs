@@ -0,0 +1,99 @@
+use crate::validation_adapters::ensure::EnsureIter;
+
+pub trait EnsureKeepingErr<T, E, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    /// Validates the `Ok` side of an iteration that already carries
+    /// errors, keeping these errors unchanged.
+    ///
+    /// `ensure_keeping_err(test, factory)` behaves exactly like
+    /// [`ensure`](crate::Ensure::ensure): `test` is applied to every element
+    /// wrapped in `Ok(element)`, and `factory` is called on the index and the
+    /// element if the test fails. Elements already wrapped in `Result::Err`,
+    /// for example by an unrelated fallible step before the validation, are
+    /// guaranteed to be passed through unchanged: `test` and `factory` are
+    /// never called on them, and they are not converted in any way.
+    ///
+    /// Use this method to state this guarantee explicitly when validating
+    /// the output of a fallible source, such as a parser.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::EnsureKeepingErr;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum LineErr {
+    ///     Parse(String),
+    ///     Negative(usize, i32),
+    /// }
+    ///
+    /// let mut iter = ["1", "x", "-2"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<i32>().map_err(|_| LineErr::Parse(s.to_string())))
+    ///     .ensure_keeping_err(|v| *v >= 0, LineErr::Negative);
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err(LineErr::Parse("x".to_string()))));
+    /// assert_eq!(iter.next(), Some(Err(LineErr::Negative(2, -2))));
+    /// ```
+    fn ensure_keeping_err(self, test: F, factory: Factory) -> EnsureIter<Self, T, E, F, Factory> {
+        EnsureIter::new(self, test, factory)
+    }
+}
+
+impl<I, T, E, F, Factory> EnsureKeepingErr<T, E, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::EnsureKeepingErr;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Upstream(i32),
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_ensure_keeping_err_passes_errors_through() {
+        let tested = Cell::new(0);
+        let results = [
+            Ok(0),
+            Err(TestErr::Upstream(1)),
+            Ok(2),
+            Ok(3),
+            Err(TestErr::Upstream(4)),
+        ]
+        .into_iter()
+        .ensure_keeping_err(
+            |i| {
+                tested.set(tested.get() + 1);
+                i % 2 == 0
+            },
+            TestErr::IsOdd,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::Upstream(1)),
+                Ok(2),
+                Err(TestErr::IsOdd(3, 3)),
+                Err(TestErr::Upstream(4))
+            ]
+        );
+        assert_eq!(tested.get(), 3)
+    }
+}
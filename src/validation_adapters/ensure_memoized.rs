@@ -0,0 +1,218 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct EnsureMemoizedIter<I, T, E, K, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    M: Fn(&T) -> K,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    iter: IndexOffset<I>,
+    cache: HashMap<K, bool>,
+    key_of: M,
+    test: F,
+    factory: Factory,
+}
+
+impl<I, T, E, K, M, F, Factory> EnsureMemoizedIter<I, T, E, K, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    M: Fn(&T) -> K,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        key_of: M,
+        test: F,
+        factory: Factory,
+    ) -> EnsureMemoizedIter<I, T, E, K, M, F, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            cache: HashMap::new(),
+            key_of,
+            test,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, K, M, F, Factory> Iterator for EnsureMemoizedIter<I, T, E, K, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    M: Fn(&T) -> K,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let key = (self.key_of)(&val);
+                let passed = match self.cache.get(&key) {
+                    Some(passed) => *passed,
+                    None => {
+                        let passed = (self.test)(&val);
+                        self.cache.insert(key, passed);
+                        passed
+                    }
+                };
+                match passed {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, K, M, F, Factory> WithIndexOffset for EnsureMemoizedIter<I, T, E, K, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    M: Fn(&T) -> K,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait EnsureMemoized<T, E, K, M, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash + Clone,
+    M: Fn(&T) -> K,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    /// Same as [`ensure`](crate::Ensure::ensure), but remembers the result
+    /// of `test` for every key, so it is computed once per key.
+    ///
+    /// `ensure_memoized(key_of, test, factory)` computes a key for each
+    /// element wrapped in `Ok`. The first time a key is seen, `test` is called
+    /// on the element and its result is cached. Later elements with the same
+    /// key reuse the cached result instead of calling `test`. Passing elements
+    /// are wrapped in `Ok(element)`, and failing ones are failed by calling
+    /// `factory` on the index of the error and the element.
+    ///
+    /// This is useful when `test` is expensive and the iteration repeats
+    /// the same values many times. `test` must depend only on the key, or
+    /// elements with the same key may get an inaccurate result. Memory grows
+    /// with the number of distinct keys.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::EnsureMemoized;
+    ///
+    /// let is_prime = |n: &u64| *n > 1 && (2..*n).all(|d| n % d != 0);
+    /// let mut iter = [7, 9, 7]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure_memoized(|n| *n, is_prime, |i, n| (i, n));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(7)));
+    /// assert_eq!(iter.next(), Some(Err((1, 9))));
+    /// assert_eq!(iter.next(), Some(Ok(7))); // not tested again
+    /// ```
+    fn ensure_memoized(
+        self,
+        key_of: M,
+        test: F,
+        factory: Factory,
+    ) -> EnsureMemoizedIter<Self, T, E, K, M, F, Factory> {
+        EnsureMemoizedIter::new(self, key_of, test, factory)
+    }
+}
+
+impl<I, T, E, K, M, F, Factory> EnsureMemoized<T, E, K, M, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    M: Fn(&T) -> K,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::EnsureMemoized;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        Corrupt,
+    }
+
+    #[test]
+    fn test_ensure_memoized_runs_once_per_key() {
+        let calls = Cell::new(0);
+        let results = [1, 2, 1, 2, 3, 1, 2]
+            .into_iter()
+            .map(Ok)
+            .ensure_memoized(
+                |i| *i,
+                |i| {
+                    calls.set(calls.get() + 1);
+                    i % 2 == 0
+                },
+                TestErr::IsOdd,
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(calls.get(), 3);
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::IsOdd(0, 1)),
+                Ok(2),
+                Err(TestErr::IsOdd(2, 1)),
+                Ok(2),
+                Err(TestErr::IsOdd(4, 3)),
+                Err(TestErr::IsOdd(5, 1)),
+                Ok(2)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_ensure_memoized_ignores_errors() {
+        let calls = Cell::new(0);
+        let results = [Err(TestErr::Corrupt), Ok(2), Err(TestErr::Corrupt)]
+            .into_iter()
+            .ensure_memoized(
+                |i| *i,
+                |_| {
+                    calls.set(calls.get() + 1);
+                    true
+                },
+                TestErr::IsOdd,
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(calls.get(), 1);
+        assert_eq!(
+            results,
+            vec![Err(TestErr::Corrupt), Ok(2), Err(TestErr::Corrupt)]
+        )
+    }
+}
@@ -0,0 +1,250 @@
+use std::{collections::HashSet, hash::Hash, vec::IntoIter};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct EnsureReferentialIntegrityIter<I, T, E, K, D, DK, RK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    D: Fn(&T) -> bool,
+    DK: Fn(&T) -> K,
+    RK: Fn(&T) -> Option<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    iter: I,
+    buffer: Option<IndexOffset<IntoIter<Result<T, E>>>>,
+    index_offset: usize,
+    defined: HashSet<K>,
+    is_definition: D,
+    defined_key: DK,
+    referenced_key: RK,
+    factory: Factory,
+}
+
+impl<I, T, E, K, D, DK, RK, Factory> EnsureReferentialIntegrityIter<I, T, E, K, D, DK, RK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    D: Fn(&T) -> bool,
+    DK: Fn(&T) -> K,
+    RK: Fn(&T) -> Option<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        is_definition: D,
+        defined_key: DK,
+        referenced_key: RK,
+        factory: Factory,
+    ) -> EnsureReferentialIntegrityIter<I, T, E, K, D, DK, RK, Factory> {
+        Self {
+            iter,
+            buffer: None,
+            index_offset: 0,
+            defined: HashSet::new(),
+            is_definition,
+            defined_key,
+            referenced_key,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, K, D, DK, RK, Factory> Iterator
+    for EnsureReferentialIntegrityIter<I, T, E, K, D, DK, RK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    D: Fn(&T) -> bool,
+    DK: Fn(&T) -> K,
+    RK: Fn(&T) -> Option<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buffer = match &mut self.buffer {
+            Some(buffer) => buffer,
+            None => {
+                let items = self.iter.by_ref().collect::<Vec<_>>();
+                for val in items.iter().flatten() {
+                    if (self.is_definition)(val) {
+                        self.defined.insert((self.defined_key)(val));
+                    }
+                }
+                let mut buffer = IndexOffset::new(items.into_iter());
+                buffer.set_offset(self.index_offset);
+                self.buffer.insert(buffer)
+            }
+        };
+        match buffer.next() {
+            Some((i, Ok(val))) => match (self.referenced_key)(&val) {
+                Some(key) if !self.defined.contains(&key) => Some(Err((self.factory)(i, val, key))),
+                _ => Some(Ok(val)),
+            },
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, K, D, DK, RK, Factory> WithIndexOffset
+    for EnsureReferentialIntegrityIter<I, T, E, K, D, DK, RK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    D: Fn(&T) -> bool,
+    DK: Fn(&T) -> K,
+    RK: Fn(&T) -> Option<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.index_offset = offset;
+        self
+    }
+}
+
+pub trait EnsureReferentialIntegrity<T, E, K, D, DK, RK, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash,
+    D: Fn(&T) -> bool,
+    DK: Fn(&T) -> K,
+    RK: Fn(&T) -> Option<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+    /// Fails any element which references a key that is not defined
+    /// anywhere in the iteration.
+    ///
+    /// `ensure_referential_integrity(is_definition, defined_key, referenced_key, factory)`
+    /// works in two passes. First, the whole iteration is consumed, and for
+    /// each element for which `is_definition` returns `true`, the key
+    /// computed by `defined_key` is collected. Then, the elements are
+    /// yielded in order: if `referenced_key` returns `Some(key)` and `key`
+    /// was not defined, `factory` is called on the index of the error, the
+    /// element, and the key. Otherwise, the element is wrapped in
+    /// `Ok(element)`.
+    ///
+    /// Since definitions are collected before any reference is checked, an
+    /// element may reference a key defined later in the iteration.
+    ///
+    /// **This adapter buffers the whole iteration** on the first call to
+    /// `next`, so its memory cost is linear in the length of the iteration,
+    /// and it can't be used on infinite iterators. Use
+    /// [`deps_precede`](crate::DepsPrecede::deps_precede) if references must
+    /// point backwards, which requires no buffering.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored, and can't define
+    /// keys.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::EnsureReferentialIntegrity;
+    ///
+    /// let doc = ["goto end", "label end", "goto start"];
+    /// let mut iter = doc.into_iter().map(Ok).ensure_referential_integrity(
+    ///     |line| line.starts_with("label "),
+    ///     |line| line.trim_start_matches("label "),
+    ///     |line| line.strip_prefix("goto "),
+    ///     |i, _, key| (i, key),
+    /// );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("goto end")));
+    /// assert_eq!(iter.next(), Some(Ok("label end")));
+    /// assert_eq!(iter.next(), Some(Err((2, "start"))));
+    /// ```
+    fn ensure_referential_integrity(
+        self,
+        is_definition: D,
+        defined_key: DK,
+        referenced_key: RK,
+        factory: Factory,
+    ) -> EnsureReferentialIntegrityIter<Self, T, E, K, D, DK, RK, Factory> {
+        EnsureReferentialIntegrityIter::new(
+            self,
+            is_definition,
+            defined_key,
+            referenced_key,
+            factory,
+        )
+    }
+}
+
+impl<I, T, E, K, D, DK, RK, Factory> EnsureReferentialIntegrity<T, E, K, D, DK, RK, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    D: Fn(&T) -> bool,
+    DK: Fn(&T) -> K,
+    RK: Fn(&T) -> Option<K>,
+    Factory: Fn(usize, T, K) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnsureReferentialIntegrity;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Undefined(usize, u32),
+        Corrupt,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Record {
+        Def(u32),
+        Ref(u32),
+    }
+
+    fn check(records: Vec<Result<Record, TestErr>>) -> Vec<Result<Record, TestErr>> {
+        records
+            .into_iter()
+            .ensure_referential_integrity(
+                |r| matches!(r, Record::Def(_)),
+                |r| match r {
+                    Record::Def(k) | Record::Ref(k) => *k,
+                },
+                |r| match r {
+                    Record::Ref(k) => Some(*k),
+                    Record::Def(_) => None,
+                },
+                |i, _, key| TestErr::Undefined(i, key),
+            )
+            .collect()
+    }
+
+    #[test]
+    fn test_ensure_referential_integrity_forward_reference() {
+        let records = vec![Ok(Record::Ref(2)), Ok(Record::Def(1)), Ok(Record::Def(2))];
+        assert_eq!(
+            check(records),
+            vec![Ok(Record::Ref(2)), Ok(Record::Def(1)), Ok(Record::Def(2))]
+        )
+    }
+
+    #[test]
+    fn test_ensure_referential_integrity_undefined_key() {
+        let records = vec![Ok(Record::Def(1)), Ok(Record::Ref(1)), Ok(Record::Ref(3))];
+        assert_eq!(
+            check(records),
+            vec![
+                Ok(Record::Def(1)),
+                Ok(Record::Ref(1)),
+                Err(TestErr::Undefined(2, 3))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_ensure_referential_integrity_ignores_errors() {
+        let records = vec![Err(TestErr::Corrupt), Ok(Record::Ref(1))];
+        assert_eq!(
+            check(records),
+            vec![Err(TestErr::Corrupt), Err(TestErr::Undefined(1, 1))]
+        )
+    }
+}
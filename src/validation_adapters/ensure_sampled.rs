@@ -0,0 +1,206 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct EnsureSampledIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    iter: IndexOffset<I>,
+    k: usize,
+    ok_counter: usize,
+    validation: F,
+    factory: Factory,
+}
+
+impl<I, T, E, F, Factory> EnsureSampledIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        k: usize,
+        validation: F,
+        factory: Factory,
+    ) -> EnsureSampledIter<I, T, E, F, Factory> {
+        EnsureSampledIter {
+            iter: IndexOffset::new(iter),
+            k,
+            ok_counter: 0,
+            validation,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, F, Factory> Iterator for EnsureSampledIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                // with k == 0 nothing is sampled
+                let sampled = self.k != 0 && self.ok_counter % self.k == 0;
+                self.ok_counter += 1;
+                match !sampled || (self.validation)(&val) {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, F, Factory> WithIndexOffset for EnsureSampledIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait EnsureSampled<T, E, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    /// Applies a boolean test to every `k`th element only.
+    ///
+    /// `ensure_sampled(k, test, factory)` works like
+    /// [`ensure`](crate::Ensure::ensure), but `test` is only applied to the
+    /// first element wrapped in `Ok`, and then to every `k`th `Ok` element
+    /// after it. All other elements are wrapped in `Ok(element)` without
+    /// being tested. If a sampled element fails the test, `factory` is
+    /// called on the index of the error in the iteration (and not in the
+    /// sample) and the element.
+    ///
+    /// This trades completeness for throughput, when testing every element
+    /// of a large iteration is too expensive. If `k` is 1, all elements are
+    /// tested, and if `k` is 0, no element is tested.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored, and are not
+    /// counted when sampling.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::EnsureSampled;
+    ///
+    /// let mut iter = [-1, -2, -3, -4]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure_sampled(3, |v| *v >= 0, |i, v| (i, v));
+    ///
+    /// assert_eq!(iter.next(), Some(Err((0, -1))));
+    /// assert_eq!(iter.next(), Some(Ok(-2)));
+    /// assert_eq!(iter.next(), Some(Ok(-3)));
+    /// assert_eq!(iter.next(), Some(Err((3, -4))));
+    /// ```
+    fn ensure_sampled(
+        self,
+        k: usize,
+        test: F,
+        factory: Factory,
+    ) -> EnsureSampledIter<Self, T, E, F, Factory> {
+        EnsureSampledIter::new(self, k, test, factory)
+    }
+}
+
+impl<I, T, E, F, Factory> EnsureSampled<T, E, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ensure, EnsureSampled};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        IsZero(usize, i32),
+    }
+
+    #[test]
+    fn test_ensure_sampled_only_fails_sampled_positions() {
+        let results = [1, 3, 5, 7, 9, 11, 13]
+            .into_iter()
+            .map(Ok)
+            .ensure_sampled(3, |i| i % 2 == 0, TestErr::IsOdd)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::IsOdd(0, 1)),
+                Ok(3),
+                Ok(5),
+                Err(TestErr::IsOdd(3, 7)),
+                Ok(9),
+                Ok(11),
+                Err(TestErr::IsOdd(6, 13))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_ensure_sampled_with_k_0_and_1() {
+        if (1..10)
+            .map(Ok)
+            .ensure_sampled(0, |i| i % 2 == 0, TestErr::IsOdd)
+            .any(|res| res.is_err())
+        {
+            panic!("ensure sampled tested an element when k is 0")
+        }
+
+        let sampled = (1..10)
+            .map(Ok)
+            .ensure_sampled(1, |i| i % 2 == 0, TestErr::IsOdd)
+            .collect::<Vec<_>>();
+        let ensured = (1..10)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .collect::<Vec<_>>();
+        assert_eq!(sampled, ensured)
+    }
+
+    #[test]
+    fn test_ensure_sampled_ignores_errors() {
+        let results = [0, 1, 3, 5]
+            .into_iter()
+            .map(Ok)
+            .ensure(|i| *i != 0, TestErr::IsZero)
+            .ensure_sampled(2, |i| i % 2 == 0, TestErr::IsOdd)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::IsZero(0, 0)),
+                Err(TestErr::IsOdd(1, 1)),
+                Ok(3),
+                Err(TestErr::IsOdd(3, 5))
+            ]
+        )
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    cell::{Ref, RefCell},
+    rc::Rc,
+};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+/// A handle to a state shared by the [`ensure_shared`](crate::EnsureShared::ensure_shared)
+/// validations of several iterations.
+///
+/// Clones of the handle refer to the same state.
+#[derive(Debug, Default)]
+pub struct SharedValidator<S> {
+    state: Rc<RefCell<S>>,
+}
+
+impl<S> Clone for SharedValidator<S> {
+    fn clone(&self) -> Self {
+        SharedValidator {
+            state: Rc::clone(&self.state),
+        }
+    }
+}
+
+impl<S> SharedValidator<S> {
+    /// Creates a new handle to `state`.
+    pub fn new(state: S) -> SharedValidator<S> {
+        SharedValidator {
+            state: Rc::new(RefCell::new(state)),
+        }
+    }
+
+    /// Immutably borrows the shared state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within the test of an `ensure_shared`
+    /// validation using this handle.
+    pub fn borrow(&self) -> Ref<'_, S> {
+        self.state.borrow()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnsureSharedIter<I, T, E, S, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&mut S, &T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    iter: IndexOffset<I>,
+    validator: SharedValidator<S>,
+    test: F,
+    factory: Factory,
+}
+
+impl<I, T, E, S, F, Factory> EnsureSharedIter<I, T, E, S, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&mut S, &T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        validator: &SharedValidator<S>,
+        test: F,
+        factory: Factory,
+    ) -> EnsureSharedIter<I, T, E, S, F, Factory> {
+        EnsureSharedIter {
+            iter: IndexOffset::new(iter),
+            validator: validator.clone(),
+            test,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, S, F, Factory> Iterator for EnsureSharedIter<I, T, E, S, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&mut S, &T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let passed = (self.test)(&mut self.validator.state.borrow_mut(), &val);
+                match passed {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, S, F, Factory> WithIndexOffset for EnsureSharedIter<I, T, E, S, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&mut S, &T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait EnsureShared<T, E, S, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: Fn(&mut S, &T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+    /// Same as [`ensure`](crate::Ensure::ensure), but with a test that
+    /// can read and update a state shared with other iterations.
+    ///
+    /// `ensure_shared(validator, test, factory)` calls `test` on the state
+    /// behind `validator` and each element wrapped in `Ok`. If it returns
+    /// `true`, the element is wrapped in `Ok(element)`. Otherwise, `factory`
+    /// is called on the index of the error and the element.
+    ///
+    /// Since every iteration validated with a clone of the same
+    /// [`SharedValidator`] sees the changes made by the others, one iteration
+    /// can record facts that another one checks against. Once all of them
+    /// are consumed, the final state can be inspected with
+    /// [`SharedValidator::borrow`].
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::HashSet;
+    /// use validiter::{EnsureShared, SharedValidator};
+    ///
+    /// let declared = SharedValidator::new(HashSet::new());
+    /// let declarations = ["x", "y"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure_shared(&declared, |names, name| names.insert(*name), |i, _| i)
+    ///     .collect::<Result<Vec<_>, _>>();
+    /// let uses = ["y", "z"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure_shared(&declared, |names, name| names.contains(name), |i, _| i)
+    ///     .collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(declarations, Ok(vec!["x", "y"]));
+    /// assert_eq!(uses, Err(1));
+    /// ```
+    fn ensure_shared(
+        self,
+        validator: &SharedValidator<S>,
+        test: F,
+        factory: Factory,
+    ) -> EnsureSharedIter<Self, T, E, S, F, Factory> {
+        EnsureSharedIter::new(self, validator, test, factory)
+    }
+}
+
+impl<I, T, E, S, F, Factory> EnsureShared<T, E, S, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&mut S, &T) -> bool,
+    Factory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::SharedValidator;
+    use crate::EnsureShared;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        DuplicateId(usize, u32),
+        UnknownId(usize, u32),
+    }
+
+    fn consume_all(a: &[u32], b: &[u32]) -> (Vec<Result<u32, TestErr>>, HashSet<u32>) {
+        let validator = SharedValidator::new(HashSet::new());
+        let mut results = a
+            .iter()
+            .copied()
+            .map(Ok)
+            .ensure_shared(&validator, |ids, id| ids.insert(*id), TestErr::DuplicateId)
+            .collect::<Vec<_>>();
+        results.extend(b.iter().copied().map(Ok).ensure_shared(
+            &validator,
+            |ids, id| ids.remove(id),
+            TestErr::UnknownId,
+        ));
+        let unused = validator.borrow().clone();
+        (results, unused)
+    }
+
+    #[test]
+    fn test_ensure_shared_every_id_consumed() {
+        let (results, unused) = consume_all(&[1, 2, 3], &[3, 1, 2]);
+        assert!(results.iter().all(|res| res.is_ok()));
+        assert!(unused.is_empty())
+    }
+
+    #[test]
+    fn test_ensure_shared_unused_id() {
+        let (results, unused) = consume_all(&[1, 2, 3], &[3, 1, 4]);
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Ok(2),
+                Ok(3),
+                Ok(3),
+                Ok(1),
+                Err(TestErr::UnknownId(2, 4))
+            ]
+        );
+        assert_eq!(unused, HashSet::from([2]))
+    }
+
+    #[test]
+    fn test_ensure_shared_interleaved() {
+        let validator = SharedValidator::new(0);
+        let mut producer = (0..3).map(Ok).ensure_shared(
+            &validator,
+            |count, _| {
+                *count += 1;
+                true
+            },
+            TestErr::DuplicateId,
+        );
+        let mut consumer = (0..3).map(Ok).ensure_shared(
+            &validator,
+            |count, _| match *count {
+                0 => false,
+                _ => {
+                    *count -= 1;
+                    true
+                }
+            },
+            TestErr::UnknownId,
+        );
+        assert_eq!(consumer.next(), Some(Err(TestErr::UnknownId(0, 0))));
+        assert_eq!(producer.next(), Some(Ok(0)));
+        assert_eq!(consumer.next(), Some(Ok(1)));
+        let count = *validator.borrow();
+        assert_eq!(count, 0)
+    }
+}
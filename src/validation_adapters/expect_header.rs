@@ -0,0 +1,158 @@
+#[derive(Debug, Clone)]
+pub struct ExpectHeaderIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<Vec<String>, E>>,
+    Factory: Fn(Vec<String>, Vec<String>) -> E,
+{
+    iter: I,
+    expected: Option<Vec<String>>,
+    factory: Factory,
+}
+
+impl<I, E, Factory> ExpectHeaderIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<Vec<String>, E>>,
+    Factory: Fn(Vec<String>, Vec<String>) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        expected: Vec<String>,
+        factory: Factory,
+    ) -> ExpectHeaderIter<I, E, Factory> {
+        ExpectHeaderIter {
+            iter,
+            expected: Some(expected),
+            factory,
+        }
+    }
+}
+
+impl<I, E, Factory> Iterator for ExpectHeaderIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<Vec<String>, E>>,
+    Factory: Fn(Vec<String>, Vec<String>) -> E,
+{
+    type Item = Result<Vec<String>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(row) => match self.expected.take() {
+                    Some(expected) if expected == row => continue,
+                    Some(expected) => return Some(Err((self.factory)(expected, row))),
+                    None => return Some(Ok(row)),
+                },
+                err => return Some(err),
+            }
+        }
+    }
+}
+
+pub trait ExpectHeader<E, Factory>: Iterator<Item = Result<Vec<String>, E>> + Sized
+where
+    Factory: Fn(Vec<String>, Vec<String>) -> E,
+{
+    /// Validates the header row of an iteration over rows, and strips it.
+    ///
+    /// `expect_header(expected, factory)` compares the first row wrapped in
+    /// `Ok` to `expected`. If they are equal, the header is consumed, and is
+    /// not yielded. Otherwise, `factory` is called on the expected header and
+    /// the actual first row, and the result is yielded in place of the
+    /// header. All subsequent rows are yielded unchanged.
+    ///
+    /// If the iteration contains no rows, nothing is reported. Use
+    /// [`at_least`](crate::AtLeast::at_least) before this adapter to require
+    /// a header. Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{fields, ExpectHeader};
+    ///
+    /// let csv = "name, age\nalice, 30\nbob, 25";
+    /// let rows = csv
+    ///     .lines()
+    ///     .map(|line| fields(line, ',').map(|f| f.map(str::to_string)).collect::<Result<Vec<_>, ()>>())
+    ///     .expect_header(vec!["name".to_string(), "age".to_string()], |_, _| ())
+    ///     .collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(
+    ///     rows,
+    ///     Ok(vec![
+    ///         vec!["alice".to_string(), "30".to_string()],
+    ///         vec!["bob".to_string(), "25".to_string()],
+    ///     ])
+    /// );
+    /// ```
+    fn expect_header(
+        self,
+        expected: Vec<String>,
+        factory: Factory,
+    ) -> ExpectHeaderIter<Self, E, Factory> {
+        ExpectHeaderIter::new(self, expected, factory)
+    }
+}
+
+impl<I, E, Factory> ExpectHeader<E, Factory> for I
+where
+    I: Iterator<Item = Result<Vec<String>, E>>,
+    Factory: Fn(Vec<String>, Vec<String>) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ExpectHeader;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadHeader(Vec<String>, Vec<String>),
+        Unreadable,
+    }
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn test_expect_header_consumes_matching_header() {
+        let results = [row(&["id", "name"]), row(&["1", "a"]), row(&["id", "name"])]
+            .into_iter()
+            .map(Ok)
+            .expect_header(row(&["id", "name"]), TestErr::BadHeader)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(row(&["1", "a"])), Ok(row(&["id", "name"]))]
+        )
+    }
+
+    #[test]
+    fn test_expect_header_mismatch() {
+        let results = [row(&["name", "id"]), row(&["1", "a"])]
+            .into_iter()
+            .map(Ok)
+            .expect_header(row(&["id", "name"]), TestErr::BadHeader)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::BadHeader(
+                    row(&["id", "name"]),
+                    row(&["name", "id"])
+                )),
+                Ok(row(&["1", "a"]))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_expect_header_ignores_errors() {
+        let results = [Err(TestErr::Unreadable), Ok(row(&["id"])), Ok(row(&["1"]))]
+            .into_iter()
+            .expect_header(row(&["id"]), TestErr::BadHeader)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::Unreadable), Ok(row(&["1"]))])
+    }
+}
@@ -0,0 +1,229 @@
+use std::{cell::Cell, rc::Rc};
+
+/// A handle connecting [`named`](crate::Named::named) stages of a
+/// validation chain to an [`explain`](crate::Explain::explain) call at its
+/// end.
+#[derive(Debug, Clone, Default)]
+pub struct Explainer {
+    culprit: Rc<Cell<Option<&'static str>>>,
+}
+
+impl Explainer {
+    /// Creates a new handle, with no stage recorded.
+    pub fn new() -> Explainer {
+        Explainer::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NamedIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    iter: I,
+    name: &'static str,
+    explainer: Explainer,
+}
+
+impl<I, T, E> NamedIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I, name: &'static str, explainer: &Explainer) -> NamedIter<I, T, E> {
+        NamedIter {
+            iter,
+            name,
+            explainer: explainer.clone(),
+        }
+    }
+}
+
+impl<I, T, E> Iterator for NamedIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let culprit = &self.explainer.culprit;
+        if item.is_err() && culprit.get().is_none() {
+            culprit.set(Some(self.name));
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplainIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    iter: I,
+    explainer: Explainer,
+}
+
+impl<I, T, E> ExplainIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I, explainer: &Explainer) -> ExplainIter<I, T, E> {
+        ExplainIter {
+            iter,
+            explainer: explainer.clone(),
+        }
+    }
+}
+
+impl<I, T, E> Iterator for ExplainIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = (Option<&'static str>, Result<T, E>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.explainer.culprit.set(None);
+        let item = self.iter.next()?;
+        let culprit = self.explainer.culprit.take();
+        match item {
+            Ok(val) => Some((None, Ok(val))),
+            Err(err) => Some((culprit, Err(err))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait Named<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Names the stage of a validation chain ending at this point.
+    ///
+    /// `named(name, explainer)` yields all elements unchanged. When an
+    /// element wrapped in `Result::Err` passes through it, and no earlier
+    /// named stage already claimed the error, `name` is recorded in
+    /// `explainer` as the stage that failed the element. A stage consists of
+    /// all adapters applied after the previous `named` call (or from the
+    /// start of the chain), up to this one.
+    ///
+    /// The recorded names are reported by
+    /// [`explain`](crate::Explain::explain), using the same [`Explainer`].
+    ///
+    /// # Examples
+    ///
+    /// See [`explain`](crate::Explain::explain).
+    fn named(self, name: &'static str, explainer: &Explainer) -> NamedIter<Self, T, E> {
+        NamedIter::new(self, name, explainer)
+    }
+}
+
+impl<I, T, E> Named<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+pub trait Explain<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Attaches to each result of a validation chain the name of the
+    /// stage which failed it.
+    ///
+    /// `explain(explainer)` yields a pair for each element: the element
+    /// itself, and the name of the first [`named`](crate::Named::named)
+    /// stage, registered with the same `explainer`, through which the
+    /// element passed as an error. Elements wrapped in `Ok` and errors which
+    /// did not pass through any named stage are paired with `None`.
+    ///
+    /// This is a debugging aid for long chains of validations. Since the
+    /// stages communicate through the shared `explainer`, the resulting
+    /// iterator is not [`Send`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{AtMost, Ensure, Explain, Explainer, Named};
+    ///
+    /// let explainer = Explainer::new();
+    /// let trace = (0..5)
+    ///     .map(Ok)
+    ///     .ensure(|i| i % 2 == 0, |_, i| i)
+    ///     .named("even", &explainer)
+    ///     .at_most(2, |_, i| i)
+    ///     .named("at most 2", &explainer)
+    ///     .explain(&explainer)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     trace,
+    ///     vec![
+    ///         (None, Ok(0)),
+    ///         (Some("even"), Err(1)),
+    ///         (None, Ok(2)),
+    ///         (Some("even"), Err(3)),
+    ///         (Some("at most 2"), Err(4)),
+    ///     ]
+    /// );
+    /// ```
+    fn explain(self, explainer: &Explainer) -> ExplainIter<Self, T, E> {
+        ExplainIter::new(self, explainer)
+    }
+}
+
+impl<I, T, E> Explain<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtLeast, Ensure, Explain, Explainer, Named};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Upstream,
+        IsOdd(usize, i32),
+        TooBig(usize, i32),
+        TooFew(usize),
+    }
+
+    #[test]
+    fn test_explain_attributes_failure_to_stage() {
+        let explainer = Explainer::new();
+        let trace = [2, 3, 12, 13]
+            .into_iter()
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .named("parity", &explainer)
+            .ensure(|i| *i < 10, TestErr::TooBig)
+            .named("size", &explainer)
+            .explain(&explainer)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            trace,
+            vec![
+                (None, Ok(2)),
+                (Some("parity"), Err(TestErr::IsOdd(1, 3))),
+                (Some("size"), Err(TestErr::TooBig(2, 12))),
+                (Some("parity"), Err(TestErr::IsOdd(3, 13)))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_explain_unnamed_and_trailing_errors() {
+        let explainer = Explainer::new();
+        let trace = [Err(TestErr::Upstream), Ok(1)]
+            .into_iter()
+            .explain(&explainer)
+            .collect::<Vec<_>>();
+        assert_eq!(trace, vec![(None, Err(TestErr::Upstream)), (None, Ok(1))]);
+
+        let trace = [Ok(0)]
+            .into_iter()
+            .at_least(2, TestErr::TooFew)
+            .named("count", &explainer)
+            .explain(&explainer)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            trace,
+            vec![(None, Ok(0)), (Some("count"), Err(TestErr::TooFew(1)))]
+        )
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct FiniteIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<f64, E>>,
+    Factory: Fn(usize, f64) -> E,
+{
+    iter: IndexOffset<I>,
+    factory: Factory,
+}
+
+impl<I, E, Factory> FiniteIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<f64, E>>,
+    Factory: Fn(usize, f64) -> E,
+{
+    pub(crate) fn new(iter: I, factory: Factory) -> FiniteIter<I, E, Factory> {
+        FiniteIter {
+            iter: IndexOffset::new(iter),
+            factory,
+        }
+    }
+}
+
+impl<I, E, Factory> Iterator for FiniteIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<f64, E>>,
+    Factory: Fn(usize, f64) -> E,
+{
+    type Item = Result<f64, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => match val.is_finite() {
+                true => Some(Ok(val)),
+                false => Some(Err((self.factory)(i, val))),
+            },
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, E, Factory> WithIndexOffset for FiniteIter<I, E, Factory>
+where
+    I: Iterator<Item = Result<f64, E>>,
+    Factory: Fn(usize, f64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait Finite<E, Factory>: Iterator<Item = Result<f64, E>> + Sized
+where
+    Factory: Fn(usize, f64) -> E,
+{
+    /// Fails any element of a float iteration which is not finite.
+    ///
+    /// `finite(factory)` wraps every finite `f64` in `Ok(element)`. Values
+    /// which are `NaN`, `+inf` or `-inf` are passed to `factory` together
+    /// with the index of the error.
+    ///
+    /// Comparisons with `NaN` are always false, and infinite values compare
+    /// with other values as expected, so range based validations can treat
+    /// these values inconsistently. Applying `finite` before such
+    /// validations removes this subtlety.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::Finite;
+    ///
+    /// let mut iter = [1.0, f64::INFINITY, 0.5]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .finite(|index, val| (index, val));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1.0)));
+    /// assert_eq!(iter.next(), Some(Err((1, f64::INFINITY))));
+    /// assert_eq!(iter.next(), Some(Ok(0.5)));
+    /// ```
+    fn finite(self, factory: Factory) -> FiniteIter<Self, E, Factory> {
+        FiniteIter::new(self, factory)
+    }
+}
+
+impl<I, E, Factory> Finite<E, Factory> for I
+where
+    I: Iterator<Item = Result<f64, E>>,
+    Factory: Fn(usize, f64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Finite;
+
+    #[derive(Debug)]
+    enum TestErr {
+        NotFinite(usize, f64),
+        Other,
+    }
+
+    #[test]
+    fn test_finite_fails_non_finite_values() {
+        let results = [0.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -2.5]
+            .into_iter()
+            .map(Ok)
+            .finite(TestErr::NotFinite)
+            .collect::<Vec<_>>();
+        assert!(matches!(results[0], Ok(v) if v == 0.0));
+        assert!(matches!(results[1], Err(TestErr::NotFinite(1, v)) if v.is_nan()));
+        assert!(matches!(
+            results[2],
+            Err(TestErr::NotFinite(2, f64::INFINITY))
+        ));
+        assert!(matches!(
+            results[3],
+            Err(TestErr::NotFinite(3, f64::NEG_INFINITY))
+        ));
+        assert!(matches!(results[4], Ok(v) if v == -2.5));
+    }
+
+    #[test]
+    fn test_finite_passes_extreme_finite_values() {
+        if [f64::MAX, f64::MIN, f64::MIN_POSITIVE, f64::EPSILON]
+            .into_iter()
+            .map(Ok)
+            .finite(TestErr::NotFinite)
+            .any(|res| res.is_err())
+        {
+            panic!("finite failed on a finite value")
+        }
+    }
+
+    #[test]
+    fn test_finite_ignores_errors() {
+        let results = [Err(TestErr::Other), Ok(f64::NAN)]
+            .into_iter()
+            .finite(TestErr::NotFinite)
+            .collect::<Vec<_>>();
+        assert!(matches!(results[0], Err(TestErr::Other)));
+        assert!(matches!(results[1], Err(TestErr::NotFinite(1, _))));
+    }
+}
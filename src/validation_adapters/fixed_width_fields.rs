@@ -0,0 +1,201 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct FixedWidthFieldsIter<'a, I, E, Factory>
+where
+    I: Iterator<Item = Result<&'a str, E>>,
+    Factory: Fn(usize, &'a str, usize) -> E,
+{
+    iter: IndexOffset<I>,
+    line_len: usize,
+    offsets: Vec<usize>,
+    factory: Factory,
+}
+
+impl<'a, I, E, Factory> FixedWidthFieldsIter<'a, I, E, Factory>
+where
+    I: Iterator<Item = Result<&'a str, E>>,
+    Factory: Fn(usize, &'a str, usize) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        line_len: usize,
+        offsets: Vec<usize>,
+        factory: Factory,
+    ) -> FixedWidthFieldsIter<'a, I, E, Factory> {
+        assert!(
+            offsets.windows(2).all(|pair| pair[0] <= pair[1])
+                && offsets.last().map_or(true, |last| *last <= line_len),
+            "column offsets must be sorted, and not exceed the line length"
+        );
+        Self {
+            iter: IndexOffset::new(iter),
+            line_len,
+            offsets,
+            factory,
+        }
+    }
+
+    fn split(&self, line: &'a str) -> Vec<&'a str> {
+        // byte positions of every char boundary, indexed by char position
+        let boundaries = line
+            .char_indices()
+            .map(|(byte, _)| byte)
+            .chain(Some(line.len()))
+            .collect::<Vec<_>>();
+        let ends = self.offsets.iter().skip(1).chain(Some(&self.line_len));
+        self.offsets
+            .iter()
+            .zip(ends)
+            .map(|(start, end)| &line[boundaries[*start]..boundaries[*end]])
+            .collect()
+    }
+}
+
+impl<'a, I, E, Factory> Iterator for FixedWidthFieldsIter<'a, I, E, Factory>
+where
+    I: Iterator<Item = Result<&'a str, E>>,
+    Factory: Fn(usize, &'a str, usize) -> E,
+{
+    type Item = Result<Vec<&'a str>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(line))) => {
+                let len = line.chars().count();
+                match len == self.line_len {
+                    true => Some(Ok(self.split(line))),
+                    false => Some(Err((self.factory)(i, line, len))),
+                }
+            }
+            Some((_, Err(err))) => Some(Err(err)),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, E, Factory> WithIndexOffset for FixedWidthFieldsIter<'a, I, E, Factory>
+where
+    I: Iterator<Item = Result<&'a str, E>>,
+    Factory: Fn(usize, &'a str, usize) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait FixedWidthFields<'a, E, Factory>: Iterator<Item = Result<&'a str, E>> + Sized
+where
+    Factory: Fn(usize, &'a str, usize) -> E,
+{
+    /// Splits each line of a fixed width text format into its fields, and
+    /// fails lines which are not exactly `line_len` characters long.
+    ///
+    /// `fixed_width_fields(line_len, offsets, factory)` checks the length of
+    /// every line wrapped in `Ok`. If the line is `line_len` characters long,
+    /// it is split into fields, where each field starts at one of the
+    /// `offsets` and ends where the next one starts (or at `line_len`), and
+    /// the fields are wrapped in `Ok(fields)`. Otherwise, `factory` is called
+    /// on the index of the error, the line and its length in characters.
+    ///
+    /// The fields are not trimmed, so they can be further validated after
+    /// flattening or mapping them. Errors for invalid fields are produced by
+    /// those later validations, so `factory` only ever reports lines of the
+    /// wrong length.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offsets` is not sorted, or if any offset is greater than
+    /// `line_len`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::FixedWidthFields;
+    ///
+    /// let file = "0042JOHN  NY\n0043ANN NJ";
+    /// let mut iter = file
+    ///     .lines()
+    ///     .map(Ok)
+    ///     .fixed_width_fields(12, vec![0, 4, 10], |i, _, len| (i, len));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(vec!["0042", "JOHN  ", "NY"])));
+    /// assert_eq!(iter.next(), Some(Err((1, 10))));
+    /// ```
+    fn fixed_width_fields(
+        self,
+        line_len: usize,
+        offsets: Vec<usize>,
+        factory: Factory,
+    ) -> FixedWidthFieldsIter<'a, Self, E, Factory> {
+        FixedWidthFieldsIter::new(self, line_len, offsets, factory)
+    }
+}
+
+impl<'a, I, E, Factory> FixedWidthFields<'a, E, Factory> for I
+where
+    I: Iterator<Item = Result<&'a str, E>>,
+    Factory: Fn(usize, &'a str, usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FixedWidthFields;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        WrongLength(usize, usize),
+        Unreadable,
+    }
+
+    fn wrong_length(index: usize, _: &str, len: usize) -> TestErr {
+        TestErr::WrongLength(index, len)
+    }
+
+    #[test]
+    fn test_fixed_width_fields_split() {
+        let results = ["AB123xyz", "CD456ü  "]
+            .into_iter()
+            .map(Ok)
+            .fixed_width_fields(8, vec![0, 2, 5], wrong_length)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(vec!["AB", "123", "xyz"]), Ok(vec!["CD", "456", "ü  "])]
+        )
+    }
+
+    #[test]
+    fn test_fixed_width_fields_wrong_length() {
+        let results = [Ok("AB123xyz"), Ok("AB123"), Err(TestErr::Unreadable)]
+            .into_iter()
+            .fixed_width_fields(8, vec![0, 2, 5], wrong_length)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(vec!["AB", "123", "xyz"]),
+                Err(TestErr::WrongLength(1, 5)),
+                Err(TestErr::Unreadable)
+            ]
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fixed_width_fields_offset_past_line() {
+        let _ = ["abc"]
+            .into_iter()
+            .map(Ok)
+            .fixed_width_fields(3, vec![0, 4], wrong_length);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct FlagsSubsetIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> u64,
+    Factory: Fn(usize, T, u64) -> E,
+{
+    iter: IndexOffset<I>,
+    allowed_mask: u64,
+    extract_flags: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> FlagsSubsetIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> u64,
+    Factory: Fn(usize, T, u64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        extract_flags: M,
+        allowed_mask: u64,
+        factory: Factory,
+    ) -> FlagsSubsetIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            allowed_mask,
+            extract_flags,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for FlagsSubsetIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> u64,
+    Factory: Fn(usize, T, u64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let unknown = (self.extract_flags)(&val) & !self.allowed_mask;
+                match unknown {
+                    0 => Some(Ok(val)),
+                    _ => Some(Err((self.factory)(i, val, unknown))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for FlagsSubsetIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> u64,
+    Factory: Fn(usize, T, u64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait FlagsSubset<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> u64,
+    Factory: Fn(usize, T, u64) -> E,
+{
+    /// Fails any element with flag bits set outside of an allowed mask.
+    ///
+    /// `flags_subset(extract_flags, allowed_mask, factory)` calls
+    /// `extract_flags` on each element wrapped in `Ok`. If all the set bits
+    /// are also set in `allowed_mask`, the element is wrapped in
+    /// `Ok(element)`. Otherwise, `factory` is called on the index of the
+    /// error, the element, and the offending bits, which are
+    /// `flags & !allowed_mask`.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::FlagsSubset;
+    ///
+    /// let mut iter = [0b011, 0b110]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .flags_subset(|flags| *flags, 0b011, |i, _, unknown| (i, unknown));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0b011)));
+    /// assert_eq!(iter.next(), Some(Err((1, 0b100))));
+    /// ```
+    fn flags_subset(
+        self,
+        extract_flags: M,
+        allowed_mask: u64,
+        factory: Factory,
+    ) -> FlagsSubsetIter<Self, T, E, M, Factory> {
+        FlagsSubsetIter::new(self, extract_flags, allowed_mask, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> FlagsSubset<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> u64,
+    Factory: Fn(usize, T, u64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FlagsSubset;
+
+    const READ: u64 = 1;
+    const WRITE: u64 = 1 << 1;
+    const EXEC: u64 = 1 << 2;
+
+    #[derive(Debug, PartialEq)]
+    struct Record {
+        id: u32,
+        flags: u64,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        UnknownFlags(usize, u32, u64),
+        Corrupt,
+    }
+
+    fn unknown_flags(index: usize, record: Record, bits: u64) -> TestErr {
+        TestErr::UnknownFlags(index, record.id, bits)
+    }
+
+    #[test]
+    fn test_flags_subset_unknown_bit_fails() {
+        let results = [READ, READ | WRITE, WRITE | 1 << 10, 0]
+            .into_iter()
+            .enumerate()
+            .map(|(id, flags)| {
+                Ok(Record {
+                    id: id as u32,
+                    flags,
+                })
+            })
+            .flags_subset(|r| r.flags, READ | WRITE | EXEC, unknown_flags)
+            .map(|res| res.map(|r| r.id))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(1),
+                Err(TestErr::UnknownFlags(2, 2, 1 << 10)),
+                Ok(3)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_flags_subset_empty_mask() {
+        let results = [0, EXEC]
+            .into_iter()
+            .map(|flags| Ok(Record { id: 0, flags }))
+            .flags_subset(|r| r.flags, 0, unknown_flags)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(Record { id: 0, flags: 0 }),
+                Err(TestErr::UnknownFlags(1, 0, EXEC))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_flags_subset_ignores_errors() {
+        let results = [Err(TestErr::Corrupt), Ok(Record { id: 1, flags: READ })]
+            .into_iter()
+            .flags_subset(|r| r.flags, READ, unknown_flags)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Err(TestErr::Corrupt), Ok(Record { id: 1, flags: READ })]
+        )
+    }
+}
@@ -0,0 +1,114 @@
+#[derive(Debug, Clone)]
+pub struct FlattenValidationIter<I, T, E>
+where
+    I: Iterator<Item = Result<Result<T, E>, E>>,
+{
+    iter: I,
+}
+
+impl<I, T, E> FlattenValidationIter<I, T, E>
+where
+    I: Iterator<Item = Result<Result<T, E>, E>>,
+{
+    pub(crate) fn new(iter: I) -> FlattenValidationIter<I, T, E> {
+        FlattenValidationIter { iter }
+    }
+}
+
+impl<I, T, E> Iterator for FlattenValidationIter<I, T, E>
+where
+    I: Iterator<Item = Result<Result<T, E>, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.and_then(|inner| inner))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait FlattenValidation<T, E>: Iterator<Item = Result<Result<T, E>, E>> + Sized {
+    /// Collapses an iteration over nested validation results into a
+    /// validation iterator.
+    ///
+    /// `flatten_validation()` turns every `Ok(Ok(element))` into
+    /// `Ok(element)`, every `Ok(Err(error))` into `Err(error)` (an error from
+    /// an inner validation), and every `Err(error)` into `Err(error)` (an
+    /// error from an outer validation). The position of every element is
+    /// kept.
+    ///
+    /// This is useful when nesting validations: elements are validated and
+    /// collected into a `Result<C, E>` by an inner chain, and the collections
+    /// are then wrapped in `Ok` to be validated by an outer chain.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::FlattenValidation;
+    ///
+    /// let nested: Vec<Result<Result<i32, &str>, &str>> = vec![Ok(Ok(1)), Ok(Err("inner")), Err("outer")];
+    /// let mut iter = nested.into_iter().flatten_validation();
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err("inner")));
+    /// assert_eq!(iter.next(), Some(Err("outer")));
+    /// ```
+    fn flatten_validation(self) -> FlattenValidationIter<Self, T, E> {
+        FlattenValidationIter::new(self)
+    }
+}
+
+impl<I, T, E> FlattenValidation<T, E> for I where I: Iterator<Item = Result<Result<T, E>, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtLeast, AtMost, Ensure, FlattenValidation};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        NotANumber(usize, String),
+        Negative(usize, i32),
+        NoColumns,
+        TooManyRows(usize),
+    }
+
+    fn parse(csv: &str) -> Result<Vec<Vec<i32>>, TestErr> {
+        csv.lines()
+            .map(|line| {
+                Ok(line
+                    .split(',')
+                    .map(str::trim)
+                    .enumerate()
+                    .map(|(j, s)| s.parse().map_err(|_| TestErr::NotANumber(j, s.to_string())))
+                    .ensure(|v| *v >= 0, TestErr::Negative)
+                    .at_least(1, |_| TestErr::NoColumns)
+                    .collect::<Result<Vec<_>, _>>())
+            })
+            .at_most(2, |i, _| TestErr::TooManyRows(i))
+            .flatten_validation()
+            .collect()
+    }
+
+    #[test]
+    fn test_flatten_validation_nested_pipeline_ok() {
+        assert_eq!(parse("1, 2\n3, 4"), Ok(vec![vec![1, 2], vec![3, 4]]))
+    }
+
+    #[test]
+    fn test_flatten_validation_inner_error() {
+        assert_eq!(parse("1, 2\n3, -4"), Err(TestErr::Negative(1, -4)));
+        assert_eq!(
+            parse("1, x\n3, 4"),
+            Err(TestErr::NotANumber(1, "x".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_flatten_validation_outer_error() {
+        assert_eq!(parse("1\n2\n3"), Err(TestErr::TooManyRows(2)))
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct GroupSumBetweenIter<I, T, E, G, Group, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: PartialEq,
+    Group: Fn(&T) -> G,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, G, f64) -> E,
+{
+    iter: IndexOffset<I>,
+    current: Option<(usize, G, f64)>,
+    pending: Option<T>,
+    group_of: Group,
+    extractor: M,
+    lo: f64,
+    hi: f64,
+    factory: Factory,
+}
+
+impl<I, T, E, G, Group, M, Factory> GroupSumBetweenIter<I, T, E, G, Group, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: PartialEq,
+    Group: Fn(&T) -> G,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, G, f64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        group_of: Group,
+        extractor: M,
+        lo: f64,
+        hi: f64,
+        factory: Factory,
+    ) -> GroupSumBetweenIter<I, T, E, G, Group, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            current: None,
+            pending: None,
+            group_of,
+            extractor,
+            lo,
+            hi,
+            factory,
+        }
+    }
+
+    fn close_group(&mut self) -> Option<E> {
+        let (start, group, total) = self.current.take()?;
+        let within = self.lo <= total && total <= self.hi;
+        match within {
+            true => None,
+            false => Some((self.factory)(start, group, total)),
+        }
+    }
+}
+
+impl<I, T, E, G, Group, M, Factory> Iterator for GroupSumBetweenIter<I, T, E, G, Group, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: PartialEq,
+    Group: Fn(&T) -> G,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, G, f64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(val) = self.pending.take() {
+            return Some(Ok(val));
+        }
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let group = (self.group_of)(&val);
+                let amount = (self.extractor)(&val);
+                if let Some((_, current, total)) = &mut self.current {
+                    if *current == group {
+                        *total += amount;
+                        return Some(Ok(val));
+                    }
+                }
+                let out_of_bounds = self.close_group();
+                self.current = Some((i, group, amount));
+                match out_of_bounds {
+                    Some(err) => {
+                        self.pending = Some(val);
+                        Some(Err(err))
+                    }
+                    None => Some(Ok(val)),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => self.close_group().map(Err),
+        }
+    }
+}
+
+impl<I, T, E, G, Group, M, Factory> WithIndexOffset
+    for GroupSumBetweenIter<I, T, E, G, Group, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: PartialEq,
+    Group: Fn(&T) -> G,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, G, f64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait GroupSumBetween<T, E, G, Group, M, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    G: PartialEq,
+    Group: Fn(&T) -> G,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, G, f64) -> E,
+{
+    /// Fails each group of consecutive elements whose total is not between
+    /// `lo` and `hi`.
+    ///
+    /// `group_sum_between(group_of, extractor, lo, hi, factory)` splits the
+    /// iteration into runs of consecutive elements with the same group, as
+    /// computed by `group_of`, and sums the values computed by `extractor`
+    /// over each run. The elements are yielded unchanged as they arrive.
+    ///
+    /// When a group ends, at the first element of the next group or at the
+    /// end of the iteration, its total is checked. If it is outside the
+    /// inclusive range `[lo, hi]`, a new error is yielded right before the
+    /// next group (or at the end), with the value returned from calling
+    /// `factory` on the index of the group's first element, the group and
+    /// its total. A `NaN` total is never in range. Like
+    /// [`at_least`](crate::AtLeast::at_least), the check of the last group
+    /// cannot handle short-circuiting of iterators.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored, and do not end
+    /// the current group.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::GroupSumBetween;
+    ///
+    /// let ledger = [("rent", 900.0), ("food", 150.0), ("food", 400.0), ("fun", 50.0)];
+    /// let mut iter = ledger
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .group_sum_between(|(item, _)| *item, |(_, amount)| *amount, 0.0, 500.0, |i, item, total| (i, item, total));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(("rent", 900.0))));
+    /// assert_eq!(iter.next(), Some(Err((0, "rent", 900.0))));
+    /// assert_eq!(iter.next(), Some(Ok(("food", 150.0))));
+    /// assert_eq!(iter.next(), Some(Ok(("food", 400.0))));
+    /// assert_eq!(iter.next(), Some(Err((1, "food", 550.0))));
+    /// assert_eq!(iter.next(), Some(Ok(("fun", 50.0))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn group_sum_between(
+        self,
+        group_of: Group,
+        extractor: M,
+        lo: f64,
+        hi: f64,
+        factory: Factory,
+    ) -> GroupSumBetweenIter<Self, T, E, G, Group, M, Factory> {
+        GroupSumBetweenIter::new(self, group_of, extractor, lo, hi, factory)
+    }
+}
+
+impl<I, T, E, G, Group, M, Factory> GroupSumBetween<T, E, G, Group, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: PartialEq,
+    Group: Fn(&T) -> G,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, G, f64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::GroupSumBetween;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadTotal(usize, u32, f64),
+        Corrupt,
+    }
+
+    type Entry = (u32, f64);
+
+    fn check(entries: Vec<Result<Entry, TestErr>>) -> Vec<Result<Entry, TestErr>> {
+        entries
+            .into_iter()
+            .group_sum_between(|e| e.0, |e| e.1, -10.0, 10.0, TestErr::BadTotal)
+            .collect()
+    }
+
+    #[test]
+    fn test_group_sum_between_fails_at_boundary() {
+        let results = check(vec![
+            Ok((1, 4.0)),
+            Ok((1, 7.5)),
+            Ok((2, 3.0)),
+            Ok((2, -3.0)),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                Ok((1, 4.0)),
+                Ok((1, 7.5)),
+                Err(TestErr::BadTotal(0, 1, 11.5)),
+                Ok((2, 3.0)),
+                Ok((2, -3.0))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_group_sum_between_fails_at_end() {
+        let results = check(vec![
+            Ok((1, 10.0)),
+            Ok((2, -6.0)),
+            Err(TestErr::Corrupt),
+            Ok((2, -6.0)),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                Ok((1, 10.0)),
+                Ok((2, -6.0)),
+                Err(TestErr::Corrupt),
+                Ok((2, -6.0)),
+                Err(TestErr::BadTotal(1, 2, -12.0))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_group_sum_between_returning_group() {
+        let results = check(vec![Ok((1, 20.0)), Ok((2, 0.0)), Ok((1, -5.0))]);
+        assert_eq!(
+            results,
+            vec![
+                Ok((1, 20.0)),
+                Err(TestErr::BadTotal(0, 1, 20.0)),
+                Ok((2, 0.0)),
+                Ok((1, -5.0))
+            ]
+        )
+    }
+}
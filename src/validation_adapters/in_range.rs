@@ -0,0 +1,263 @@
+use std::ops::RangeBounds;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct InRangeIter<I, T, E, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: PartialOrd,
+    R: RangeBounds<T>,
+    Factory: Fn(usize, T, &R) -> E,
+{
+    iter: IndexOffset<I>,
+    range: R,
+    factory: Factory,
+}
+
+impl<I, T, E, R, Factory> InRangeIter<I, T, E, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: PartialOrd,
+    R: RangeBounds<T>,
+    Factory: Fn(usize, T, &R) -> E,
+{
+    pub(crate) fn new(iter: I, range: R, factory: Factory) -> InRangeIter<I, T, E, R, Factory> {
+        InRangeIter {
+            iter: IndexOffset::new(iter),
+            range,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, R, Factory> Iterator for InRangeIter<I, T, E, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: PartialOrd,
+    R: RangeBounds<T>,
+    Factory: Fn(usize, T, &R) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => match self.range.contains(&val) {
+                true => Some(Ok(val)),
+                false => Some(Err((self.factory)(i, val, &self.range))),
+            },
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, R, Factory> WithIndexOffset for InRangeIter<I, T, E, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: PartialOrd,
+    R: RangeBounds<T>,
+    Factory: Fn(usize, T, &R) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait InRange<T, E, R, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: PartialOrd,
+    R: RangeBounds<T>,
+    Factory: Fn(usize, T, &R) -> E,
+{
+    /// Fails any element which is not contained in `range`.
+    ///
+    /// `in_range(range, factory)` accepts any of the standard range types
+    /// (`a..b`, `a..=b`, `a..`, `..b`, `..=b`, `..`) or any other
+    /// [`RangeBounds`] implementation, so both inclusive and exclusive
+    /// bounds are supported. Elements contained in the range are wrapped in
+    /// `Ok(element)`. Otherwise, `factory` is called on the index of the
+    /// error, the element, and a reference to the range.
+    ///
+    /// Containment is checked with [`RangeBounds::contains`], so for
+    /// floats `NaN` is never in range.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::InRange;
+    ///
+    /// let mut iter = [0, 9, 10].into_iter().map(Ok).in_range(0..10, |i, v, _| (i, v));
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(9)));
+    /// assert_eq!(iter.next(), Some(Err((2, 10))));
+    ///
+    /// let mut iter = [0, 9, 10].into_iter().map(Ok).in_range(0..=10, |i, v, _| (i, v));
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(9)));
+    /// assert_eq!(iter.next(), Some(Ok(10)));
+    /// ```
+    ///
+    /// The range can be used by the factory to report the failed bounds:
+    /// ```
+    /// use std::ops::RangeBounds;
+    /// use validiter::InRange;
+    ///
+    /// let mut iter = [-1.5, 0.5].into_iter().map(Ok).in_range(0.0.., |_, v, range| {
+    ///     format!("{v} is out of {:?}", range.start_bound())
+    /// });
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err("-1.5 is out of Included(0.0)".to_string()))
+    /// );
+    /// assert_eq!(iter.next(), Some(Ok(0.5)));
+    /// ```
+    fn in_range(self, range: R, factory: Factory) -> InRangeIter<Self, T, E, R, Factory> {
+        InRangeIter::new(self, range, factory)
+    }
+}
+
+impl<I, T, E, R, Factory> InRange<T, E, R, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: PartialOrd,
+    R: RangeBounds<T>,
+    Factory: Fn(usize, T, &R) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::{Bound, RangeBounds};
+
+    use crate::InRange;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr<T> {
+        OutOfRange(usize, T, Bound<T>, Bound<T>),
+        Other,
+    }
+
+    fn out_of_range<T: Copy, R: RangeBounds<T>>(index: usize, val: T, range: &R) -> TestErr<T> {
+        TestErr::OutOfRange(
+            index,
+            val,
+            range.start_bound().cloned(),
+            range.end_bound().cloned(),
+        )
+    }
+
+    #[test]
+    fn test_in_range_half_open() {
+        let results = (-1..=10)
+            .map(Ok)
+            .in_range(0..10, out_of_range)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results[0],
+            Err(TestErr::OutOfRange(
+                0,
+                -1,
+                Bound::Included(0),
+                Bound::Excluded(10)
+            ))
+        );
+        assert!(results[1..11].iter().all(|res| res.is_ok()));
+        assert_eq!(
+            results[11],
+            Err(TestErr::OutOfRange(
+                11,
+                10,
+                Bound::Included(0),
+                Bound::Excluded(10)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_in_range_closed() {
+        let results = [0, 10, 11]
+            .into_iter()
+            .map(Ok)
+            .in_range(0..=10, out_of_range)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(10),
+                Err(TestErr::OutOfRange(
+                    2,
+                    11,
+                    Bound::Included(0),
+                    Bound::Included(10)
+                ))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_in_range_unbounded_on_one_side() {
+        let results = [i32::MIN, 0, 1]
+            .into_iter()
+            .map(Ok)
+            .in_range(..1, out_of_range)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(i32::MIN),
+                Ok(0),
+                Err(TestErr::OutOfRange(
+                    2,
+                    1,
+                    Bound::Unbounded,
+                    Bound::Excluded(1)
+                ))
+            ]
+        );
+
+        let results = [0.0, f64::INFINITY, -0.1]
+            .into_iter()
+            .map(Ok)
+            .in_range(0.0.., out_of_range)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0.0),
+                Ok(f64::INFINITY),
+                Err(TestErr::OutOfRange(
+                    2,
+                    -0.1,
+                    Bound::Included(0.0),
+                    Bound::Unbounded
+                ))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_in_range_ignores_errors() {
+        let results = [Err(TestErr::Other), Ok(5)]
+            .into_iter()
+            .in_range(0..5, out_of_range)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Other),
+                Err(TestErr::OutOfRange(
+                    1,
+                    5,
+                    Bound::Included(0),
+                    Bound::Excluded(5)
+                ))
+            ]
+        )
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::Sub;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct IncreasingByAtLeastIter<I, T, E, A, D, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Sub<Output = D> + PartialOrd + Clone,
+    D: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    iter: IndexOffset<I>,
+    delta: D,
+    previous: Option<A>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, A, D, M, Factory> IncreasingByAtLeastIter<I, T, E, A, D, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Sub<Output = D> + PartialOrd + Clone,
+    D: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        delta: D,
+        extractor: M,
+        factory: Factory,
+    ) -> IncreasingByAtLeastIter<I, T, E, A, D, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            delta,
+            previous: None,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, D, M, Factory> Iterator for IncreasingByAtLeastIter<I, T, E, A, D, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Sub<Output = D> + PartialOrd + Clone,
+    D: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                match &self.previous {
+                    // checking the order first keeps unsigned values from
+                    // overflowing on the subtraction
+                    Some(prev) => match extraction >= *prev
+                        && extraction.clone() - prev.clone() >= self.delta
+                    {
+                        true => {
+                            self.previous = Some(extraction);
+                            Some(Ok(val))
+                        }
+                        false => Some(Err((self.factory)(i, val, extraction, prev))),
+                    },
+                    None => {
+                        self.previous = Some(extraction);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, Err(e))) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, A, D, M, Factory> WithIndexOffset
+    for IncreasingByAtLeastIter<I, T, E, A, D, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Sub<Output = D> + PartialOrd + Clone,
+    D: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait IncreasingByAtLeast<T, E, A, D, M, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    A: Sub<Output = D> + PartialOrd + Clone,
+    D: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    /// Fails an iteration if the values computed by `extractor` do not
+    /// increase by at least `delta` between consecutive elements.
+    ///
+    /// `increasing_by_at_least(delta, extractor, factory)` computes the step
+    /// `current - previous` between the value extracted from each element and
+    /// the value extracted from the last valid element. If the step is
+    /// greater or equal to `delta`, the element is wrapped in `Ok(element)`.
+    /// Otherwise, `factory` is called on the index of the error, the element,
+    /// the extracted value, and the previous value. The first valid element
+    /// is always wrapped in `Ok`, and sets the baseline.
+    ///
+    /// A value lower than the previous value always fails, before the step
+    /// is computed, so unsigned values can be validated without overflowing.
+    ///
+    /// The step type may differ from the extracted type, so for example
+    /// [`Instant`](std::time::Instant) values can be validated against a
+    /// [`Duration`](std::time::Duration) delta.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::IncreasingByAtLeast;
+    ///
+    /// let timestamps = [0, 1, 2, 2, 5];
+    /// let mut iter = timestamps
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .increasing_by_at_least(1, |t| *t, |i, _, t, prev| (i, t, *prev));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), Some(Err((3, 2, 2))));
+    /// assert_eq!(iter.next(), Some(Ok(5)));
+    /// ```
+    fn increasing_by_at_least(
+        self,
+        delta: D,
+        extractor: M,
+        factory: Factory,
+    ) -> IncreasingByAtLeastIter<Self, T, E, A, D, M, Factory> {
+        IncreasingByAtLeastIter::new(self, delta, extractor, factory)
+    }
+}
+
+impl<I, T, E, A, D, M, Factory> IncreasingByAtLeast<T, E, A, D, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Sub<Output = D> + PartialOrd + Clone,
+    D: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::IncreasingByAtLeast;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr<T> {
+        SmallStep(usize, T, T),
+        IsNegative(T),
+    }
+
+    fn small_step<T: Copy>(index: usize, _: T, extraction: T, prev: &T) -> TestErr<T> {
+        TestErr::SmallStep(index, extraction, *prev)
+    }
+
+    #[test]
+    fn test_increasing_by_at_least_exact_delta_passes() {
+        if (0..10)
+            .map(|i| Ok(i * 3))
+            .increasing_by_at_least(3, |i| *i, small_step)
+            .any(|res| res.is_err())
+        {
+            panic!("increasing by at least failed on a step equal to delta")
+        }
+    }
+
+    #[test]
+    fn test_increasing_by_at_least_small_step_fails() {
+        let results = [0.0, 1.0, 1.5, 2.0]
+            .into_iter()
+            .map(Ok)
+            .increasing_by_at_least(1.0, |f| *f, small_step)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0.0),
+                Ok(1.0),
+                Err(TestErr::SmallStep(2, 1.5, 1.0)),
+                Ok(2.0)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_increasing_by_at_least_different_step_type() {
+        let start = Instant::now();
+        let results = [0, 10, 15]
+            .into_iter()
+            .map(|ms| Ok::<_, TestErr<Instant>>(start + Duration::from_millis(ms)))
+            .increasing_by_at_least(Duration::from_millis(10), |t| *t, small_step)
+            .map(|res| res.is_ok())
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![true, true, false])
+    }
+
+    #[test]
+    fn test_increasing_by_at_least_unsigned_decrease() {
+        let results = [5u64, 7, 3, 9]
+            .into_iter()
+            .map(Ok)
+            .increasing_by_at_least(2, |i| *i, small_step)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(5), Ok(7), Err(TestErr::SmallStep(2, 3, 7)), Ok(9)]
+        )
+    }
+
+    #[test]
+    fn test_increasing_by_at_least_ignores_errors() {
+        let results = [0, -1, 1, 2]
+            .into_iter()
+            .map(|i| match i < 0 {
+                true => Err(TestErr::IsNegative(i)),
+                false => Ok(i),
+            })
+            .increasing_by_at_least(2, |i| *i, small_step)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::IsNegative(-1)),
+                Err(TestErr::SmallStep(2, 1, 0)),
+                Ok(2)
+            ]
+        )
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct IncreasingWithResetsIter<I, T, E, A, M, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    R: Fn(&T) -> bool,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    iter: IndexOffset<I>,
+    previous: Option<A>,
+    extractor: M,
+    is_reset: R,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, R, Factory> IncreasingWithResetsIter<I, T, E, A, M, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    R: Fn(&T) -> bool,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        extractor: M,
+        is_reset: R,
+        factory: Factory,
+    ) -> IncreasingWithResetsIter<I, T, E, A, M, R, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            previous: None,
+            extractor,
+            is_reset,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, R, Factory> Iterator for IncreasingWithResetsIter<I, T, E, A, M, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    R: Fn(&T) -> bool,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((_, Ok(val))) if (self.is_reset)(&val) => {
+                self.previous = None;
+                Some(Ok(val))
+            }
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                match &self.previous {
+                    Some(prev) if extraction > *prev => {
+                        self.previous = Some(extraction);
+                        Some(Ok(val))
+                    }
+                    Some(prev) => Some(Err((self.factory)(i, val, extraction, prev))),
+                    None => {
+                        self.previous = Some(extraction);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, A, M, R, Factory> WithIndexOffset
+    for IncreasingWithResetsIter<I, T, E, A, M, R, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    R: Fn(&T) -> bool,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait IncreasingWithResets<T, E, A, M, R, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    R: Fn(&T) -> bool,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    /// Fails an iteration if the values computed by `extractor` are not
+    /// strictly increasing between resets.
+    ///
+    /// `increasing_with_resets(extractor, is_reset, factory)` compares the
+    /// value extracted from each element to the value extracted from the
+    /// last valid element. If the current value is greater, the element is
+    /// wrapped in `Ok(element)`. Otherwise, `factory` is called on the index
+    /// of the error, the element, the extracted value, and the previous
+    /// value.
+    ///
+    /// Elements for which `is_reset` returns `true` always pass, without
+    /// being compared, and clear the baseline: the next valid element starts
+    /// a new increasing run. This is useful for counters that legitimately
+    /// restart, such as per-session sequence numbers.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::IncreasingWithResets;
+    ///
+    /// let log = ["1", "2", "new session", "1", "3", "2"];
+    /// let mut iter = log.into_iter().map(Ok).increasing_with_resets(
+    ///     |line| line.parse::<i32>().unwrap(),
+    ///     |line| *line == "new session",
+    ///     |i, _, seq, prev| (i, seq, *prev),
+    /// );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("1")));
+    /// assert_eq!(iter.next(), Some(Ok("2")));
+    /// assert_eq!(iter.next(), Some(Ok("new session")));
+    /// assert_eq!(iter.next(), Some(Ok("1")));
+    /// assert_eq!(iter.next(), Some(Ok("3")));
+    /// assert_eq!(iter.next(), Some(Err((5, 2, 3))));
+    /// ```
+    fn increasing_with_resets(
+        self,
+        extractor: M,
+        is_reset: R,
+        factory: Factory,
+    ) -> IncreasingWithResetsIter<Self, T, E, A, M, R, Factory> {
+        IncreasingWithResetsIter::new(self, extractor, is_reset, factory)
+    }
+}
+
+impl<I, T, E, A, M, R, Factory> IncreasingWithResets<T, E, A, M, R, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    R: Fn(&T) -> bool,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IncreasingWithResets;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        NotIncreasing(usize, i32, i32),
+        Corrupt,
+    }
+
+    fn not_increasing(index: usize, _: i32, extraction: i32, prev: &i32) -> TestErr {
+        TestErr::NotIncreasing(index, extraction, *prev)
+    }
+
+    const RESET: i32 = 0;
+
+    #[test]
+    fn test_increasing_with_resets_ok() {
+        if [1, 2, 3, RESET, 1, 2]
+            .into_iter()
+            .map(Ok)
+            .increasing_with_resets(|i| *i, |i| *i == RESET, not_increasing)
+            .any(|res| res.is_err())
+        {
+            panic!("increasing with resets failed on increasing runs")
+        }
+    }
+
+    #[test]
+    fn test_increasing_with_resets_fails_within_run() {
+        let results = [1, 3, 2, RESET, 2]
+            .into_iter()
+            .map(Ok)
+            .increasing_with_resets(|i| *i, |i| *i == RESET, not_increasing)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Ok(3),
+                Err(TestErr::NotIncreasing(2, 2, 3)),
+                Ok(RESET),
+                Ok(2)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_increasing_with_resets_ignores_errors() {
+        let results = [Ok(5), Err(TestErr::Corrupt), Ok(4)]
+            .into_iter()
+            .increasing_with_resets(|i| *i, |i| *i == RESET, not_increasing)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(5),
+                Err(TestErr::Corrupt),
+                Err(TestErr::NotIncreasing(2, 4, 5))
+            ]
+        )
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct IncreasingWithToleranceIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    iter: IndexOffset<I>,
+    max_decreases: usize,
+    decreases: usize,
+    previous: Option<A>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, Factory> IncreasingWithToleranceIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        max_decreases: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> IncreasingWithToleranceIter<I, T, E, A, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            max_decreases,
+            decreases: 0,
+            previous: None,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> Iterator for IncreasingWithToleranceIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                match &self.previous {
+                    Some(prev) if extraction < *prev => {
+                        if self.decreases == self.max_decreases {
+                            return Some(Err((self.factory)(i, val, extraction, prev)));
+                        }
+                        self.decreases += 1;
+                    }
+                    _ => {}
+                }
+                self.previous = Some(extraction);
+                Some(Ok(val))
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, A, M, Factory> WithIndexOffset for IncreasingWithToleranceIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait IncreasingWithTolerance<T, E, A, M, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+    /// Fails an iteration if the values computed by `extractor` decrease
+    /// more than `max_decreases` times.
+    ///
+    /// `increasing_with_tolerance(max_decreases, extractor, factory)`
+    /// compares the value extracted from each element to the value extracted
+    /// from the last valid element. Equal and greater values are wrapped in
+    /// `Ok(element)`. The first `max_decreases` decreases in the whole
+    /// iteration are tolerated and wrapped in `Ok` as well, but every decrease
+    /// after them fails, by calling `factory` on the index of the error, the
+    /// element, the extracted value and the previous value.
+    ///
+    /// A tolerated decrease becomes the new baseline, so noisy but mostly
+    /// increasing data can recover after a dip.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::IncreasingWithTolerance;
+    ///
+    /// let mut iter = [1, 3, 2, 4, 3]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .increasing_with_tolerance(1, |v| *v, |i, _, v, prev| (i, v, *prev));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), Some(Ok(2))); // first decrease is tolerated
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// assert_eq!(iter.next(), Some(Err((4, 3, 4))));
+    /// ```
+    fn increasing_with_tolerance(
+        self,
+        max_decreases: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> IncreasingWithToleranceIter<Self, T, E, A, M, Factory> {
+        IncreasingWithToleranceIter::new(self, max_decreases, extractor, factory)
+    }
+}
+
+impl<I, T, E, A, M, Factory> IncreasingWithTolerance<T, E, A, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IncreasingWithTolerance;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        TooManyDecreases(usize, i32, i32),
+        Corrupt,
+    }
+
+    fn too_many(index: usize, _: i32, val: i32, prev: &i32) -> TestErr {
+        TestErr::TooManyDecreases(index, val, *prev)
+    }
+
+    #[test]
+    fn test_increasing_with_tolerance_exactly_k_decreases() {
+        if [0, 5, 5, 4, 6, 3, 3, 7]
+            .into_iter()
+            .map(Ok)
+            .increasing_with_tolerance(2, |v| *v, too_many)
+            .any(|res| res.is_err())
+        {
+            panic!("increasing with tolerance failed on exactly max_decreases decreases")
+        }
+    }
+
+    #[test]
+    fn test_increasing_with_tolerance_one_more_fails() {
+        let results = [0, 5, 4, 6, 3, 7, 6, 1]
+            .into_iter()
+            .map(Ok)
+            .increasing_with_tolerance(2, |v| *v, too_many)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(5),
+                Ok(4),
+                Ok(6),
+                Ok(3),
+                Ok(7),
+                Err(TestErr::TooManyDecreases(6, 6, 7)),
+                Err(TestErr::TooManyDecreases(7, 1, 7))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_increasing_with_tolerance_ignores_errors() {
+        let results = [Ok(2), Err(TestErr::Corrupt), Ok(1)]
+            .into_iter()
+            .increasing_with_tolerance(0, |v| *v, too_many)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(2),
+                Err(TestErr::Corrupt),
+                Err(TestErr::TooManyDecreases(2, 1, 2))
+            ]
+        )
+    }
+}
@@ -0,0 +1,186 @@
+/// An [`Enumerate`](std::iter::Enumerate) like counter, which starts
+/// counting from a configurable offset.
+#[derive(Debug, Clone)]
+pub struct IndexOffset<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    offset: usize,
+    count: usize,
+}
+
+impl<I> IndexOffset<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I) -> IndexOffset<I> {
+        IndexOffset {
+            iter,
+            offset: 0,
+            count: 0,
+        }
+    }
+
+    pub(crate) fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+}
+
+impl<I> Iterator for IndexOffset<I>
+where
+    I: Iterator,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let index = self.offset + self.count;
+        self.count += 1;
+        Some((index, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait WithIndexOffset: Sized {
+    /// Offsets the indices an adapter reports to its factories.
+    ///
+    /// `with_index_offset(offset)` makes the adapter it is called on pass
+    /// `offset + index` to its factories, instead of `index`. This aligns the
+    /// reported positions with a larger source, when validating a part of
+    /// it, such as a slice of a file starting at line `offset`. Adapters
+    /// chained before or after it keep counting from 0.
+    ///
+    /// Only element indices are offset. Lengths and counts, such as the
+    /// length given to the factory of [`at_least`](crate::AtLeast::at_least)
+    /// at the end of an iteration, are reported unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, WithIndexOffset};
+    ///
+    /// let lines = ["7", "x", "9"];
+    /// let mut iter = lines
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure(|line| line.parse::<i32>().is_ok(), |i, line| (i, line))
+    ///     .with_index_offset(1000);
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("7")));
+    /// assert_eq!(iter.next(), Some(Err((1001, "x"))));
+    /// ```
+    fn with_index_offset(self, offset: usize) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AtMost, ConstOver, Ensure, LookBack, SampleFailure, SampleWithoutReplacement, UniqueBy,
+        WithIndexOffset,
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        TooMany(usize, i32),
+        NotConst(usize, i32),
+        NotIncreasing(usize, i32),
+        Duplicate(usize, i32),
+    }
+
+    #[test]
+    fn test_index_offset_ensure() {
+        let results = (0..4)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .with_index_offset(1000)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::IsOdd(1001, 1)),
+                Ok(2),
+                Err(TestErr::IsOdd(1003, 3))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_index_offset_downstream_of_ensure() {
+        let results = (0..4)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .with_index_offset(10)
+            .at_most(1, TestErr::TooMany)
+            .with_index_offset(20)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::IsOdd(11, 1)),
+                Err(TestErr::TooMany(22, 2)),
+                Err(TestErr::IsOdd(13, 3))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_index_offset_const_over_and_look_back() {
+        let results = [1, 1, 2]
+            .into_iter()
+            .map(Ok)
+            .const_over(|i| *i, |i, v, _, _| TestErr::NotConst(i, v))
+            .with_index_offset(5)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(1), Ok(1), Err(TestErr::NotConst(7, 2))]);
+
+        let results = [1, 2, 0]
+            .into_iter()
+            .map(Ok)
+            .look_back(
+                1,
+                |i| *i,
+                |i, prev| i > prev,
+                |i, v, _| TestErr::NotIncreasing(i, v),
+            )
+            .with_index_offset(5)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(1), Ok(2), Err(TestErr::NotIncreasing(7, 0))]
+        )
+    }
+
+    #[test]
+    fn test_index_offset_other_adapters() {
+        let results = [1, 2, 1]
+            .into_iter()
+            .map(Ok)
+            .unique_by(|i| *i, |i, v, _| TestErr::Duplicate(i, v))
+            .with_index_offset(100)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(1), Ok(2), Err(TestErr::Duplicate(102, 1))]);
+
+        let results = [7, 7]
+            .into_iter()
+            .map(Ok)
+            .sample_without_replacement(1, |i| *i, |i, failure| (i, failure))
+            .with_index_offset(100)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(7),
+                Err((101, SampleFailure::Repeated(7, 7))),
+                Err((2, SampleFailure::Overdrawn(2)))
+            ]
+        )
+    }
+}
@@ -0,0 +1,126 @@
+use fallible_iterator::FallibleIterator;
+
+#[derive(Debug, Clone)]
+pub struct IntoFallibleIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    iter: I,
+}
+
+impl<I, T, E> IntoFallibleIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I) -> IntoFallibleIter<I, T, E> {
+        IntoFallibleIter { iter }
+    }
+}
+
+impl<I, T, E> FallibleIterator for IntoFallibleIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Error = E;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.iter.next().transpose()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait IntoFallible<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Turns a validation iterator into a
+    /// [`FallibleIterator`](fallible_iterator::FallibleIterator).
+    ///
+    /// `into_fallible()` unpacks every `Result` yielded by the iteration
+    /// into the `fallible-iterator` model: `Ok(element)` becomes
+    /// `Ok(Some(element))`, `Err(error)` becomes `Err(error)`, and the end
+    /// of the iteration becomes `Ok(None)`. This allows passing a validated
+    /// iteration to code that expects a `FallibleIterator`.
+    ///
+    /// Like other fallible iterators, the result is not fused after an
+    /// error: calling `next` again continues with the element after the
+    /// error.
+    ///
+    /// This method is only available with the `fallible-iterator` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use fallible_iterator::FallibleIterator;
+    /// use validiter::{Ensure, IntoFallible};
+    ///
+    /// let mut iter = (0..3)
+    ///     .map(Ok)
+    ///     .ensure(|i| *i != 1, |i, _| i)
+    ///     .into_fallible();
+    ///
+    /// assert_eq!(iter.next(), Ok(Some(0)));
+    /// assert_eq!(iter.next(), Err(1));
+    /// assert_eq!(iter.next(), Ok(Some(2)));
+    /// assert_eq!(iter.next(), Ok(None));
+    /// ```
+    fn into_fallible(self) -> IntoFallibleIter<Self, T, E> {
+        IntoFallibleIter::new(self)
+    }
+}
+
+impl<I, T, E> IntoFallible<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use fallible_iterator::FallibleIterator;
+
+    use crate::{AtMost, IntoFallible};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        TooMany(usize, i32),
+    }
+
+    #[test]
+    fn test_into_fallible_collect_ok() {
+        let results = (0..5)
+            .map(Ok)
+            .at_most(5, TestErr::TooMany)
+            .into_fallible()
+            .collect::<Vec<_>>();
+        assert_eq!(results, Ok(vec![0, 1, 2, 3, 4]))
+    }
+
+    #[test]
+    fn test_into_fallible_collect_err() {
+        let results = (0..5)
+            .map(Ok)
+            .at_most(3, TestErr::TooMany)
+            .into_fallible()
+            .collect::<Vec<_>>();
+        assert_eq!(results, Err(TestErr::TooMany(3, 3)))
+    }
+
+    #[test]
+    fn test_into_fallible_round_trip() {
+        let results = (0..5)
+            .map(Ok)
+            .at_most(3, TestErr::TooMany)
+            .into_fallible()
+            .iterator()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(1),
+                Ok(2),
+                Err(TestErr::TooMany(3, 3)),
+                Err(TestErr::TooMany(4, 4))
+            ]
+        )
+    }
+}
@@ -0,0 +1,240 @@
+use crate::WithIndexOffset;
+
+#[derive(Debug, Clone)]
+pub struct LenBetweenIter<I, T, E, FewFactory, ManyFactory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    FewFactory: Fn(usize) -> E,
+    ManyFactory: Fn(usize, T) -> E,
+{
+    iter: I,
+    min_count: usize,
+    max_count: usize,
+    counter: usize,
+    enumeration_counter: usize,
+    index_offset: usize,
+    too_few: FewFactory,
+    too_many: ManyFactory,
+}
+
+impl<I, T, E, FewFactory, ManyFactory> LenBetweenIter<I, T, E, FewFactory, ManyFactory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    FewFactory: Fn(usize) -> E,
+    ManyFactory: Fn(usize, T) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        min_count: usize,
+        max_count: usize,
+        too_few: FewFactory,
+        too_many: ManyFactory,
+    ) -> LenBetweenIter<I, T, E, FewFactory, ManyFactory> {
+        LenBetweenIter {
+            iter,
+            min_count,
+            max_count,
+            counter: 0,
+            enumeration_counter: 0,
+            index_offset: 0,
+            too_few,
+            too_many,
+        }
+    }
+}
+
+impl<I, T, E, FewFactory, ManyFactory> Iterator for LenBetweenIter<I, T, E, FewFactory, ManyFactory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    FewFactory: Fn(usize) -> E,
+    ManyFactory: Fn(usize, T) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.iter.next() {
+            Some(Ok(val)) => match self.counter >= self.max_count {
+                true => Some(Err((self.too_many)(
+                    self.index_offset + self.enumeration_counter,
+                    val,
+                ))),
+                false => {
+                    self.counter += 1;
+                    Some(Ok(val))
+                }
+            },
+            None => match self.counter >= self.min_count {
+                true => None,
+                false => {
+                    self.counter = self.min_count;
+                    Some(Err((self.too_few)(self.enumeration_counter)))
+                }
+            },
+            other => other,
+        };
+        self.enumeration_counter += 1;
+        item
+    }
+}
+
+impl<I, T, E, FewFactory, ManyFactory> WithIndexOffset
+    for LenBetweenIter<I, T, E, FewFactory, ManyFactory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    FewFactory: Fn(usize) -> E,
+    ManyFactory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.index_offset = offset;
+        self
+    }
+}
+
+pub trait LenBetween<T, E, FewFactory, ManyFactory>: Iterator<Item = Result<T, E>> + Sized
+where
+    FewFactory: Fn(usize) -> E,
+    ManyFactory: Fn(usize, T) -> E,
+{
+    /// Fails a validation iterator if it does not contain between `min`
+    /// and `max` elements (inclusive).
+    ///
+    /// `len_between(min, max, too_few, too_many)` combines
+    /// [`at_least`](crate::AtLeast::at_least) and [`at_most`](crate::AtMost::at_most)
+    /// in a single adapter. Elements are wrapped in `Ok(element)` until `max`
+    /// elements were yielded. Any element after that is passed to `too_many`
+    /// together with its index. If the iteration ends before `min` elements
+    /// were yielded, a new element is added to the end of the iteration with
+    /// the value returned from calling `too_few` on the length of the iterator.
+    ///
+    /// Like `at_least`, the lower bound cannot be validated if the iteration
+    /// is short-circuited.
+    ///
+    /// Elements already wrapped in `Result::Err` are not counted towards
+    /// either bound, but are counted in the indices and length provided to
+    /// the factories.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::LenBetween;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum LenErr {
+    ///     TooFew(usize),
+    ///     TooMany(usize, i32),
+    /// }
+    ///
+    /// let mut iter = (0..1).map(Ok).len_between(2, 3, LenErr::TooFew, LenErr::TooMany);
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Err(LenErr::TooFew(1))));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let mut iter = (0..4).map(Ok).len_between(2, 3, LenErr::TooFew, LenErr::TooMany);
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), Some(Err(LenErr::TooMany(3, 3))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn len_between(
+        self,
+        min_count: usize,
+        max_count: usize,
+        too_few: FewFactory,
+        too_many: ManyFactory,
+    ) -> LenBetweenIter<Self, T, E, FewFactory, ManyFactory> {
+        LenBetweenIter::new(self, min_count, max_count, too_few, too_many)
+    }
+}
+
+impl<I, T, E, FewFactory, ManyFactory> LenBetween<T, E, FewFactory, ManyFactory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    FewFactory: Fn(usize) -> E,
+    ManyFactory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LenBetween;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        TooFew(usize),
+        TooMany(usize, i32),
+        IsOdd(i32),
+    }
+
+    #[test]
+    fn test_len_between_ok() {
+        for len in 2..=4 {
+            if (0..len)
+                .map(Ok)
+                .len_between(2, 4, TestErr::TooFew, TestErr::TooMany)
+                .any(|res| res.is_err())
+            {
+                panic!("len between failed on a length in bounds")
+            }
+        }
+    }
+
+    #[test]
+    fn test_len_between_ends_too_short() {
+        let results = (0..2)
+            .map(Ok)
+            .len_between(3, 5, TestErr::TooFew, TestErr::TooMany)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(1), Err(TestErr::TooFew(2))])
+    }
+
+    #[test]
+    fn test_len_between_too_long_and_short_circuited() {
+        let results = (0..)
+            .map(Ok)
+            .len_between(3, 5, TestErr::TooFew, TestErr::TooMany)
+            .take(7)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(1),
+                Ok(2),
+                Ok(3),
+                Ok(4),
+                Err(TestErr::TooMany(5, 5)),
+                Err(TestErr::TooMany(6, 6))
+            ]
+        );
+
+        let results = (0..)
+            .map(Ok)
+            .len_between(3, 5, TestErr::TooFew, TestErr::TooMany)
+            .take(2)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(1)]);
+    }
+
+    #[test]
+    fn test_len_between_skips_errors() {
+        let results = (0..4)
+            .map(|i| match i % 2 {
+                0 => Ok(i),
+                _ => Err(TestErr::IsOdd(i)),
+            })
+            .len_between(3, 5, TestErr::TooFew, TestErr::TooMany)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::IsOdd(1)),
+                Ok(2),
+                Err(TestErr::IsOdd(3)),
+                Err(TestErr::TooFew(4))
+            ]
+        )
+    }
+}
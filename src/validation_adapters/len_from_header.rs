@@ -0,0 +1,191 @@
+#[derive(Debug, Clone)]
+pub struct LenFromHeaderIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> usize,
+    Factory: Fn(usize, usize) -> E,
+{
+    iter: I,
+    yield_header: bool,
+    header_seen: bool,
+    declared: Option<usize>,
+    body_len: usize,
+    count_of: C,
+    factory: Factory,
+}
+
+impl<I, T, E, C, Factory> LenFromHeaderIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> usize,
+    Factory: Fn(usize, usize) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        count_of: C,
+        yield_header: bool,
+        factory: Factory,
+    ) -> LenFromHeaderIter<I, T, E, C, Factory> {
+        LenFromHeaderIter {
+            iter,
+            yield_header,
+            header_seen: false,
+            declared: None,
+            body_len: 0,
+            count_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, C, Factory> Iterator for LenFromHeaderIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> usize,
+    Factory: Fn(usize, usize) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(Ok(val)) if !self.header_seen => {
+                    self.header_seen = true;
+                    self.declared = Some((self.count_of)(&val));
+                    if self.yield_header {
+                        return Some(Ok(val));
+                    }
+                }
+                Some(item) => {
+                    if self.header_seen {
+                        self.body_len += 1;
+                    }
+                    return Some(item);
+                }
+                None => {
+                    return match self.declared.take() {
+                        Some(declared) if declared != self.body_len => {
+                            Some(Err((self.factory)(declared, self.body_len)))
+                        }
+                        _ => None,
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub trait LenFromHeader<T, E, C, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    C: Fn(&T) -> usize,
+    Factory: Fn(usize, usize) -> E,
+{
+    /// Fails an iteration if the number of elements after its header is
+    /// not the number declared in the header.
+    ///
+    /// `len_from_header(count_of, yield_header, factory)` treats the first
+    /// element wrapped in `Ok` as a header, and computes the declared length
+    /// of the body with `count_of`. The header is yielded only if
+    /// `yield_header` is `true`. All the elements after the header are
+    /// yielded unchanged and counted. If at the end of the iteration the
+    /// count is different from the declared length, a new element is added to
+    /// the end of the iteration with the value returned from calling
+    /// `factory` on the declared length and the actual length of the body.
+    ///
+    /// Like [`at_least`](crate::AtLeast::at_least), the `len_from_header`
+    /// adapter cannot handle short-circuiting of iterators. An iteration
+    /// without a header is not reported.
+    ///
+    /// Values already wrapped in `Result::Err` can't be the header, but are
+    /// counted as part of the body if they come after it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::LenFromHeader;
+    ///
+    /// let message = ["3", "a", "b"];
+    /// let mut iter = message
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .len_from_header(|header| header.parse().unwrap(), false, |declared, actual| (declared, actual));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("a")));
+    /// assert_eq!(iter.next(), Some(Ok("b")));
+    /// assert_eq!(iter.next(), Some(Err((3, 2))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn len_from_header(
+        self,
+        count_of: C,
+        yield_header: bool,
+        factory: Factory,
+    ) -> LenFromHeaderIter<Self, T, E, C, Factory> {
+        LenFromHeaderIter::new(self, count_of, yield_header, factory)
+    }
+}
+
+impl<I, T, E, C, Factory> LenFromHeader<T, E, C, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> usize,
+    Factory: Fn(usize, usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LenFromHeader;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        WrongLen(usize, usize),
+        Corrupt,
+    }
+
+    #[test]
+    fn test_len_from_header_accurate() {
+        let results = [2, 10, 20]
+            .into_iter()
+            .map(Ok)
+            .len_from_header(|h| *h, false, TestErr::WrongLen)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(10), Ok(20)]);
+
+        let results = [2, 10, 20]
+            .into_iter()
+            .map(Ok)
+            .len_from_header(|h| *h, true, TestErr::WrongLen)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(2), Ok(10), Ok(20)])
+    }
+
+    #[test]
+    fn test_len_from_header_inflated() {
+        let results = [5, 10, 20]
+            .into_iter()
+            .map(Ok)
+            .len_from_header(|h| *h, true, TestErr::WrongLen)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(5), Ok(10), Ok(20), Err(TestErr::WrongLen(5, 2))]
+        )
+    }
+
+    #[test]
+    fn test_len_from_header_errors() {
+        let results = [Err(TestErr::Corrupt), Ok(1), Err(TestErr::Corrupt)]
+            .into_iter()
+            .len_from_header(|h| *h, false, TestErr::WrongLen)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::Corrupt), Err(TestErr::Corrupt)]);
+
+        let results = (0..0)
+            .map(Ok)
+            .len_from_header(|h| *h, false, TestErr::WrongLen)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![])
+    }
+}
@@ -1,7 +1,7 @@
-use std::iter::Enumerate;
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
 
 /// The [`LookBack`] ValidIter adapter, for more info see
-///  [`look_back`](crate::ValidIter::look_back) and [`look_back_n`](crate::ValidIter::look_back_n).
+///  [`look_back`](crate::LookBack::look_back) and [`look_back_n`](crate::LookBackN::look_back_n).
 #[derive(Debug, Clone)]
 pub struct LookBackIter<I, T, E, A, M, F, Factory>
 where
@@ -10,7 +10,7 @@ where
     F: Fn(&T, &A) -> bool,
     Factory: Fn(usize, T, &A) -> E,
 {
-    iter: Enumerate<I>,
+    iter: IndexOffset<I>,
     steps: usize,
     pos: usize,
     value_store: Vec<A>,
@@ -34,7 +34,7 @@ where
         factory: Factory,
     ) -> LookBackIter<I, T, E, A, M, F, Factory> {
         Self {
-            iter: iter.enumerate(),
+            iter: IndexOffset::new(iter),
             steps,
             pos: 0,
             value_store: Vec::with_capacity(steps),
@@ -90,6 +90,19 @@ where
     }
 }
 
+impl<I, T, E, A, M, F, Factory> WithIndexOffset for LookBackIter<I, T, E, A, M, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
 pub trait LookBack<T, E, A, M, F, Factory>: Iterator<Item = Result<T, E>> + Sized
 where
     M: Fn(&T) -> A,
@@ -0,0 +1,241 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+/// The [`LookBackN`] adapter over an iterator of `Result<T, E>`, for more
+/// info see [`look_back_n`](crate::LookBackN::look_back_n).
+#[derive(Debug, Clone)]
+pub struct LookBackNIter<I, T, E, A, M, F, Factory, const N: usize>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Default,
+    M: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, &A) -> E,
+{
+    iter: IndexOffset<I>,
+    pos: usize,
+    value_store: [A; N],
+    extractor: M,
+    validation: F,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, F, Factory, const N: usize> LookBackNIter<I, T, E, A, M, F, Factory, N>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Default,
+    M: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, &A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        extractor: M,
+        validation: F,
+        factory: Factory,
+    ) -> LookBackNIter<I, T, E, A, M, F, Factory, N> {
+        Self {
+            iter: IndexOffset::new(iter),
+            pos: 0,
+            value_store: std::array::from_fn(|_| A::default()),
+            extractor,
+            validation,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, F, Factory, const N: usize> Iterator
+    for LookBackNIter<I, T, E, A, M, F, Factory, N>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Default,
+    M: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, &A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // prevent modulo 0 div
+        if N == 0 {
+            return self.iter.next().map(|(_, item)| item);
+        }
+
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let cycle_index = self.pos % N;
+                if self.pos >= N {
+                    let former = &self.value_store[cycle_index];
+                    if !(self.validation)(&val, former) {
+                        return Some(Err((self.factory)(i, val, former)));
+                    }
+                }
+                self.value_store[cycle_index] = (self.extractor)(&val);
+                self.pos += 1;
+                Some(Ok(val))
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, A, M, F, Factory, const N: usize> WithIndexOffset
+    for LookBackNIter<I, T, E, A, M, F, Factory, N>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Default,
+    M: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, &A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait LookBackN<T, E, A, M, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    A: Default,
+    M: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, &A) -> E,
+{
+    /// Same as [`look_back`](crate::LookBack::look_back), with the cycle
+    /// length given at compile time.
+    ///
+    /// `look_back_n::<N>(extractor, test, factory)` validates every
+    /// element against the value extracted from the `N`th preceding valid
+    /// element, exactly like `look_back(N, extractor, test, factory)`.
+    /// The extracted values are stored in an array of size `N` instead of
+    /// a heap allocated `Vec`, so the memory used by the adapter is known at
+    /// compile time. The array is initially filled with `A::default()`, but
+    /// these values are never passed to `test`.
+    ///
+    /// If `N` is 0, all elements are yielded unchanged. Elements that failed
+    /// the validation are not stored, and values already wrapped in
+    /// `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::LookBackN;
+    ///
+    /// let mut iter = [0, 1, 2, 2, 3]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .look_back_n::<2>(|i| i % 2, |i, parity| i % 2 == *parity, |index, val, _| (index, val));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2))); // evaluated with respect to 0
+    /// assert_eq!(iter.next(), Some(Err((3, 2)))); // evaluated with respect to 1
+    /// assert_eq!(iter.next(), Some(Ok(3))); // also evaluated with respect to 1
+    /// ```
+    fn look_back_n<const N: usize>(
+        self,
+        extractor: M,
+        test: F,
+        factory: Factory,
+    ) -> LookBackNIter<Self, T, E, A, M, F, Factory, N> {
+        LookBackNIter::new(self, extractor, test, factory)
+    }
+}
+
+impl<I, T, E, A, M, F, Factory> LookBackN<T, E, A, M, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Default,
+    M: Fn(&T) -> A,
+    F: Fn(&T, &A) -> bool,
+    Factory: Fn(usize, T, &A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LookBack, LookBackN};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        LookBackFailed(usize, i32, i32),
+        IsNegative(i32),
+    }
+
+    fn lbfailed(index: usize, item: i32, against: &i32) -> TestErr {
+        TestErr::LookBackFailed(index, item, *against)
+    }
+
+    fn source() -> impl Iterator<Item = Result<i32, TestErr>> {
+        [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, -1, 8, 9, 7, 9]
+            .into_iter()
+            .map(|i| match i < 0 {
+                true => Err(TestErr::IsNegative(i)),
+                false => Ok(i),
+            })
+    }
+
+    #[test]
+    fn test_look_back_n_same_as_look_back() {
+        let test = |i: &i32, prev: &i32| i >= prev;
+        assert_eq!(
+            source()
+                .look_back_n::<1>(|i| *i, test, lbfailed)
+                .collect::<Vec<_>>(),
+            source()
+                .look_back(1, |i| *i, test, lbfailed)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            source()
+                .look_back_n::<2>(|i| *i, test, lbfailed)
+                .collect::<Vec<_>>(),
+            source()
+                .look_back(2, |i| *i, test, lbfailed)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            source()
+                .look_back_n::<3>(|i| *i, test, lbfailed)
+                .collect::<Vec<_>>(),
+            source()
+                .look_back(3, |i| *i, test, lbfailed)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_look_back_n_err() {
+        let results = [2, 3, 4, 2, 0, 3, 5]
+            .into_iter()
+            .map(Ok)
+            .look_back_n::<3>(|i| *i, |i, prev| prev < i, lbfailed)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(2),
+                Ok(3),
+                Ok(4),
+                Err(TestErr::LookBackFailed(3, 2, 2)),
+                Err(TestErr::LookBackFailed(4, 0, 2)),
+                Ok(3),
+                Ok(5)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_look_back_n_does_nothing_on_0() {
+        if (0..5)
+            .chain(0..5)
+            .map(Ok)
+            .look_back_n::<0>(|i| *i, |i, prev| prev < i, lbfailed)
+            .any(|res| res.is_err())
+        {
+            panic!("look back n failed when it should not be validating anything")
+        }
+    }
+}
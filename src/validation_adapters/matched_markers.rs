@@ -0,0 +1,227 @@
+use std::collections::VecDeque;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+/// The role of an element in a structure delimited by markers, as
+/// returned from the classifier of
+/// [`matched_markers`](crate::MatchedMarkers::matched_markers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerKind {
+    /// Opens a section.
+    Begin,
+    /// Closes the last open section.
+    End,
+    /// Any element which is not a marker.
+    Other,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchedMarkersIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> MarkerKind,
+    Factory: Fn(usize, MarkerKind) -> E,
+{
+    iter: IndexOffset<I>,
+    open: VecDeque<usize>,
+    classify: C,
+    factory: Factory,
+}
+
+impl<I, T, E, C, Factory> MatchedMarkersIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> MarkerKind,
+    Factory: Fn(usize, MarkerKind) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        classify: C,
+        factory: Factory,
+    ) -> MatchedMarkersIter<I, T, E, C, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            open: VecDeque::new(),
+            classify,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, C, Factory> Iterator for MatchedMarkersIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> MarkerKind,
+    Factory: Fn(usize, MarkerKind) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => match (self.classify)(&val) {
+                MarkerKind::Begin => {
+                    self.open.push_back(i);
+                    Some(Ok(val))
+                }
+                MarkerKind::End => match self.open.pop_back() {
+                    Some(_) => Some(Ok(val)),
+                    None => Some(Err((self.factory)(i, MarkerKind::End))),
+                },
+                MarkerKind::Other => Some(Ok(val)),
+            },
+            Some((_, err)) => Some(err),
+            None => self
+                .open
+                .pop_front()
+                .map(|begin| Err((self.factory)(begin, MarkerKind::Begin))),
+        }
+    }
+}
+
+impl<I, T, E, C, Factory> WithIndexOffset for MatchedMarkersIter<I, T, E, C, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> MarkerKind,
+    Factory: Fn(usize, MarkerKind) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait MatchedMarkers<T, E, C, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    C: Fn(&T) -> MarkerKind,
+    Factory: Fn(usize, MarkerKind) -> E,
+{
+    /// Fails an iteration if its begin and end markers are not balanced.
+    ///
+    /// `matched_markers(classify, factory)` calls `classify` on each element
+    /// wrapped in `Ok`, and keeps a stack of the open
+    /// [`Begin`](MarkerKind::Begin) markers, so sections may be nested. Every
+    /// [`End`](MarkerKind::End) marker closes the innermost open section. An
+    /// `End` marker without an open section is failed by calling `factory` on
+    /// its index and `MarkerKind::End`. All other elements are wrapped in
+    /// `Ok(element)`.
+    ///
+    /// At the end of the iteration, a new error is added for every section
+    /// that is still open, by calling `factory` on the index of its `Begin`
+    /// marker and `MarkerKind::Begin`, in the order the sections were opened.
+    /// Like [`at_least`](crate::AtLeast::at_least), this cannot handle
+    /// short-circuiting of iterators.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{MarkerKind, MatchedMarkers};
+    ///
+    /// let classify = |line: &&str| match *line {
+    ///     "BEGIN" => MarkerKind::Begin,
+    ///     "END" => MarkerKind::End,
+    ///     _ => MarkerKind::Other,
+    /// };
+    /// let mut iter = ["BEGIN", "a", "END", "END", "BEGIN"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .matched_markers(classify, |i, kind| (i, kind));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("BEGIN")));
+    /// assert_eq!(iter.next(), Some(Ok("a")));
+    /// assert_eq!(iter.next(), Some(Ok("END")));
+    /// assert_eq!(iter.next(), Some(Err((3, MarkerKind::End))));
+    /// assert_eq!(iter.next(), Some(Ok("BEGIN")));
+    /// assert_eq!(iter.next(), Some(Err((4, MarkerKind::Begin))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn matched_markers(
+        self,
+        classify: C,
+        factory: Factory,
+    ) -> MatchedMarkersIter<Self, T, E, C, Factory> {
+        MatchedMarkersIter::new(self, classify, factory)
+    }
+}
+
+impl<I, T, E, C, Factory> MatchedMarkers<T, E, C, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: Fn(&T) -> MarkerKind,
+    Factory: Fn(usize, MarkerKind) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarkerKind;
+    use crate::MatchedMarkers;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Unmatched(usize, MarkerKind),
+        Corrupt,
+    }
+
+    fn classify(c: &char) -> MarkerKind {
+        match c {
+            '(' => MarkerKind::Begin,
+            ')' => MarkerKind::End,
+            _ => MarkerKind::Other,
+        }
+    }
+
+    #[test]
+    fn test_matched_markers_balanced() {
+        if "(a(b)(c))()"
+            .chars()
+            .map(Ok)
+            .matched_markers(classify, TestErr::Unmatched)
+            .any(|res| res.is_err())
+        {
+            panic!("matched markers failed on balanced markers")
+        }
+    }
+
+    #[test]
+    fn test_matched_markers_unbalanced_close() {
+        let results = [Ok('('), Ok(')'), Err(TestErr::Corrupt), Ok(')'), Ok('a')]
+            .into_iter()
+            .matched_markers(classify, TestErr::Unmatched)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('('),
+                Ok(')'),
+                Err(TestErr::Corrupt),
+                Err(TestErr::Unmatched(3, MarkerKind::End)),
+                Ok('a')
+            ]
+        )
+    }
+
+    #[test]
+    fn test_matched_markers_trailing_open() {
+        let results = "((a)(b"
+            .chars()
+            .map(Ok)
+            .matched_markers(classify, TestErr::Unmatched)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('('),
+                Ok('('),
+                Ok('a'),
+                Ok(')'),
+                Ok('('),
+                Ok('b'),
+                Err(TestErr::Unmatched(0, MarkerKind::Begin)),
+                Err(TestErr::Unmatched(4, MarkerKind::Begin))
+            ]
+        )
+    }
+}
@@ -0,0 +1,235 @@
+use regex::Regex;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct MatchesRegexIter<'a, I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    iter: IndexOffset<I>,
+    pattern: &'a Regex,
+    factory: Factory,
+}
+
+impl<'a, I, T, E, Factory> MatchesRegexIter<'a, I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        pattern: &'a Regex,
+        factory: Factory,
+    ) -> MatchesRegexIter<'a, I, T, E, Factory> {
+        MatchesRegexIter {
+            iter: IndexOffset::new(iter),
+            pattern,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, Factory> Iterator for MatchesRegexIter<'_, I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let text = val.as_ref();
+                let full_match = self
+                    .pattern
+                    .find(text)
+                    .is_some_and(|m| m.start() == 0 && m.end() == text.len());
+                match full_match {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, Factory> WithIndexOffset for MatchesRegexIter<'_, I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait MatchesRegex<'a, T, E, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    /// Fails any element which does not fully match a regular expression.
+    ///
+    /// `matches_regex(pattern, factory)` searches each element wrapped in
+    /// `Ok` with a precompiled [`Regex`](regex::Regex), used as is, so any
+    /// [`RegexBuilder`](regex::RegexBuilder) options apply. If the match
+    /// found spans the whole element, it is wrapped in `Ok(element)`.
+    /// Otherwise, `factory` is called on the index of the error and the
+    /// element.
+    ///
+    /// Only the leftmost match is considered, so a pattern with alternations
+    /// such as `a|ab` will not fully match `"ab"`. Such patterns must be
+    /// anchored, as in `^(?:a|ab)$`.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// This method is only available with the `regex` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use regex::Regex;
+    /// use validiter::MatchesRegex;
+    ///
+    /// let digits = Regex::new(r"\d+").unwrap();
+    /// let mut iter = ["123", "12a"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .matches_regex(&digits, |i, s| (i, s));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("123")));
+    /// assert_eq!(iter.next(), Some(Err((1, "12a"))));
+    /// ```
+    fn matches_regex(
+        self,
+        pattern: &'a Regex,
+        factory: Factory,
+    ) -> MatchesRegexIter<'a, Self, T, E, Factory> {
+        MatchesRegexIter::new(self, pattern, factory)
+    }
+}
+
+impl<'a, I, T, E, Factory> MatchesRegex<'a, T, E, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::{Regex, RegexBuilder};
+
+    use crate::MatchesRegex;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Malformed(usize, String),
+        Missing,
+    }
+
+    fn email() -> Regex {
+        Regex::new(r"^[\w.]+@[\w]+\.[a-z]+$").unwrap()
+    }
+
+    #[test]
+    fn test_matches_regex_email() {
+        let pattern = email();
+        let results = ["a@b.com", "x.y@z.org", "nope@", "c@d.io"]
+            .into_iter()
+            .map(|s| Ok(s.to_string()))
+            .matches_regex(&pattern, TestErr::Malformed)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("a@b.com".to_string()),
+                Ok("x.y@z.org".to_string()),
+                Err(TestErr::Malformed(2, "nope@".to_string())),
+                Ok("c@d.io".to_string())
+            ]
+        )
+    }
+
+    #[test]
+    fn test_matches_regex_partial_match_fails() {
+        let pattern = Regex::new(r"\d+").unwrap();
+        let results = ["42", "x42", "42x"]
+            .into_iter()
+            .map(Ok)
+            .matches_regex(&pattern, |i, s: &str| TestErr::Malformed(i, s.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("42"),
+                Err(TestErr::Malformed(1, "x42".to_string())),
+                Err(TestErr::Malformed(2, "42x".to_string()))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_matches_regex_anchored_alternation() {
+        let pattern = Regex::new(r"^(?:a|ab)$").unwrap();
+        let results = ["a", "ab", "abc"]
+            .into_iter()
+            .map(Ok)
+            .matches_regex(&pattern, |i, s: &str| TestErr::Malformed(i, s.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("a"),
+                Ok("ab"),
+                Err(TestErr::Malformed(2, "abc".to_string()))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_matches_regex_keeps_builder_options() {
+        let pattern = RegexBuilder::new(r"abc")
+            .case_insensitive(true)
+            .build()
+            .unwrap();
+        let results = ["ABC", "aBc", "abd"]
+            .into_iter()
+            .map(Ok)
+            .matches_regex(&pattern, |i, s: &str| TestErr::Malformed(i, s.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("ABC"),
+                Ok("aBc"),
+                Err(TestErr::Malformed(2, "abd".to_string()))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_matches_regex_ignores_errors() {
+        let pattern = email();
+        let results = [Err(TestErr::Missing), Ok("a@b.com")]
+            .into_iter()
+            .matches_regex(&pattern, |i, s: &str| TestErr::Malformed(i, s.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::Missing), Ok("a@b.com")])
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct MaxDecimalPlacesIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    iter: IndexOffset<I>,
+    max_places: usize,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> MaxDecimalPlacesIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        max_places: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> MaxDecimalPlacesIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            max_places,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for MaxDecimalPlacesIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let places = match (self.extractor)(&val).split_once('.') {
+                    Some((_, fraction)) => {
+                        fraction.chars().take_while(|c| c.is_ascii_digit()).count()
+                    }
+                    None => 0,
+                };
+                match places <= self.max_places {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val, places))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for MaxDecimalPlacesIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait MaxDecimalPlaces<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, usize) -> E,
+{
+    /// Fails any element whose number has more than `max_places` digits
+    /// after the decimal point.
+    ///
+    /// `max_decimal_places(max_places, extractor, factory)` counts the digits
+    /// after the `'.'` in the text returned by `extractor` for each element
+    /// wrapped in `Ok`. If there are at most `max_places` of them, the element
+    /// is wrapped in `Ok(element)`. Otherwise, `factory` is called on the
+    /// index of the error, the element and the number of decimal places.
+    ///
+    /// Counting is done on the textual form of the number, which avoids the
+    /// inexact representation of decimal fractions as floats. Trailing zeros
+    /// are counted, a number without a `'.'` has no decimal places, and
+    /// counting stops at the first non digit, such as an exponent. The text is
+    /// not otherwise checked to be a number, see
+    /// [`parses_as`](crate::ParsesAs::parses_as) for that.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::MaxDecimalPlaces;
+    ///
+    /// let mut iter = ["19.99", "5", "0.125"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .max_decimal_places(2, |price| *price, |i, _, places| (i, places));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("19.99")));
+    /// assert_eq!(iter.next(), Some(Ok("5")));
+    /// assert_eq!(iter.next(), Some(Err((2, 3))));
+    /// ```
+    fn max_decimal_places(
+        self,
+        max_places: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> MaxDecimalPlacesIter<Self, T, E, M, Factory> {
+        MaxDecimalPlacesIter::new(self, max_places, extractor, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> MaxDecimalPlaces<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MaxDecimalPlaces;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        TooPrecise(usize, String, usize),
+        Missing,
+    }
+
+    fn amount(record: &(u32, String)) -> &str {
+        &record.1
+    }
+
+    fn too_precise(index: usize, record: (u32, String), places: usize) -> TestErr {
+        TestErr::TooPrecise(index, record.1, places)
+    }
+
+    #[test]
+    fn test_max_decimal_places() {
+        let results = ["1.23", "1.234", "-7", "1.20", "3.1e5", "0.000"]
+            .into_iter()
+            .map(|s| Ok((0, s.to_string())))
+            .max_decimal_places(2, amount, too_precise)
+            .map(|res| res.map(|(_, s)| s))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("1.23".to_string()),
+                Err(TestErr::TooPrecise(1, "1.234".to_string(), 3)),
+                Ok("-7".to_string()),
+                Ok("1.20".to_string()),
+                Ok("3.1e5".to_string()),
+                Err(TestErr::TooPrecise(5, "0.000".to_string(), 3))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_max_decimal_places_ignores_errors() {
+        let results = [Err(TestErr::Missing), Ok((1, "2.5".to_string()))]
+            .into_iter()
+            .max_decimal_places(0, amount, too_precise)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Missing),
+                Err(TestErr::TooPrecise(1, "2.5".to_string(), 1))
+            ]
+        )
+    }
+}
@@ -0,0 +1,199 @@
+use std::{collections::HashSet, hash::Hash};
+
+#[derive(Debug, Clone)]
+pub struct MustCoverIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, HashSet<K>) -> E,
+{
+    iter: I,
+    missing: Option<HashSet<K>>,
+    enumeration_counter: usize,
+    key_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, K, M, Factory> MustCoverIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, HashSet<K>) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        expected: HashSet<K>,
+        key_of: M,
+        factory: Factory,
+    ) -> MustCoverIter<I, T, E, K, M, Factory> {
+        MustCoverIter {
+            iter,
+            missing: Some(expected),
+            enumeration_counter: 0,
+            key_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, K, M, Factory> Iterator for MustCoverIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, HashSet<K>) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.iter.next() {
+            Some(Ok(val)) => {
+                if let Some(missing) = &mut self.missing {
+                    missing.remove(&(self.key_of)(&val));
+                }
+                Some(Ok(val))
+            }
+            None => match self.missing.take() {
+                Some(missing) if !missing.is_empty() => {
+                    Some(Err((self.factory)(self.enumeration_counter, missing)))
+                }
+                _ => return None,
+            },
+            other => other,
+        };
+        self.enumeration_counter += 1;
+        item
+    }
+}
+
+pub trait MustCover<T, E, K, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, HashSet<K>) -> E,
+{
+    /// Fails a validation iterator if some of the `expected` keys
+    /// never appeared in it.
+    ///
+    /// `must_cover(expected, key_of, factory)` yields all elements
+    /// unchanged, while removing the key computed by `key_of` for each
+    /// element from the `expected` set. If at the end of the iteration some
+    /// keys were not seen, a new element is added to the end of the iteration
+    /// with the value returned from calling `factory` on the length of the
+    /// iterator and the set of missing keys.
+    ///
+    /// Like [`at_least`](crate::AtLeast::at_least), the `must_cover` adapter
+    /// cannot handle short-circuiting of iterators.
+    ///
+    /// Elements already wrapped in `Result::Err` do not count as covering
+    /// their key, but are counted in the length provided to `factory`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::HashSet;
+    /// use validiter::MustCover;
+    ///
+    /// let expected = HashSet::from(["host", "port", "user"]);
+    /// let mut iter = [("host", "localhost"), ("user", "root")]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .must_cover(expected, |(key, _)| *key, |len, missing| (len, missing));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(("host", "localhost"))));
+    /// assert_eq!(iter.next(), Some(Ok(("user", "root"))));
+    /// assert_eq!(iter.next(), Some(Err((2, HashSet::from(["port"])))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn must_cover(
+        self,
+        expected: HashSet<K>,
+        key_of: M,
+        factory: Factory,
+    ) -> MustCoverIter<Self, T, E, K, M, Factory> {
+        MustCoverIter::new(self, expected, key_of, factory)
+    }
+}
+
+impl<I, T, E, K, M, Factory> MustCover<T, E, K, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, HashSet<K>) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{Ensure, MustCover};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Missing(usize, HashSet<char>),
+        IsUpper(usize, char),
+    }
+
+    #[test]
+    fn test_must_cover_ok() {
+        let results = "abcab"
+            .chars()
+            .map(Ok)
+            .must_cover(HashSet::from(['a', 'b', 'c']), |c| *c, TestErr::Missing)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok('a'), Ok('b'), Ok('c'), Ok('a'), Ok('b')])
+    }
+
+    #[test]
+    fn test_must_cover_reports_missing_key() {
+        let results = "abab"
+            .chars()
+            .map(Ok)
+            .must_cover(HashSet::from(['a', 'b', 'c']), |c| *c, TestErr::Missing)
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(results, Err(TestErr::Missing(4, HashSet::from(['c']))))
+    }
+
+    #[test]
+    fn test_must_cover_empty_iteration() {
+        let results = ""
+            .chars()
+            .map(Ok)
+            .must_cover(HashSet::from(['a']), |c| *c, TestErr::Missing)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Err(TestErr::Missing(0, HashSet::from(['a'])))]
+        );
+
+        let results = ""
+            .chars()
+            .map(Ok)
+            .must_cover(HashSet::new(), |c| *c, TestErr::Missing)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![])
+    }
+
+    #[test]
+    fn test_must_cover_ignores_errors() {
+        let results = "aB"
+            .chars()
+            .map(Ok)
+            .ensure(|c| c.is_lowercase(), TestErr::IsUpper)
+            .must_cover(HashSet::from(['a', 'B']), |c| *c, TestErr::Missing)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok('a'),
+                Err(TestErr::IsUpper(1, 'B')),
+                Err(TestErr::Missing(2, HashSet::from(['B'])))
+            ]
+        )
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct NoCyclesIter<I, T, E, K, Id, Refs, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    Id: Fn(&T) -> K,
+    Refs: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>) -> E,
+{
+    iter: IndexOffset<I>,
+    edges: HashMap<K, Vec<K>>,
+    id_of: Id,
+    refs_of: Refs,
+    factory: Factory,
+}
+
+impl<I, T, E, K, Id, Refs, Factory> NoCyclesIter<I, T, E, K, Id, Refs, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    Id: Fn(&T) -> K,
+    Refs: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        id_of: Id,
+        refs_of: Refs,
+        factory: Factory,
+    ) -> NoCyclesIter<I, T, E, K, Id, Refs, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            edges: HashMap::new(),
+            id_of,
+            refs_of,
+            factory,
+        }
+    }
+
+    /// Finds a path of references leading from `from` to `to`, both included.
+    fn path(&self, from: &K, to: &K) -> Option<Vec<K>> {
+        if from == to {
+            return Some(vec![from.clone()]);
+        }
+        let mut parents: HashMap<&K, &K> = HashMap::new();
+        let mut visited = HashSet::from([from]);
+        let mut stack = vec![from];
+        while let Some(node) = stack.pop() {
+            for next in self.edges.get(node).into_iter().flatten() {
+                if next == to {
+                    let mut path = vec![to.clone()];
+                    let mut current = node;
+                    path.push(current.clone());
+                    while let Some(parent) = parents.get(current) {
+                        current = parent;
+                        path.push(current.clone());
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                if visited.insert(next) {
+                    parents.insert(next, node);
+                    stack.push(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<I, T, E, K, Id, Refs, Factory> Iterator for NoCyclesIter<I, T, E, K, Id, Refs, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    Id: Fn(&T) -> K,
+    Refs: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let id = (self.id_of)(&val);
+                let refs = (self.refs_of)(&val);
+                let cycle = refs.iter().find_map(|r| {
+                    self.path(r, &id).map(|path| {
+                        let mut cycle = vec![id.clone()];
+                        cycle.extend(path);
+                        cycle
+                    })
+                });
+                match cycle {
+                    Some(cycle) => Some(Err((self.factory)(i, val, cycle))),
+                    None => {
+                        self.edges.entry(id).or_default().extend(refs);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, K, Id, Refs, Factory> WithIndexOffset for NoCyclesIter<I, T, E, K, Id, Refs, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    Id: Fn(&T) -> K,
+    Refs: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait NoCycles<T, E, K, Id, Refs, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash + Clone,
+    Id: Fn(&T) -> K,
+    Refs: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>) -> E,
+{
+    /// Fails any element whose references close a cycle with the references
+    /// of previous elements.
+    ///
+    /// `no_cycles(id_of, refs_of, factory)` builds a graph of references,
+    /// where each element wrapped in `Ok` is a node named by `id_of`, with an
+    /// edge to every id returned by `refs_of`. References may point to ids
+    /// that did not appear yet. When an element arrives, the graph is searched
+    /// for a path from any of its references back to its own id. If there is
+    /// none, the element is wrapped in `Ok(element)` and its edges are added
+    /// to the graph. Otherwise, `factory` is called on the index of the error,
+    /// the element, and the cycle as a list of ids that starts and ends with
+    /// the id of the element. A reference to itself is a cycle of length 1.
+    ///
+    /// Unlike [`deps_precede`](crate::DepsPrecede::deps_precede), forward
+    /// references are allowed, so the whole graph is kept in memory, and every
+    /// element may cause a search over all the edges seen so far.
+    ///
+    /// Elements that failed the validation are not added to the graph, and
+    /// values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::NoCycles;
+    ///
+    /// let modules = [("a", vec!["b"]), ("b", vec!["c"]), ("c", vec!["a"])];
+    /// let mut iter = modules
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .no_cycles(|(id, _)| *id, |(_, refs)| refs.clone(), |i, _, cycle| (i, cycle));
+    ///
+    /// assert!(matches!(iter.next(), Some(Ok(("a", _)))));
+    /// assert!(matches!(iter.next(), Some(Ok(("b", _)))));
+    /// assert_eq!(iter.next(), Some(Err((2, vec!["c", "a", "b", "c"]))));
+    /// ```
+    fn no_cycles(
+        self,
+        id_of: Id,
+        refs_of: Refs,
+        factory: Factory,
+    ) -> NoCyclesIter<Self, T, E, K, Id, Refs, Factory> {
+        NoCyclesIter::new(self, id_of, refs_of, factory)
+    }
+}
+
+impl<I, T, E, K, Id, Refs, Factory> NoCycles<T, E, K, Id, Refs, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash + Clone,
+    Id: Fn(&T) -> K,
+    Refs: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NoCycles;
+
+    type Node = (u32, Vec<u32>);
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Cycle(usize, Vec<u32>),
+        Corrupt,
+    }
+
+    fn cycle(index: usize, _: Node, cycle: Vec<u32>) -> TestErr {
+        TestErr::Cycle(index, cycle)
+    }
+
+    fn check(nodes: Vec<Result<Node, TestErr>>) -> Vec<Result<u32, TestErr>> {
+        nodes
+            .into_iter()
+            .no_cycles(|(id, _)| *id, |(_, refs)| refs.clone(), cycle)
+            .map(|res| res.map(|(id, _)| id))
+            .collect()
+    }
+
+    #[test]
+    fn test_no_cycles_self_loop() {
+        let results = check(vec![Ok((1, vec![])), Ok((2, vec![1, 2]))]);
+        assert_eq!(results, vec![Ok(1), Err(TestErr::Cycle(1, vec![2, 2]))])
+    }
+
+    #[test]
+    fn test_no_cycles_two_node_cycle() {
+        let results = check(vec![Ok((1, vec![2])), Ok((2, vec![1])), Ok((2, vec![3]))]);
+        assert_eq!(
+            results,
+            vec![Ok(1), Err(TestErr::Cycle(1, vec![2, 1, 2])), Ok(2)]
+        )
+    }
+
+    #[test]
+    fn test_no_cycles_dag_with_forward_refs() {
+        let results = check(vec![
+            Ok((1, vec![2, 3])),
+            Err(TestErr::Corrupt),
+            Ok((2, vec![3, 4])),
+            Ok((3, vec![4])),
+            Ok((4, vec![])),
+        ]);
+        assert_eq!(
+            results,
+            vec![Ok(1), Err(TestErr::Corrupt), Ok(2), Ok(3), Ok(4)]
+        )
+    }
+}
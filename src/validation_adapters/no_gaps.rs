@@ -0,0 +1,171 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct NoGapsIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64, i64) -> E,
+{
+    iter: IndexOffset<I>,
+    expected: Option<i64>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> NoGapsIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64, i64) -> E,
+{
+    pub(crate) fn new(iter: I, extractor: M, factory: Factory) -> NoGapsIter<I, T, E, M, Factory> {
+        NoGapsIter {
+            iter: IndexOffset::new(iter),
+            expected: None,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for NoGapsIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64, i64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                match self.expected {
+                    Some(expected) if extraction != expected => {
+                        Some(Err((self.factory)(i, val, extraction, expected)))
+                    }
+                    _ => {
+                        self.expected = Some(extraction.wrapping_add(1));
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for NoGapsIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64, i64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait NoGaps<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64, i64) -> E,
+{
+    /// Fails an iteration if the integers computed by `extractor` do not
+    /// form a consecutive sequence.
+    ///
+    /// `no_gaps(extractor, factory)` expects the value extracted from each
+    /// element to be exactly one more than the value extracted from the last
+    /// valid element. If it is, the element is wrapped in `Ok(element)`.
+    /// Otherwise, `factory` is called on the index of the error, the element,
+    /// the extracted value, and the expected value. The first valid element
+    /// always passes, and sets the start of the sequence.
+    ///
+    /// Elements that failed the validation do not change the expected value,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::NoGaps;
+    ///
+    /// let pages = [1, 2, 4, 3];
+    /// let mut iter = pages
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .no_gaps(|page| *page, |i, _, actual, expected| (i, actual, expected));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), Some(Err((2, 4, 3))));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    fn no_gaps(self, extractor: M, factory: Factory) -> NoGapsIter<Self, T, E, M, Factory> {
+        NoGapsIter::new(self, extractor, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> NoGaps<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64, i64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NoGaps;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Gap(usize, i64, i64),
+        Corrupt,
+    }
+
+    fn gap(index: usize, _: i64, actual: i64, expected: i64) -> TestErr {
+        TestErr::Gap(index, actual, expected)
+    }
+
+    #[test]
+    fn test_no_gaps_ok() {
+        if (-5..5).map(Ok).no_gaps(|i| *i, gap).any(|res| res.is_err()) {
+            panic!("no gaps failed on a consecutive iteration")
+        }
+    }
+
+    #[test]
+    fn test_no_gaps_reports_expected_value() {
+        let results = [1, 2, 4]
+            .into_iter()
+            .map(Ok)
+            .no_gaps(|i| *i, gap)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(1), Ok(2), Err(TestErr::Gap(2, 4, 3))])
+    }
+
+    #[test]
+    fn test_no_gaps_duplicates_and_errors() {
+        let results = [Ok(7), Ok(7), Err(TestErr::Corrupt), Ok(8)]
+            .into_iter()
+            .no_gaps(|i| *i, gap)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(7),
+                Err(TestErr::Gap(1, 7, 8)),
+                Err(TestErr::Corrupt),
+                Ok(8)
+            ]
+        )
+    }
+}
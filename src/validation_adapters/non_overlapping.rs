@@ -0,0 +1,190 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct NonOverlappingIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> (i64, i64),
+    Factory: Fn(usize, T, (i64, i64), (i64, i64)) -> E,
+{
+    iter: IndexOffset<I>,
+    previous: Option<(i64, i64)>,
+    interval_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> NonOverlappingIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> (i64, i64),
+    Factory: Fn(usize, T, (i64, i64), (i64, i64)) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        interval_of: M,
+        factory: Factory,
+    ) -> NonOverlappingIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            previous: None,
+            interval_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for NonOverlappingIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> (i64, i64),
+    Factory: Fn(usize, T, (i64, i64), (i64, i64)) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let interval = (self.interval_of)(&val);
+                match self.previous {
+                    Some(prev) if interval.0 < prev.1 => {
+                        Some(Err((self.factory)(i, val, interval, prev)))
+                    }
+                    _ => {
+                        self.previous = Some(interval);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for NonOverlappingIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> (i64, i64),
+    Factory: Fn(usize, T, (i64, i64), (i64, i64)) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait NonOverlapping<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> (i64, i64),
+    Factory: Fn(usize, T, (i64, i64), (i64, i64)) -> E,
+{
+    /// Fails an iteration of intervals sorted by their start, if any
+    /// interval overlaps the one before it.
+    ///
+    /// `non_overlapping(interval_of, factory)` computes a `(start, end)`
+    /// interval for each element wrapped in `Ok`. If the start of the interval
+    /// is not less than the end of the interval of the last valid element,
+    /// the element is wrapped in `Ok(element)`. Otherwise, `factory` is called
+    /// on the index of the error, the element, its interval and the previous
+    /// interval. The first valid element is always wrapped in `Ok`.
+    ///
+    /// Intervals are treated as half open, so touching intervals such as
+    /// `(0, 5)` and `(5, 10)` do not overlap. Only consecutive intervals are
+    /// compared, so the iteration is expected to be sorted by start, which can
+    /// be checked with [`look_back`](crate::LookBack::look_back).
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::NonOverlapping;
+    ///
+    /// let meetings = [(9, 10), (10, 12), (11, 13)];
+    /// let mut iter = meetings
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .non_overlapping(|m| *m, |i, _, m, prev| (i, m, prev));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok((9, 10))));
+    /// assert_eq!(iter.next(), Some(Ok((10, 12))));
+    /// assert_eq!(iter.next(), Some(Err((2, (11, 13), (10, 12)))));
+    /// ```
+    fn non_overlapping(
+        self,
+        interval_of: M,
+        factory: Factory,
+    ) -> NonOverlappingIter<Self, T, E, M, Factory> {
+        NonOverlappingIter::new(self, interval_of, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> NonOverlapping<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> (i64, i64),
+    Factory: Fn(usize, T, (i64, i64), (i64, i64)) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NonOverlapping;
+
+    type Interval = (i64, i64);
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Overlap(usize, Interval, Interval),
+        Corrupt,
+    }
+
+    fn overlap(index: usize, _: Interval, interval: Interval, prev: Interval) -> TestErr {
+        TestErr::Overlap(index, interval, prev)
+    }
+
+    #[test]
+    fn test_non_overlapping_touching_intervals() {
+        if [(0, 5), (5, 10), (10, 10), (10, 20)]
+            .into_iter()
+            .map(Ok)
+            .non_overlapping(|iv| *iv, overlap)
+            .any(|res| res.is_err())
+        {
+            panic!("non overlapping failed on touching intervals")
+        }
+    }
+
+    #[test]
+    fn test_non_overlapping_overlap_fails() {
+        let results = [(0, 10), (3, 4), (8, 12), (10, 11)]
+            .into_iter()
+            .map(Ok)
+            .non_overlapping(|iv| *iv, overlap)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((0, 10)),
+                Err(TestErr::Overlap(1, (3, 4), (0, 10))),
+                Err(TestErr::Overlap(2, (8, 12), (0, 10))),
+                Ok((10, 11))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_non_overlapping_ignores_errors() {
+        let results = [Ok((0, 2)), Err(TestErr::Corrupt), Ok((2, 3))]
+            .into_iter()
+            .non_overlapping(|iv| *iv, overlap)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok((0, 2)), Err(TestErr::Corrupt), Ok((2, 3))])
+    }
+}
@@ -0,0 +1,194 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct NotInSetIter<'a, I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    iter: IndexOffset<I>,
+    set: &'a HashSet<A>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<'a, I, T, E, A, M, Factory> NotInSetIter<'a, I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        set: &'a HashSet<A>,
+        extractor: M,
+        factory: Factory,
+    ) -> NotInSetIter<'a, I, T, E, A, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            set,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> Iterator for NotInSetIter<'_, I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let key = (self.extractor)(&val);
+                match self.set.contains(&key) {
+                    true => Some(Err((self.factory)(i, val, key))),
+                    false => Some(Ok(val)),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> WithIndexOffset for NotInSetIter<'_, I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait NotInSet<'a, T, E, A, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    A: Eq + Hash,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    /// Fails any element whose extracted value is contained in a
+    /// borrowed set.
+    ///
+    /// `not_in_set(set, extractor, factory)` computes a key for each element
+    /// with `extractor`. If `set` does not contain the key, the element is
+    /// wrapped in `Ok(element)`. Otherwise, `factory` is called on the index
+    /// of the error, the element, and the key.
+    ///
+    /// The set is borrowed, so the same set can be used to validate several
+    /// iterations, and the caller keeps ownership of it. This is useful for
+    /// rejecting elements which already exist in some external snapshot.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::HashSet;
+    /// use validiter::NotInSet;
+    ///
+    /// let existing_ids = HashSet::from([2, 3]);
+    /// let mut iter = [1, 2, 4]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .not_in_set(&existing_ids, |id| *id, |i, _, id| (i, id));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err((1, 2))));
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// assert!(existing_ids.contains(&3)); // still usable
+    /// ```
+    fn not_in_set(
+        self,
+        set: &'a HashSet<A>,
+        extractor: M,
+        factory: Factory,
+    ) -> NotInSetIter<'a, Self, T, E, A, M, Factory> {
+        NotInSetIter::new(self, set, extractor, factory)
+    }
+}
+
+impl<'a, I, T, E, A, M, Factory> NotInSet<'a, T, E, A, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::NotInSet;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr<T, A> {
+        Exists(usize, T, A),
+        Other,
+    }
+
+    #[test]
+    fn test_not_in_set_fails_elements_in_set() {
+        let set = HashSet::from(["b".to_string(), "d".to_string()]);
+        let results = ["a", "b", "c", "d"]
+            .into_iter()
+            .map(Ok)
+            .not_in_set(&set, |s| s.to_string(), TestErr::Exists)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("a"),
+                Err(TestErr::Exists(1, "b", "b".to_string())),
+                Ok("c"),
+                Err(TestErr::Exists(3, "d", "d".to_string()))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_not_in_set_borrows_across_iterations() {
+        let set = HashSet::from([0, 5]);
+        let first = (0..3)
+            .map(Ok)
+            .not_in_set(&set, |i| *i, TestErr::Exists)
+            .collect::<Vec<_>>();
+        let second = (3..6)
+            .map(Ok)
+            .not_in_set(&set, |i| *i, TestErr::Exists)
+            .collect::<Vec<_>>();
+        assert_eq!(first, vec![Err(TestErr::Exists(0, 0, 0)), Ok(1), Ok(2)]);
+        assert_eq!(second, vec![Ok(3), Ok(4), Err(TestErr::Exists(2, 5, 5))]);
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_not_in_set_ignores_errors() {
+        let set = HashSet::from([0]);
+        let results = [Err(TestErr::Other), Ok(0)]
+            .into_iter()
+            .not_in_set(&set, |i| *i, TestErr::Exists)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Err(TestErr::Other), Err(TestErr::Exists(1, 0, 0))]
+        )
+    }
+}
@@ -0,0 +1,173 @@
+/// The decision returned from the callback of
+/// [`on_error_control`](crate::OnErrorControl::on_error_control).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorControl {
+    /// Yield the error, and continue the iteration.
+    Continue,
+    /// Drop the error, and continue the iteration.
+    Skip,
+    /// End the iteration without yielding the error.
+    Abort,
+}
+
+#[derive(Debug, Clone)]
+pub struct OnErrorControlIter<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(&E) -> ErrorControl,
+{
+    iter: I,
+    aborted: bool,
+    control: F,
+}
+
+impl<I, T, E, F> OnErrorControlIter<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(&E) -> ErrorControl,
+{
+    pub(crate) fn new(iter: I, control: F) -> OnErrorControlIter<I, T, E, F> {
+        OnErrorControlIter {
+            iter,
+            aborted: false,
+            control,
+        }
+    }
+}
+
+impl<I, T, E, F> Iterator for OnErrorControlIter<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(&E) -> ErrorControl,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.aborted {
+            return None;
+        }
+        loop {
+            match self.iter.next()? {
+                Ok(val) => return Some(Ok(val)),
+                Err(err) => match (self.control)(&err) {
+                    ErrorControl::Continue => return Some(Err(err)),
+                    ErrorControl::Skip => continue,
+                    ErrorControl::Abort => {
+                        self.aborted = true;
+                        return None;
+                    }
+                },
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.aborted {
+            true => (0, Some(0)),
+            false => (0, self.iter.size_hint().1),
+        }
+    }
+}
+
+pub trait OnErrorControl<T, E, F>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: FnMut(&E) -> ErrorControl,
+{
+    /// Lets a callback decide what happens with each error in a
+    /// validation iterator.
+    ///
+    /// `on_error_control(f)` yields all elements wrapped in `Ok`
+    /// unchanged. For every `Err(error)`, `f` is called on a reference to the
+    /// error, and the returned [`ErrorControl`] decides what happens next:
+    /// 1. `Continue` - the error is yielded.
+    /// 2. `Skip` - the error is dropped, and the iteration continues with the
+    ///    next element.
+    /// 3. `Abort` - the error is dropped, and the iteration ends. The
+    ///    underlying iterator is not advanced anymore.
+    ///
+    /// Since `f` is a `FnMut`, it may keep state, which makes this adapter
+    /// useful for interactive debugging, or for stopping after a number of
+    /// errors.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, ErrorControl, OnErrorControl};
+    ///
+    /// let mut seen = 0;
+    /// let results = (0..10)
+    ///     .map(Ok)
+    ///     .ensure(|i| i % 3 != 0, |_, i| i)
+    ///     .on_error_control(|_| {
+    ///         seen += 1;
+    ///         match seen {
+    ///             1 => ErrorControl::Skip,
+    ///             2 => ErrorControl::Continue,
+    ///             _ => ErrorControl::Abort,
+    ///         }
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(results, vec![Ok(1), Ok(2), Err(3), Ok(4), Ok(5)]);
+    /// ```
+    fn on_error_control(self, f: F) -> OnErrorControlIter<Self, T, E, F> {
+        OnErrorControlIter::new(self, f)
+    }
+}
+
+impl<I, T, E, F> OnErrorControl<T, E, F> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(&E) -> ErrorControl,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ensure, ErrorControl, OnErrorControl};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_on_error_control_continue() {
+        let results = (0..4)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .on_error_control(|_| ErrorControl::Continue)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::IsOdd(1, 1)),
+                Ok(2),
+                Err(TestErr::IsOdd(3, 3))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_on_error_control_skip() {
+        let results = (0..4)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .on_error_control(|_| ErrorControl::Skip)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(2)])
+    }
+
+    #[test]
+    fn test_on_error_control_abort() {
+        let mut source = (0..6).map(Ok).ensure(|i| *i != 2, TestErr::IsOdd);
+        let mut iter = source.by_ref().on_error_control(|_| ErrorControl::Abort);
+        assert_eq!(iter.next(), Some(Ok(0)));
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(source.next(), Some(Ok(3)))
+    }
+}
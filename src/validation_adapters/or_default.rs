@@ -0,0 +1,118 @@
+#[derive(Debug, Clone)]
+pub struct OrDefaultIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Default,
+{
+    iter: I,
+}
+
+impl<I, T, E> OrDefaultIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Default,
+{
+    pub(crate) fn new(iter: I) -> OrDefaultIter<I, T, E> {
+        OrDefaultIter { iter }
+    }
+}
+
+impl<I, T, E> Iterator for OrDefaultIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Default,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| Ok(item.unwrap_or_default()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait OrDefault<T, E>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: Default,
+{
+    /// Replaces every error in the iteration with the default value
+    /// of the element type.
+    ///
+    /// `or_default()` wraps elements already wrapped in `Ok` unchanged,
+    /// and turns every `Err(error)` into `Ok(T::default())`, discarding the
+    /// error. The position of the replaced elements is kept, so the
+    /// iteration is never shortened.
+    ///
+    /// This is the most lenient way to handle failures, and is useful for
+    /// filling numeric data where a failed value can be safely zeroed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, OrDefault};
+    ///
+    /// let values = [3, -1, 5]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure(|v| *v >= 0, |_, _| "negative")
+    ///     .or_default()
+    ///     .collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(values, Ok(vec![3, 0, 5]));
+    /// ```
+    fn or_default(self) -> OrDefaultIter<Self, T, E> {
+        OrDefaultIter::new(self)
+    }
+}
+
+impl<I, T, E> OrDefault<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Default,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtMost, Ensure, OrDefault};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        TooMany(usize, i32),
+    }
+
+    #[test]
+    fn test_or_default_substitutes_failures() {
+        let results = (1..=6)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .or_default()
+            .collect::<Result<Vec<_>, TestErr>>();
+        assert_eq!(results, Ok(vec![0, 2, 0, 4, 0, 6]))
+    }
+
+    #[test]
+    fn test_or_default_substitutes_all_errors() {
+        let results = (1..=6)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .at_most(1, TestErr::TooMany)
+            .or_default()
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(2), Ok(0), Ok(0), Ok(0), Ok(0)])
+    }
+
+    #[test]
+    fn test_or_default_keeps_valid_elements() {
+        let results = ["a", "b"]
+            .into_iter()
+            .map(|s| Ok::<_, TestErr>(s.to_string()))
+            .or_default()
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok("a".to_string()), Ok("b".to_string())])
+    }
+}
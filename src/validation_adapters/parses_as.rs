@@ -0,0 +1,170 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct ParsesAsIter<I, T, E, U, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    U: FromStr,
+    Factory: Fn(usize, T, U::Err) -> E,
+{
+    iter: IndexOffset<I>,
+    factory: Factory,
+    _target: PhantomData<U>,
+}
+
+impl<I, T, E, U, Factory> ParsesAsIter<I, T, E, U, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    U: FromStr,
+    Factory: Fn(usize, T, U::Err) -> E,
+{
+    pub(crate) fn new(iter: I, factory: Factory) -> ParsesAsIter<I, T, E, U, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            factory,
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<I, T, E, U, Factory> Iterator for ParsesAsIter<I, T, E, U, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    U: FromStr,
+    Factory: Fn(usize, T, U::Err) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => match val.as_ref().parse::<U>() {
+                Ok(_) => Some(Ok(val)),
+                Err(parse_err) => Some(Err((self.factory)(i, val, parse_err))),
+            },
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, U, Factory> WithIndexOffset for ParsesAsIter<I, T, E, U, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    U: FromStr,
+    Factory: Fn(usize, T, U::Err) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait ParsesAs<T, E>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: AsRef<str>,
+{
+    /// Fails any element which can't be parsed as a `U`, without replacing
+    /// the element with the parsed value.
+    ///
+    /// `parses_as::<U, _>(factory)` calls [`str::parse::<U>`] on each element
+    /// wrapped in `Ok`. If parsing succeeds, the parsed value is discarded and
+    /// the original element is wrapped in `Ok(element)`. Otherwise, `factory`
+    /// is called on the index of the error, the element and the parse error.
+    ///
+    /// This separates validating the format of the elements from
+    /// transforming them, for example when the text itself should be kept.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::ParsesAs;
+    ///
+    /// let mut iter = ["007", "x7"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .parses_as::<u32, _>(|i, s, _| (i, s));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("007"))); // not 7
+    /// assert_eq!(iter.next(), Some(Err((1, "x7"))));
+    /// ```
+    fn parses_as<U, Factory>(self, factory: Factory) -> ParsesAsIter<Self, T, E, U, Factory>
+    where
+        U: FromStr,
+        Factory: Fn(usize, T, U::Err) -> E,
+    {
+        ParsesAsIter::new(self, factory)
+    }
+}
+
+impl<I, T, E> ParsesAs<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::{ParseFloatError, ParseIntError};
+
+    use crate::ParsesAs;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        NotAnInt(usize, String, ParseIntError),
+        NotAFloat(usize, String, ParseFloatError),
+        Missing,
+    }
+
+    #[test]
+    fn test_parses_as_keeps_original() {
+        let results = ["1", "+2", "three", "04"]
+            .into_iter()
+            .map(|s| Ok(s.to_string()))
+            .parses_as::<i64, _>(TestErr::NotAnInt)
+            .map(|res| res.map_err(|err| matches!(err, TestErr::NotAnInt(2, _, _))))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("1".to_string()),
+                Ok("+2".to_string()),
+                Err(true),
+                Ok("04".to_string())
+            ]
+        )
+    }
+
+    #[test]
+    fn test_parses_as_reports_parse_error() {
+        let results = [Ok("1.5"), Err(TestErr::Missing), Ok("1,5")]
+            .into_iter()
+            .parses_as::<f64, _>(|i, s, err| TestErr::NotAFloat(i, s.to_string(), err))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("1.5"),
+                Err(TestErr::Missing),
+                Err(TestErr::NotAFloat(
+                    2,
+                    "1,5".to_string(),
+                    "1,5".parse::<f64>().unwrap_err()
+                ))
+            ]
+        )
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: HashMap<char, usize>,
+    terminal: bool,
+}
+
+/// The codewords seen so far, where no word is a prefix of another.
+#[derive(Debug, Clone)]
+struct Trie {
+    nodes: Vec<TrieNode>,
+}
+
+impl Trie {
+    fn new() -> Trie {
+        Trie {
+            nodes: vec![TrieNode::default()],
+        }
+    }
+
+    /// Returns a stored word which conflicts with `word`, if there is one.
+    fn conflict(&self, word: &str) -> Option<String> {
+        let mut node = 0;
+        for (pos, c) in word.char_indices() {
+            if self.nodes[node].terminal {
+                return Some(word[..pos].to_string());
+            }
+            match self.nodes[node].children.get(&c) {
+                Some(child) => node = *child,
+                None => return None,
+            }
+        }
+        if self.nodes[node].terminal {
+            return Some(word.to_string());
+        }
+        // `word` is a prefix of every stored word below `node`, pick any
+        let mut conflicting = word.to_string();
+        while let Some((c, child)) = self.nodes[node].children.iter().next() {
+            conflicting.push(*c);
+            node = *child;
+            if self.nodes[node].terminal {
+                return Some(conflicting);
+            }
+        }
+        None
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(child) => *child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+        self.nodes[node].terminal = true;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PrefixFreeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String) -> E,
+{
+    iter: IndexOffset<I>,
+    trie: Trie,
+    bits_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> PrefixFreeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        bits_of: M,
+        factory: Factory,
+    ) -> PrefixFreeIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            trie: Trie::new(),
+            bits_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for PrefixFreeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let bits = (self.bits_of)(&val);
+                match self.trie.conflict(bits) {
+                    Some(conflicting) => Some(Err((self.factory)(i, val, conflicting))),
+                    None => {
+                        self.trie.insert(bits);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for PrefixFreeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait PrefixFree<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String) -> E,
+{
+    /// Fails an element if its codeword and a previous codeword are
+    /// prefixes of one another.
+    ///
+    /// `prefix_free(bits_of, factory)` keeps a trie of the codewords returned
+    /// by `bits_of` for the valid elements. If the codeword of the current
+    /// element has a stored codeword as its prefix, or is itself a prefix of a
+    /// stored codeword, `factory` is called on the index of the error, the
+    /// element, and the conflicting stored codeword. Otherwise, the element is
+    /// wrapped in `Ok(element)`. A repeated codeword conflicts with itself.
+    ///
+    /// Codewords are compared by characters, so they are not limited to
+    /// strings of `'0'` and `'1'`.
+    ///
+    /// Elements that failed the validation are not stored, and values
+    /// already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::PrefixFree;
+    ///
+    /// let mut iter = ["10", "0", "101"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .prefix_free(|code| *code, |i, _, conflicting| (i, conflicting));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("10")));
+    /// assert_eq!(iter.next(), Some(Ok("0")));
+    /// assert_eq!(iter.next(), Some(Err((2, "10".to_string()))));
+    /// ```
+    fn prefix_free(self, bits_of: M, factory: Factory) -> PrefixFreeIter<Self, T, E, M, Factory> {
+        PrefixFreeIter::new(self, bits_of, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> PrefixFree<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PrefixFree;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Conflict(usize, &'static str, String),
+        Corrupt,
+    }
+
+    fn bits<'a>(code: &'a &'static str) -> &'a str {
+        code
+    }
+
+    #[test]
+    fn test_prefix_free_prefix_code() {
+        if ["00", "01", "1"]
+            .into_iter()
+            .map(Ok)
+            .prefix_free(bits, TestErr::Conflict)
+            .any(|res| res.is_err())
+        {
+            panic!("prefix free failed on a prefix code")
+        }
+    }
+
+    #[test]
+    fn test_prefix_free_conflicts() {
+        let results = ["0", "01", "11", "1", "0"]
+            .into_iter()
+            .map(Ok)
+            .prefix_free(bits, TestErr::Conflict)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("0"),
+                Err(TestErr::Conflict(1, "01", "0".to_string())),
+                Ok("11"),
+                Err(TestErr::Conflict(3, "1", "11".to_string())),
+                Err(TestErr::Conflict(4, "0", "0".to_string()))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_prefix_free_ignores_errors() {
+        let results = [
+            Err(TestErr::Corrupt),
+            Ok("1"),
+            Err(TestErr::Corrupt),
+            Ok("0"),
+        ]
+        .into_iter()
+        .prefix_free(bits, TestErr::Conflict)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Corrupt),
+                Ok("1"),
+                Err(TestErr::Corrupt),
+                Ok("0")
+            ]
+        )
+    }
+}
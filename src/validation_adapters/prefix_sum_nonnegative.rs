@@ -0,0 +1,185 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct PrefixSumNonnegativeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    iter: IndexOffset<I>,
+    total: i64,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> PrefixSumNonnegativeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        extractor: M,
+        factory: Factory,
+    ) -> PrefixSumNonnegativeIter<I, T, E, M, Factory> {
+        PrefixSumNonnegativeIter {
+            iter: IndexOffset::new(iter),
+            total: 0,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for PrefixSumNonnegativeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let total = self.total.saturating_add((self.extractor)(&val));
+                match total >= 0 {
+                    true => {
+                        self.total = total;
+                        Some(Ok(val))
+                    }
+                    false => Some(Err((self.factory)(i, val, total))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for PrefixSumNonnegativeIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait PrefixSumNonnegative<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    /// Fails any element which would drive the running sum of the
+    /// iteration below zero.
+    ///
+    /// `prefix_sum_nonnegative(extractor, factory)` keeps a running total
+    /// of the values computed by `extractor`, starting at 0. If adding the
+    /// value of an element keeps the total non-negative, the element is
+    /// wrapped in `Ok(element)` and the total is updated. Otherwise,
+    /// `factory` is called on the index of the error, the element, and the
+    /// negative total the element would have produced.
+    ///
+    /// Elements that failed the validation are not added to the total, so
+    /// the running total never becomes negative, and each element that
+    /// would overdraw it fails on its own. Values already wrapped in
+    /// `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::PrefixSumNonnegative;
+    ///
+    /// let mut iter = [100, -30, -80, 20, -90]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .prefix_sum_nonnegative(|amount| *amount, |i, _, total| (i, total));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(100)));
+    /// assert_eq!(iter.next(), Some(Ok(-30)));
+    /// assert_eq!(iter.next(), Some(Err((2, -10)))); // 70 - 80
+    /// assert_eq!(iter.next(), Some(Ok(20)));
+    /// assert_eq!(iter.next(), Some(Ok(-90)));
+    /// ```
+    fn prefix_sum_nonnegative(
+        self,
+        extractor: M,
+        factory: Factory,
+    ) -> PrefixSumNonnegativeIter<Self, T, E, M, Factory> {
+        PrefixSumNonnegativeIter::new(self, extractor, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> PrefixSumNonnegative<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PrefixSumNonnegative;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Overdrawn(usize, i64, i64),
+        Corrupt,
+    }
+
+    #[test]
+    fn test_prefix_sum_nonnegative_ok() {
+        if [5, -5, 3, -1, -2]
+            .into_iter()
+            .map(Ok)
+            .prefix_sum_nonnegative(|v| *v, TestErr::Overdrawn)
+            .any(|res| res.is_err())
+        {
+            panic!("prefix sum nonnegative failed on a non-negative running sum")
+        }
+    }
+
+    #[test]
+    fn test_prefix_sum_nonnegative_dips_and_recovers() {
+        let results = [10, -5, -10, -6, 3, -8]
+            .into_iter()
+            .map(Ok)
+            .prefix_sum_nonnegative(|v| *v, TestErr::Overdrawn)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(10),
+                Ok(-5),
+                Err(TestErr::Overdrawn(2, -10, -5)),
+                Err(TestErr::Overdrawn(3, -6, -1)),
+                Ok(3),
+                Ok(-8)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_prefix_sum_nonnegative_ignores_errors() {
+        let results = [Ok(1), Err(TestErr::Corrupt), Ok(-2)]
+            .into_iter()
+            .prefix_sum_nonnegative(|v| *v, TestErr::Overdrawn)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Err(TestErr::Corrupt),
+                Err(TestErr::Overdrawn(2, -2, -1))
+            ]
+        )
+    }
+}
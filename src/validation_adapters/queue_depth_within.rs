@@ -0,0 +1,177 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct QueueDepthWithinIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    iter: IndexOffset<I>,
+    depth: i64,
+    delta_of: M,
+    max_depth: i64,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> QueueDepthWithinIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        delta_of: M,
+        max_depth: i64,
+        factory: Factory,
+    ) -> QueueDepthWithinIter<I, T, E, M, Factory> {
+        QueueDepthWithinIter {
+            iter: IndexOffset::new(iter),
+            depth: 0,
+            delta_of,
+            max_depth,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for QueueDepthWithinIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let depth = self.depth.saturating_add((self.delta_of)(&val));
+                match (0..=self.max_depth).contains(&depth) {
+                    true => {
+                        self.depth = depth;
+                        Some(Ok(val))
+                    }
+                    false => Some(Err((self.factory)(i, val, depth))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for QueueDepthWithinIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait QueueDepthWithin<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    /// Fails any element which would drive the depth of a queue below zero
+    /// or above `max_depth`.
+    ///
+    /// `queue_depth_within(delta_of, max_depth, factory)` tracks the depth
+    /// of a queue, starting empty, where each element changes the depth by
+    /// the value computed by `delta_of` - usually `1` for an enqueue and `-1`
+    /// for a dequeue. If the new depth is within `[0, max_depth]`, the
+    /// element is wrapped in `Ok(element)` and the depth is updated.
+    /// Otherwise, `factory` is called on the index of the error, the element,
+    /// and the depth the element would have produced.
+    ///
+    /// Elements that failed the validation do not change the depth, and
+    /// values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::QueueDepthWithin;
+    ///
+    /// let events = ["push", "push", "push", "pop", "pop", "pop", "pop"];
+    /// let mut iter = events
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .queue_depth_within(|e| if *e == "push" { 1 } else { -1 }, 2, |i, _, depth| (i, depth));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("push")));
+    /// assert_eq!(iter.next(), Some(Ok("push")));
+    /// assert_eq!(iter.next(), Some(Err((2, 3))));
+    /// assert_eq!(iter.next(), Some(Ok("pop")));
+    /// assert_eq!(iter.next(), Some(Ok("pop")));
+    /// assert_eq!(iter.next(), Some(Err((5, -1))));
+    /// ```
+    fn queue_depth_within(
+        self,
+        delta_of: M,
+        max_depth: i64,
+        factory: Factory,
+    ) -> QueueDepthWithinIter<Self, T, E, M, Factory> {
+        QueueDepthWithinIter::new(self, delta_of, max_depth, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> QueueDepthWithin<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::QueueDepthWithin;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadDepth(usize, i64, i64),
+        Corrupt,
+    }
+
+    #[test]
+    fn test_queue_depth_within_overflow() {
+        let results = [2, 1, 1, -3, 1]
+            .into_iter()
+            .map(Ok)
+            .queue_depth_within(|d| *d, 3, TestErr::BadDepth)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(2), Ok(1), Err(TestErr::BadDepth(2, 1, 4)), Ok(-3), Ok(1)]
+        )
+    }
+
+    #[test]
+    fn test_queue_depth_within_underflow() {
+        let results = [Ok(1), Ok(-1), Err(TestErr::Corrupt), Ok(-1), Ok(1)]
+            .into_iter()
+            .queue_depth_within(|d| *d, 3, TestErr::BadDepth)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Ok(-1),
+                Err(TestErr::Corrupt),
+                Err(TestErr::BadDepth(3, -1, -1)),
+                Ok(1)
+            ]
+        )
+    }
+}
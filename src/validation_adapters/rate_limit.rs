@@ -0,0 +1,206 @@
+use std::collections::VecDeque;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct RateLimitIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    iter: IndexOffset<I>,
+    max_per_window: usize,
+    window_size: i64,
+    window: VecDeque<i64>,
+    time_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> RateLimitIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        max_per_window: usize,
+        window_size: i64,
+        time_of: M,
+        factory: Factory,
+    ) -> RateLimitIter<I, T, E, M, Factory> {
+        RateLimitIter {
+            iter: IndexOffset::new(iter),
+            max_per_window,
+            window_size,
+            window: VecDeque::new(),
+            time_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for RateLimitIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let time = (self.time_of)(&val);
+                let window_start = time.saturating_sub(self.window_size);
+                while self.window.front().is_some_and(|t| *t <= window_start) {
+                    self.window.pop_front();
+                }
+                match self.window.len() < self.max_per_window {
+                    true => {
+                        self.window.push_back(time);
+                        Some(Ok(val))
+                    }
+                    false => Some(Err((self.factory)(i, val, time))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for RateLimitIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait RateLimit<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+    /// Fails any element which exceeds a limit of `max_per_window`
+    /// elements in a sliding time window.
+    ///
+    /// `rate_limit(max_per_window, window_size, time_of, factory)` computes
+    /// a timestamp for each element with `time_of`, and keeps the timestamps
+    /// of the valid elements seen in the last `window_size` time units. If
+    /// fewer than `max_per_window` elements were seen in the window ending at
+    /// the current timestamp (excluding elements exactly `window_size` units
+    /// older), the element is wrapped in `Ok(element)`. Otherwise, `factory`
+    /// is called on the index of the error, the element, and its timestamp.
+    ///
+    /// Timestamps are expected to be non-decreasing, as in a log. Elements
+    /// that failed the validation are not counted towards the limit, and
+    /// values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::RateLimit;
+    ///
+    /// // at most 2 requests per 10 seconds
+    /// let timestamps = [0, 3, 5, 10, 12];
+    /// let mut iter = timestamps
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .rate_limit(2, 10, |t| *t, |i, _, t| (i, t));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), Some(Err((2, 5))));
+    /// assert_eq!(iter.next(), Some(Ok(10))); // the request at 0 left the window
+    /// assert_eq!(iter.next(), Some(Err((4, 12))));
+    /// ```
+    fn rate_limit(
+        self,
+        max_per_window: usize,
+        window_size: i64,
+        time_of: M,
+        factory: Factory,
+    ) -> RateLimitIter<Self, T, E, M, Factory> {
+        RateLimitIter::new(self, max_per_window, window_size, time_of, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> RateLimit<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> i64,
+    Factory: Fn(usize, T, i64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RateLimit;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        RateExceeded(usize, i64),
+        Corrupt,
+    }
+
+    #[test]
+    fn test_rate_limit_ok() {
+        if (0..100)
+            .map(Ok)
+            .rate_limit(1, 1, |t| *t, |i, _, t| TestErr::RateExceeded(i, t))
+            .any(|res| res.is_err())
+        {
+            panic!("rate limit failed on an iteration within the limit")
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_burst_then_recovery() {
+        let results = [100, 101, 102, 103, 104, 160, 161]
+            .into_iter()
+            .map(Ok)
+            .rate_limit(3, 60, |t| *t, |i, _, t| TestErr::RateExceeded(i, t))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(100),
+                Ok(101),
+                Ok(102),
+                Err(TestErr::RateExceeded(3, 103)),
+                Err(TestErr::RateExceeded(4, 104)),
+                Ok(160),
+                Ok(161)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_rate_limit_ignores_errors() {
+        let results = [Ok(0), Err(TestErr::Corrupt), Ok(1), Ok(5)]
+            .into_iter()
+            .rate_limit(1, 5, |t| *t, |i, _, t| TestErr::RateExceeded(i, t))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::Corrupt),
+                Err(TestErr::RateExceeded(2, 1)),
+                Ok(5)
+            ]
+        )
+    }
+}
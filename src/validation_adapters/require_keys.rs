@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct RequireKeysIter<'a, I, V, E, Factory>
+where
+    I: Iterator<Item = Result<HashMap<String, V>, E>>,
+    Factory: Fn(usize, HashMap<String, V>, &'a str) -> E,
+{
+    iter: IndexOffset<I>,
+    keys: &'a [&'a str],
+    factory: Factory,
+}
+
+impl<'a, I, V, E, Factory> RequireKeysIter<'a, I, V, E, Factory>
+where
+    I: Iterator<Item = Result<HashMap<String, V>, E>>,
+    Factory: Fn(usize, HashMap<String, V>, &'a str) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        keys: &'a [&'a str],
+        factory: Factory,
+    ) -> RequireKeysIter<'a, I, V, E, Factory> {
+        RequireKeysIter {
+            iter: IndexOffset::new(iter),
+            keys,
+            factory,
+        }
+    }
+}
+
+impl<'a, I, V, E, Factory> Iterator for RequireKeysIter<'a, I, V, E, Factory>
+where
+    I: Iterator<Item = Result<HashMap<String, V>, E>>,
+    Factory: Fn(usize, HashMap<String, V>, &'a str) -> E,
+{
+    type Item = Result<HashMap<String, V>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(map))) => {
+                let missing = self.keys.iter().find(|key| !map.contains_key(**key));
+                match missing {
+                    None => Some(Ok(map)),
+                    Some(key) => Some(Err((self.factory)(i, map, key))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, V, E, Factory> WithIndexOffset for RequireKeysIter<'a, I, V, E, Factory>
+where
+    I: Iterator<Item = Result<HashMap<String, V>, E>>,
+    Factory: Fn(usize, HashMap<String, V>, &'a str) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait RequireKeys<'a, V, E, Factory>:
+    Iterator<Item = Result<HashMap<String, V>, E>> + Sized
+where
+    Factory: Fn(usize, HashMap<String, V>, &'a str) -> E,
+{
+    /// Fails any map which is missing one of the required keys.
+    ///
+    /// `require_keys(keys, factory)` checks that each map wrapped in `Ok`
+    /// contains all of `keys`. If it does, the map is wrapped in `Ok(map)`.
+    /// Otherwise, `factory` is called on the index of the error, the map, and
+    /// the first key in `keys` that is missing from it.
+    ///
+    /// This is a structural check, meant to run before validations which
+    /// look up the required keys.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::HashMap;
+    /// use validiter::RequireKeys;
+    ///
+    /// let configs = [
+    ///     HashMap::from([("host".to_string(), "a"), ("port".to_string(), "80")]),
+    ///     HashMap::from([("host".to_string(), "b")]),
+    /// ];
+    /// let mut iter = configs
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .require_keys(&["host", "port"], |i, _, key| (i, key));
+    ///
+    /// assert!(matches!(iter.next(), Some(Ok(_))));
+    /// assert_eq!(iter.next(), Some(Err((1, "port"))));
+    /// ```
+    fn require_keys(
+        self,
+        keys: &'a [&'a str],
+        factory: Factory,
+    ) -> RequireKeysIter<'a, Self, V, E, Factory> {
+        RequireKeysIter::new(self, keys, factory)
+    }
+}
+
+impl<'a, I, V, E, Factory> RequireKeys<'a, V, E, Factory> for I
+where
+    I: Iterator<Item = Result<HashMap<String, V>, E>>,
+    Factory: Fn(usize, HashMap<String, V>, &'a str) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::RequireKeys;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        MissingKey(usize, String),
+        Unparsable,
+    }
+
+    fn config(keys: &[&str]) -> HashMap<String, i32> {
+        keys.iter().map(|key| (key.to_string(), 0)).collect()
+    }
+
+    fn missing_key(index: usize, _: HashMap<String, i32>, key: &str) -> TestErr {
+        TestErr::MissingKey(index, key.to_string())
+    }
+
+    #[test]
+    fn test_require_keys_missing_key_named() {
+        let results = [
+            config(&["a", "b", "c"]),
+            config(&["a", "c"]),
+            config(&["c"]),
+        ]
+        .into_iter()
+        .map(Ok)
+        .require_keys(&["a", "b"], missing_key)
+        .map(|res| res.map(|map| map.len()))
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(3),
+                Err(TestErr::MissingKey(1, "b".to_string())),
+                Err(TestErr::MissingKey(2, "a".to_string()))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_require_keys_no_keys() {
+        if [config(&[])]
+            .into_iter()
+            .map(Ok)
+            .require_keys(&[], missing_key)
+            .any(|res| res.is_err())
+        {
+            panic!("require keys failed with no required keys")
+        }
+    }
+
+    #[test]
+    fn test_require_keys_ignores_errors() {
+        let results = [Err(TestErr::Unparsable), Ok(config(&["a"]))]
+            .into_iter()
+            .require_keys(&["a"], missing_key)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::Unparsable), Ok(config(&["a"]))])
+    }
+}
@@ -0,0 +1,196 @@
+use std::vec::IntoIter;
+
+#[derive(Debug, Clone)]
+pub struct RetryIter<I, T, E, P, R>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: Fn(&E) -> bool,
+    R: FnMut() -> I,
+{
+    source: Option<I>,
+    retries_left: usize,
+    is_transient: P,
+    rebuild: R,
+    output: Option<IntoIter<Result<T, E>>>,
+}
+
+impl<I, T, E, P, R> RetryIter<I, T, E, P, R>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: Fn(&E) -> bool,
+    R: FnMut() -> I,
+{
+    pub(crate) fn new(
+        iter: I,
+        max_retries: usize,
+        is_transient: P,
+        rebuild: R,
+    ) -> RetryIter<I, T, E, P, R> {
+        RetryIter {
+            source: Some(iter),
+            retries_left: max_retries,
+            is_transient,
+            rebuild,
+            output: None,
+        }
+    }
+
+    fn run_attempts(&mut self, mut source: I) -> Vec<Result<T, E>> {
+        'attempts: loop {
+            let mut attempt = Vec::new();
+            for item in source.by_ref() {
+                if let Err(err) = &item {
+                    if self.retries_left > 0 && (self.is_transient)(err) {
+                        self.retries_left -= 1;
+                        source = (self.rebuild)();
+                        continue 'attempts;
+                    }
+                }
+                attempt.push(item);
+            }
+            return attempt;
+        }
+    }
+}
+
+impl<I, T, E, P, R> Iterator for RetryIter<I, T, E, P, R>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: Fn(&E) -> bool,
+    R: FnMut() -> I,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(source) = self.source.take() {
+            self.output = Some(self.run_attempts(source).into_iter());
+        }
+        self.output.as_mut().and_then(|output| output.next())
+    }
+}
+
+pub trait Retry<T, E, P, R>: Iterator<Item = Result<T, E>> + Sized
+where
+    P: Fn(&E) -> bool,
+    R: FnMut() -> Self,
+{
+    /// Re-runs the iteration from a fresh source when a transient error
+    /// is found.
+    ///
+    /// `retry(max_retries, is_transient, rebuild)` consumes the iteration
+    /// until it ends. If an error for which `is_transient` returns `true` is
+    /// found, the attempt is discarded, and a new source is built by calling
+    /// `rebuild`, which is then consumed from its start. This is repeated at
+    /// most `max_retries` times. The elements of the last attempt are then
+    /// yielded - if the retries were exhausted, these include the transient
+    /// error.
+    ///
+    /// Because elements can't be yielded before an attempt is known to be
+    /// final, each attempt is buffered in memory. The first call to `next`
+    /// consumes the whole iteration, possibly more than once.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::cell::Cell;
+    /// use validiter::Retry;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum FetchErr {
+    ///     Timeout,
+    /// }
+    ///
+    /// let attempts = Cell::new(0);
+    /// let fetch = || {
+    ///     attempts.set(attempts.get() + 1);
+    ///     match attempts.get() {
+    ///         1 => vec![Ok(1), Err(FetchErr::Timeout)],
+    ///         _ => vec![Ok(1), Ok(2)],
+    ///     }
+    ///     .into_iter()
+    /// };
+    ///
+    /// let results = fetch()
+    ///     .retry(3, |err| *err == FetchErr::Timeout, fetch)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(results, vec![Ok(1), Ok(2)]);
+    /// assert_eq!(attempts.get(), 2);
+    /// ```
+    fn retry(self, max_retries: usize, is_transient: P, rebuild: R) -> RetryIter<Self, T, E, P, R> {
+        RetryIter::new(self, max_retries, is_transient, rebuild)
+    }
+}
+
+impl<I, T, E, P, R> Retry<T, E, P, R> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: Fn(&E) -> bool,
+    R: FnMut() -> I,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, vec::IntoIter};
+
+    use crate::Retry;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Transient(usize),
+        Permanent,
+    }
+
+    fn flaky_source(attempt: &Cell<usize>, failures: usize) -> IntoIter<Result<i32, TestErr>> {
+        let current = attempt.get();
+        attempt.set(current + 1);
+        match current < failures {
+            true => vec![Ok(0), Ok(1), Err(TestErr::Transient(current)), Ok(3)],
+            false => vec![Ok(0), Ok(1), Ok(2), Ok(3)],
+        }
+        .into_iter()
+    }
+
+    fn is_transient(err: &TestErr) -> bool {
+        matches!(err, TestErr::Transient(_))
+    }
+
+    #[test]
+    fn test_retry_fails_twice_then_succeeds() {
+        let attempt = Cell::new(0);
+        let results = flaky_source(&attempt, 2)
+            .retry(5, is_transient, || flaky_source(&attempt, 2))
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(1), Ok(2), Ok(3)]);
+        assert_eq!(attempt.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_exhausted() {
+        let attempt = Cell::new(0);
+        let results = flaky_source(&attempt, 10)
+            .retry(2, is_transient, || flaky_source(&attempt, 10))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(0), Ok(1), Err(TestErr::Transient(2)), Ok(3)]
+        );
+        assert_eq!(attempt.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_does_not_retry_other_errors() {
+        let attempt = Cell::new(0);
+        let results = vec![Ok(0), Err(TestErr::Permanent)]
+            .into_iter()
+            .retry(2, is_transient, || {
+                attempt.set(attempt.get() + 1);
+                vec![].into_iter()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Err(TestErr::Permanent)]);
+        assert_eq!(attempt.get(), 0);
+    }
+}
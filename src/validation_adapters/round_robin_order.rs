@@ -0,0 +1,196 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct RoundRobinOrderIter<I, T, E, S, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    iter: IndexOffset<I>,
+    num_sources: usize,
+    expected: usize,
+    source_of: S,
+    factory: Factory,
+}
+
+impl<I, T, E, S, Factory> RoundRobinOrderIter<I, T, E, S, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        source_of: S,
+        num_sources: usize,
+        factory: Factory,
+    ) -> RoundRobinOrderIter<I, T, E, S, Factory> {
+        RoundRobinOrderIter {
+            iter: IndexOffset::new(iter),
+            num_sources,
+            expected: 0,
+            source_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, S, Factory> Iterator for RoundRobinOrderIter<I, T, E, S, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // prevent modulo 0 div
+        if self.num_sources == 0 {
+            return self.iter.next().map(|(_, item)| item);
+        }
+
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let source = (self.source_of)(&val);
+                match source == self.expected {
+                    true => {
+                        self.expected = (self.expected + 1) % self.num_sources;
+                        Some(Ok(val))
+                    }
+                    false => Some(Err((self.factory)(i, val, source, self.expected))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, S, Factory> WithIndexOffset for RoundRobinOrderIter<I, T, E, S, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait RoundRobinOrder<T, E, S, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    S: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+    /// Fails an iteration if its elements do not rotate among
+    /// `num_sources` sources in order.
+    ///
+    /// `round_robin_order(source_of, num_sources, factory)` expects the
+    /// sources of the elements, as computed by `source_of`, to be
+    /// `0, 1, ..., num_sources - 1, 0, 1, ...`. If an element comes from the
+    /// expected source, it is wrapped in `Ok(element)`, and the next source
+    /// in the rotation is expected. Otherwise, `factory` is called on the
+    /// index of the error, the element, its source, and the expected source.
+    ///
+    /// If `num_sources` is 0, all elements are yielded unchanged. Elements
+    /// that failed the validation do not advance the rotation, and values
+    /// already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::RoundRobinOrder;
+    ///
+    /// let merged = [(0, 'a'), (1, 'x'), (1, 'y'), (0, 'b')];
+    /// let mut iter = merged
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .round_robin_order(|(source, _)| *source, 2, |i, _, source, expected| (i, source, expected));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok((0, 'a'))));
+    /// assert_eq!(iter.next(), Some(Ok((1, 'x'))));
+    /// assert_eq!(iter.next(), Some(Err((2, 1, 0))));
+    /// assert_eq!(iter.next(), Some(Ok((0, 'b'))));
+    /// ```
+    fn round_robin_order(
+        self,
+        source_of: S,
+        num_sources: usize,
+        factory: Factory,
+    ) -> RoundRobinOrderIter<Self, T, E, S, Factory> {
+        RoundRobinOrderIter::new(self, source_of, num_sources, factory)
+    }
+}
+
+impl<I, T, E, S, Factory> RoundRobinOrder<T, E, S, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Fn(&T) -> usize,
+    Factory: Fn(usize, T, usize, usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RoundRobinOrder;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        OutOfTurn(usize, usize, usize),
+        Corrupt,
+    }
+
+    fn out_of_turn(index: usize, _: usize, source: usize, expected: usize) -> TestErr {
+        TestErr::OutOfTurn(index, source, expected)
+    }
+
+    #[test]
+    fn test_round_robin_order_ok() {
+        if [0, 1, 2, 0, 1, 2]
+            .into_iter()
+            .map(Ok)
+            .round_robin_order(|s| *s, 3, out_of_turn)
+            .any(|res| res.is_err())
+        {
+            panic!("round robin order failed on a rotating iteration")
+        }
+    }
+
+    #[test]
+    fn test_round_robin_order_repeated_source() {
+        let results = [0, 0, 1, 2]
+            .into_iter()
+            .map(Ok)
+            .round_robin_order(|s| *s, 3, out_of_turn)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(0), Err(TestErr::OutOfTurn(1, 0, 1)), Ok(1), Ok(2)]
+        )
+    }
+
+    #[test]
+    fn test_round_robin_order_zero_sources_and_errors() {
+        if [5, 5, 5]
+            .into_iter()
+            .map(Ok)
+            .round_robin_order(|s| *s, 0, out_of_turn)
+            .any(|res| res.is_err())
+        {
+            panic!("round robin order failed when it should not be validating anything")
+        }
+
+        let results = [Ok(0), Err(TestErr::Corrupt), Ok(1)]
+            .into_iter()
+            .round_robin_order(|s| *s, 2, out_of_turn)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Err(TestErr::Corrupt), Ok(1)])
+    }
+}
@@ -0,0 +1,241 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::WithIndexOffset;
+
+/// The reason a draw sequence failed
+/// [`sample_without_replacement`](crate::SampleWithoutReplacement::sample_without_replacement).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SampleFailure<T, K> {
+    /// The element drew a key which was already drawn.
+    Repeated(T, K),
+    /// The iteration drew more times than the size of the universe. Holds
+    /// the number of draws.
+    Overdrawn(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct SampleWithoutReplacementIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, SampleFailure<T, K>) -> E,
+{
+    iter: I,
+    universe_size: usize,
+    drawn: HashSet<K>,
+    draws: usize,
+    index: usize,
+    index_offset: usize,
+    key_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, K, M, Factory> SampleWithoutReplacementIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, SampleFailure<T, K>) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        universe_size: usize,
+        key_of: M,
+        factory: Factory,
+    ) -> SampleWithoutReplacementIter<I, T, E, K, M, Factory> {
+        Self {
+            iter,
+            universe_size,
+            drawn: HashSet::new(),
+            draws: 0,
+            index: 0,
+            index_offset: 0,
+            key_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, K, M, Factory> Iterator for SampleWithoutReplacementIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, SampleFailure<T, K>) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.iter.next() {
+            Some(Ok(val)) => {
+                self.draws += 1;
+                let key = (self.key_of)(&val);
+                match self.drawn.contains(&key) {
+                    true => Some(Err((self.factory)(
+                        self.index_offset + self.index,
+                        SampleFailure::Repeated(val, key),
+                    ))),
+                    false => {
+                        self.drawn.insert(key);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some(err) => Some(err),
+            None => match self.draws > self.universe_size {
+                true => {
+                    let draws = self.draws;
+                    // report the overdraw only once
+                    self.draws = self.universe_size;
+                    Some(Err((self.factory)(
+                        self.index,
+                        SampleFailure::Overdrawn(draws),
+                    )))
+                }
+                false => None,
+            },
+        };
+        self.index += 1;
+        item
+    }
+}
+
+impl<I, T, E, K, M, Factory> WithIndexOffset
+    for SampleWithoutReplacementIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, SampleFailure<T, K>) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.index_offset = offset;
+        self
+    }
+}
+
+pub trait SampleWithoutReplacement<T, E, K, M, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, SampleFailure<T, K>) -> E,
+{
+    /// Fails a sequence of draws from a finite universe which repeats a
+    /// value, or draws more times than the universe holds.
+    ///
+    /// `sample_without_replacement(universe_size, key_of, factory)` works
+    /// like [`unique_by`](crate::UniqueBy::unique_by): the first element with
+    /// a given key, as computed by `key_of`, is wrapped in `Ok(element)`, and
+    /// any later element with the same key fails by calling `factory` on the
+    /// index of the error and [`SampleFailure::Repeated`], holding the
+    /// element and its key.
+    ///
+    /// Every element wrapped in `Ok` counts as a draw, repeated or not. If
+    /// the iteration draws more than `universe_size` times, a new element is
+    /// added to the end of the iteration, with the value returned from
+    /// calling `factory` on the length of the iteration and
+    /// [`SampleFailure::Overdrawn`], holding the number of draws. Like
+    /// [`at_least`](crate::AtLeast::at_least), this check cannot handle
+    /// short-circuiting of iterators.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored, and are not
+    /// counted as draws.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{SampleFailure, SampleWithoutReplacement};
+    ///
+    /// let draws = ["ace", "king", "ace", "queen"];
+    /// let mut iter = draws
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .sample_without_replacement(3, |card| *card, |i, failure| (i, failure));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("ace")));
+    /// assert_eq!(iter.next(), Some(Ok("king")));
+    /// assert_eq!(iter.next(), Some(Err((2, SampleFailure::Repeated("ace", "ace")))));
+    /// assert_eq!(iter.next(), Some(Ok("queen")));
+    /// assert_eq!(iter.next(), Some(Err((4, SampleFailure::Overdrawn(4)))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn sample_without_replacement(
+        self,
+        universe_size: usize,
+        key_of: M,
+        factory: Factory,
+    ) -> SampleWithoutReplacementIter<Self, T, E, K, M, Factory> {
+        SampleWithoutReplacementIter::new(self, universe_size, key_of, factory)
+    }
+}
+
+impl<I, T, E, K, M, Factory> SampleWithoutReplacement<T, E, K, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, SampleFailure<T, K>) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SampleFailure, SampleWithoutReplacement};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadDraw(usize, SampleFailure<u32, u32>),
+        Jammed,
+    }
+
+    #[test]
+    fn test_sample_without_replacement_repeat() {
+        let results = [3, 1, 3, 2]
+            .into_iter()
+            .map(Ok)
+            .sample_without_replacement(4, |d| *d, TestErr::BadDraw)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(3),
+                Ok(1),
+                Err(TestErr::BadDraw(2, SampleFailure::Repeated(3, 3))),
+                Ok(2)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_sample_without_replacement_overdrawn() {
+        let results = [Ok(0), Ok(1), Err(TestErr::Jammed), Ok(2), Ok(3)]
+            .into_iter()
+            .sample_without_replacement(3, |d| *d, TestErr::BadDraw)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(1),
+                Err(TestErr::Jammed),
+                Ok(2),
+                Ok(3),
+                Err(TestErr::BadDraw(5, SampleFailure::Overdrawn(4)))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_sample_without_replacement_full_universe() {
+        if (0..10)
+            .map(Ok)
+            .sample_without_replacement(10, |d| *d, TestErr::BadDraw)
+            .any(|res| res.is_err())
+        {
+            panic!("sample without replacement failed on a permutation of the universe")
+        }
+    }
+}
@@ -0,0 +1,122 @@
+#[derive(Debug, Clone)]
+pub struct SkipWhileErrIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    iter: I,
+    skipping: bool,
+}
+
+impl<I, T, E> SkipWhileErrIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I) -> SkipWhileErrIter<I, T, E> {
+        SkipWhileErrIter {
+            iter,
+            skipping: true,
+        }
+    }
+}
+
+impl<I, T, E> Iterator for SkipWhileErrIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.skipping {
+            self.skipping = false;
+            return self.iter.find(|item| item.is_ok());
+        }
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.skipping {
+            true => (0, upper),
+            false => (lower, upper),
+        }
+    }
+}
+
+pub trait SkipWhileErr<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Discards the errors at the start of a validation iteration.
+    ///
+    /// `skip_while_err()` drops every leading element wrapped in
+    /// `Result::Err`, and starts yielding from the first `Ok(element)`. After
+    /// that, all elements are yielded unchanged, so errors appearing later in
+    /// the iteration are not dropped. If the iteration contains only
+    /// errors, nothing is yielded.
+    ///
+    /// This is the same as calling [`Iterator::skip_while`] with
+    /// [`Result::is_err`], and is useful for feeds that may start with
+    /// garbage before the actual data.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::SkipWhileErr;
+    ///
+    /// let mut iter = [Err("garbage"), Ok(1), Err("bad"), Ok(2)]
+    ///     .into_iter()
+    ///     .skip_while_err();
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err("bad")));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn skip_while_err(self) -> SkipWhileErrIter<Self, T, E> {
+        SkipWhileErrIter::new(self)
+    }
+}
+
+impl<I, T, E> SkipWhileErr<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ensure, SkipWhileErr};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        NotNumeric(usize, &'static str),
+    }
+
+    #[test]
+    fn test_skip_while_err_drops_only_leading_errors() {
+        let results = ["#", "--", "header", "1", "2", "x", "3"]
+            .into_iter()
+            .map(Ok)
+            .ensure(|s| s.parse::<i32>().is_ok(), TestErr::NotNumeric)
+            .skip_while_err()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok("1"), Ok("2"), Err(TestErr::NotNumeric(5, "x")), Ok("3")]
+        )
+    }
+
+    #[test]
+    fn test_skip_while_err_without_errors() {
+        let results = (0..3)
+            .map(Ok::<_, TestErr>)
+            .skip_while_err()
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(1), Ok(2)])
+    }
+
+    #[test]
+    fn test_skip_while_err_only_errors() {
+        let results = ["a", "b"]
+            .into_iter()
+            .map(Ok)
+            .ensure(|s| s.parse::<i32>().is_ok(), TestErr::NotNumeric)
+            .skip_while_err()
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![])
+    }
+}
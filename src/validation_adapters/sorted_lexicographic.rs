@@ -0,0 +1,200 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct SortedLexicographicIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Ord,
+    M: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>, &[K]) -> E,
+{
+    iter: IndexOffset<I>,
+    previous: Option<Vec<K>>,
+    key_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, K, M, Factory> SortedLexicographicIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Ord,
+    M: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>, &[K]) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        key_of: M,
+        factory: Factory,
+    ) -> SortedLexicographicIter<I, T, E, K, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            previous: None,
+            key_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, K, M, Factory> Iterator for SortedLexicographicIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Ord,
+    M: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>, &[K]) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let key = (self.key_of)(&val);
+                match &self.previous {
+                    Some(prev) if key < *prev => Some(Err((self.factory)(i, val, key, prev))),
+                    _ => {
+                        self.previous = Some(key);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, K, M, Factory> WithIndexOffset for SortedLexicographicIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Ord,
+    M: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>, &[K]) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait SortedLexicographic<T, E, K, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    K: Ord,
+    M: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>, &[K]) -> E,
+{
+    /// Fails an iteration if it is not sorted by several keys.
+    ///
+    /// `sorted_lexicographic(key_of, factory)` computes a list of keys for
+    /// each element wrapped in `Ok`, and compares it lexicographically to the
+    /// keys of the last valid element: the first keys are compared first, and
+    /// later keys only break ties. If the keys are greater or equal, the
+    /// element is wrapped in `Ok(element)`. Otherwise, `factory` is called on
+    /// the index of the error, the element, its keys and the previous keys.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::SortedLexicographic;
+    ///
+    /// let rows = [("a", 2), ("b", 1), ("b", 0)];
+    /// let mut iter = rows
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .sorted_lexicographic(
+    ///         |(name, n)| vec![name.to_string(), n.to_string()],
+    ///         |i, _, key, prev| (i, key, prev.to_vec()),
+    ///     );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(("a", 2))));
+    /// assert_eq!(iter.next(), Some(Ok(("b", 1))));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err((2, vec!["b".to_string(), "0".to_string()], vec!["b".to_string(), "1".to_string()])))
+    /// );
+    /// ```
+    fn sorted_lexicographic(
+        self,
+        key_of: M,
+        factory: Factory,
+    ) -> SortedLexicographicIter<Self, T, E, K, M, Factory> {
+        SortedLexicographicIter::new(self, key_of, factory)
+    }
+}
+
+impl<I, T, E, K, M, Factory> SortedLexicographic<T, E, K, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Ord,
+    M: Fn(&T) -> Vec<K>,
+    Factory: Fn(usize, T, Vec<K>, &[K]) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SortedLexicographic;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Inversion(usize, Vec<i32>, Vec<i32>),
+        Corrupt,
+    }
+
+    fn inversion(index: usize, _: (i32, i32), key: Vec<i32>, prev: &[i32]) -> TestErr {
+        TestErr::Inversion(index, key, prev.to_vec())
+    }
+
+    #[test]
+    fn test_sorted_lexicographic_secondary_key_unsorted() {
+        let results = [(1, 5), (2, 1), (2, 3), (2, 2), (3, 0)]
+            .into_iter()
+            .map(Ok)
+            .sorted_lexicographic(|(a, b)| vec![*a, *b], inversion)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((1, 5)),
+                Ok((2, 1)),
+                Ok((2, 3)),
+                Err(TestErr::Inversion(3, vec![2, 2], vec![2, 3])),
+                Ok((3, 0))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_sorted_lexicographic_sorted() {
+        if [(0, 0), (0, 0), (0, 1), (1, -5)]
+            .into_iter()
+            .map(Ok)
+            .sorted_lexicographic(|(a, b)| vec![*a, *b], inversion)
+            .any(|res| res.is_err())
+        {
+            panic!("sorted lexicographic failed on a sorted iteration")
+        }
+    }
+
+    #[test]
+    fn test_sorted_lexicographic_ignores_errors() {
+        let results = [Ok((1, 1)), Err(TestErr::Corrupt), Ok((1, 0))]
+            .into_iter()
+            .sorted_lexicographic(|(a, b)| vec![*a, *b], inversion)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((1, 1)),
+                Err(TestErr::Corrupt),
+                Err(TestErr::Inversion(2, vec![1, 0], vec![1, 1]))
+            ]
+        )
+    }
+}
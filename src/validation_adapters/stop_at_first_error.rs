@@ -0,0 +1,123 @@
+#[derive(Debug, Clone)]
+pub struct StopAtFirstErrorIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    iter: I,
+    stopped: bool,
+}
+
+impl<I, T, E> StopAtFirstErrorIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I) -> StopAtFirstErrorIter<I, T, E> {
+        StopAtFirstErrorIter {
+            iter,
+            stopped: false,
+        }
+    }
+}
+
+impl<I, T, E> Iterator for StopAtFirstErrorIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return None;
+        }
+        let item = self.iter.next();
+        if let Some(Err(_)) = item {
+            self.stopped = true;
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.stopped {
+            true => (0, Some(0)),
+            false => (0, self.iter.size_hint().1),
+        }
+    }
+}
+
+pub trait StopAtFirstError<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Ends an iteration right after its first error.
+    ///
+    /// `stop_at_first_error()` yields elements until the first `Err(error)`,
+    /// yields that error, and then returns `None` forever. No element after
+    /// the first error is pulled from the underlying iterator, which bounds
+    /// the work done on an input that is unusable as a whole.
+    ///
+    /// Unlike collecting into a `Result<Vec<T>, E>`, the iteration can still
+    /// be consumed lazily, for example by a `for` loop.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, StopAtFirstError};
+    ///
+    /// let mut iter = (0..10)
+    ///     .map(Ok)
+    ///     .ensure(|i| *i < 2, |i, _| i)
+    ///     .stop_at_first_error();
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err(2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn stop_at_first_error(self) -> StopAtFirstErrorIter<Self, T, E> {
+        StopAtFirstErrorIter::new(self)
+    }
+}
+
+impl<I, T, E> StopAtFirstError<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::{Ensure, StopAtFirstError};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_stop_at_first_error_does_not_pull_source() {
+        let pulled = Cell::new(0);
+        let results = (0..10)
+            .inspect(|_| pulled.set(pulled.get() + 1))
+            .map(Ok)
+            .ensure(|i| i % 2 == 0 || *i > 4, TestErr::IsOdd)
+            .stop_at_first_error()
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Err(TestErr::IsOdd(1, 1))]);
+        assert_eq!(pulled.get(), 2)
+    }
+
+    #[test]
+    fn test_stop_at_first_error_stays_stopped() {
+        let mut iter = [Err(TestErr::IsOdd(0, 1)), Ok(2)]
+            .into_iter()
+            .stop_at_first_error();
+        assert_eq!(iter.next(), Some(Err(TestErr::IsOdd(0, 1))));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None)
+    }
+
+    #[test]
+    fn test_stop_at_first_error_all_valid() {
+        let results = (0..3)
+            .map(Ok::<_, TestErr>)
+            .stop_at_first_error()
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(1), Ok(2)])
+    }
+}
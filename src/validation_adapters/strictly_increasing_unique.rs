@@ -0,0 +1,239 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+/// The reason an element failed the
+/// [`strictly_increasing_unique`](crate::StrictlyIncreasingUnique::strictly_increasing_unique)
+/// adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncreaseViolation {
+    /// The extracted value is equal to the previous one.
+    Duplicate,
+    /// The extracted value is smaller than the previous one, or can't be
+    /// compared to it.
+    OutOfOrder,
+}
+
+#[derive(Debug, Clone)]
+pub struct StrictlyIncreasingUniqueIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A, IncreaseViolation) -> E,
+{
+    iter: IndexOffset<I>,
+    previous: Option<A>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, Factory> StrictlyIncreasingUniqueIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A, IncreaseViolation) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        extractor: M,
+        factory: Factory,
+    ) -> StrictlyIncreasingUniqueIter<I, T, E, A, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            previous: None,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> Iterator for StrictlyIncreasingUniqueIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A, IncreaseViolation) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                match &self.previous {
+                    Some(prev) if extraction > *prev => {
+                        self.previous = Some(extraction);
+                        Some(Ok(val))
+                    }
+                    Some(prev) => {
+                        let violation = match extraction == *prev {
+                            true => IncreaseViolation::Duplicate,
+                            false => IncreaseViolation::OutOfOrder,
+                        };
+                        Some(Err((self.factory)(i, val, extraction, prev, violation)))
+                    }
+                    None => {
+                        self.previous = Some(extraction);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, Err(e))) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> WithIndexOffset
+    for StrictlyIncreasingUniqueIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A, IncreaseViolation) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait StrictlyIncreasingUnique<T, E, A, M, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A, IncreaseViolation) -> E,
+{
+    /// Fails an iteration if the values computed by `extractor` are
+    /// not strictly increasing.
+    ///
+    /// `strictly_increasing_unique(extractor, factory)` compares the value
+    /// extracted from each element to the value extracted from the last
+    /// valid element. If the current value is greater, the element is
+    /// wrapped in `Ok(element)`. Otherwise, `factory` is called on the index
+    /// of the error, the element, the extracted value, the previous value,
+    /// and an [`IncreaseViolation`] telling apart a duplicate value from a
+    /// value that is out of order. The first valid element is always wrapped
+    /// in `Ok`.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{IncreaseViolation, StrictlyIncreasingUnique};
+    ///
+    /// let mut iter = [1, 3, 3, 2, 4]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .strictly_increasing_unique(|v| *v, |i, _, _, _, violation| (i, violation));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), Some(Err((2, IncreaseViolation::Duplicate))));
+    /// assert_eq!(iter.next(), Some(Err((3, IncreaseViolation::OutOfOrder))));
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// ```
+    fn strictly_increasing_unique(
+        self,
+        extractor: M,
+        factory: Factory,
+    ) -> StrictlyIncreasingUniqueIter<Self, T, E, A, M, Factory> {
+        StrictlyIncreasingUniqueIter::new(self, extractor, factory)
+    }
+}
+
+impl<I, T, E, A, M, Factory> StrictlyIncreasingUnique<T, E, A, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: PartialOrd,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A, &A, IncreaseViolation) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IncreaseViolation, StrictlyIncreasingUnique};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr<T> {
+        Duplicate(usize, T, T),
+        OutOfOrder(usize, T, T),
+        IsZero,
+    }
+
+    fn not_increasing<T: Copy>(
+        index: usize,
+        _: T,
+        extraction: T,
+        prev: &T,
+        violation: IncreaseViolation,
+    ) -> TestErr<T> {
+        match violation {
+            IncreaseViolation::Duplicate => TestErr::Duplicate(index, extraction, *prev),
+            IncreaseViolation::OutOfOrder => TestErr::OutOfOrder(index, extraction, *prev),
+        }
+    }
+
+    #[test]
+    fn test_strictly_increasing_unique_ok() {
+        if (0..10)
+            .map(Ok)
+            .strictly_increasing_unique(|i| *i, not_increasing)
+            .any(|res| res.is_err())
+        {
+            panic!("strictly increasing unique failed on increasing iteration")
+        }
+    }
+
+    #[test]
+    fn test_strictly_increasing_unique_reports_duplicate() {
+        let results = [0, 1, 1, 2]
+            .into_iter()
+            .map(Ok)
+            .strictly_increasing_unique(|i| *i, not_increasing)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(0), Ok(1), Err(TestErr::Duplicate(2, 1, 1)), Ok(2)]
+        )
+    }
+
+    #[test]
+    fn test_strictly_increasing_unique_reports_decrease() {
+        let results = [0, 5, 3, 6]
+            .into_iter()
+            .map(Ok)
+            .strictly_increasing_unique(|i| *i, not_increasing)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(0), Ok(5), Err(TestErr::OutOfOrder(2, 3, 5)), Ok(6)]
+        )
+    }
+
+    #[test]
+    fn test_strictly_increasing_unique_ignores_errors() {
+        let results = [1, 0, 1, 2]
+            .into_iter()
+            .map(|i| match i {
+                0 => Err(TestErr::IsZero),
+                i => Ok(i),
+            })
+            .strictly_increasing_unique(|i| *i, not_increasing)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Err(TestErr::IsZero),
+                Err(TestErr::Duplicate(2, 1, 1)),
+                Ok(2)
+            ]
+        )
+    }
+}
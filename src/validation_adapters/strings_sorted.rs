@@ -0,0 +1,199 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct StringsSortedIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String, &str) -> E,
+{
+    iter: IndexOffset<I>,
+    previous: Option<String>,
+    key_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> StringsSortedIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String, &str) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        key_of: M,
+        factory: Factory,
+    ) -> StringsSortedIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            previous: None,
+            key_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for StringsSortedIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String, &str) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => match &self.previous {
+                Some(prev) if (self.key_of)(&val) < prev.as_str() => {
+                    let key = (self.key_of)(&val).to_string();
+                    Some(Err((self.factory)(i, val, key, prev)))
+                }
+                _ => {
+                    self.previous = Some((self.key_of)(&val).to_string());
+                    Some(Ok(val))
+                }
+            },
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for StringsSortedIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String, &str) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait StringsSorted<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String, &str) -> E,
+{
+    /// Fails an iteration if its string keys are not sorted in byte order.
+    ///
+    /// `strings_sorted(key_of, factory)` compares the string computed by
+    /// `key_of` for each element wrapped in `Ok` to the string of the last
+    /// valid element. If it is greater or equal, the element is wrapped in
+    /// `Ok(element)`. Otherwise, `factory` is called on the index of the
+    /// error, the element, its string and the previous string.
+    ///
+    /// Strings are compared byte by byte, which for UTF-8 is the order of
+    /// their code points. The comparison is case-sensitive and ignores the
+    /// locale, so all uppercase ASCII letters sort before all lowercase ones,
+    /// and `"Cherry" < "apple"`.
+    ///
+    /// Elements that failed the validation do not become the new baseline,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::StringsSorted;
+    ///
+    /// let names = ["Zed", "adam", "Bob"];
+    /// let mut iter = names
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .strings_sorted(|name| name, |i, _, name, prev| (i, name, prev.to_string()));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("Zed")));
+    /// assert_eq!(iter.next(), Some(Ok("adam")));
+    /// assert_eq!(iter.next(), Some(Err((2, "Bob".to_string(), "adam".to_string()))));
+    /// ```
+    fn strings_sorted(
+        self,
+        key_of: M,
+        factory: Factory,
+    ) -> StringsSortedIter<Self, T, E, M, Factory> {
+        StringsSortedIter::new(self, key_of, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> StringsSorted<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> &str,
+    Factory: Fn(usize, T, String, &str) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StringsSorted;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Inversion(usize, String, String),
+        Corrupt,
+    }
+
+    fn key<'a>(word: &'a &'static str) -> &'a str {
+        word
+    }
+
+    fn inversion(index: usize, _: &'static str, word: String, prev: &str) -> TestErr {
+        TestErr::Inversion(index, word, prev.to_string())
+    }
+
+    #[test]
+    fn test_strings_sorted_byte_order() {
+        let results = ["apple", "banana", "Cherry"]
+            .into_iter()
+            .map(Ok)
+            .strings_sorted(key, inversion)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("apple"),
+                Ok("banana"),
+                Err(TestErr::Inversion(
+                    2,
+                    "Cherry".to_string(),
+                    "banana".to_string()
+                ))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_strings_sorted_sorted() {
+        if ["Cherry", "apple", "apple", "banana", "é"]
+            .into_iter()
+            .map(Ok)
+            .strings_sorted(key, inversion)
+            .any(|res| res.is_err())
+        {
+            panic!("strings sorted failed on a sorted iteration")
+        }
+    }
+
+    #[test]
+    fn test_strings_sorted_ignores_errors() {
+        let results = [Ok("b"), Err(TestErr::Corrupt), Ok("a"), Ok("c")]
+            .into_iter()
+            .strings_sorted(key, inversion)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok("b"),
+                Err(TestErr::Corrupt),
+                Err(TestErr::Inversion(2, "a".to_string(), "b".to_string())),
+                Ok("c")
+            ]
+        )
+    }
+}
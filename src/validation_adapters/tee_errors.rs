@@ -0,0 +1,140 @@
+use std::sync::mpsc::Sender;
+
+#[derive(Debug, Clone)]
+pub struct TeeErrorsIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: Clone,
+{
+    iter: I,
+    sender: Sender<E>,
+}
+
+impl<I, T, E> TeeErrorsIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: Clone,
+{
+    pub(crate) fn new(iter: I, sender: Sender<E>) -> TeeErrorsIter<I, T, E> {
+        TeeErrorsIter { iter, sender }
+    }
+}
+
+impl<I, T, E> Iterator for TeeErrorsIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: Clone,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        if let Some(Err(err)) = &item {
+            // a disconnected receiver should not affect the iteration
+            let _ = self.sender.send(err.clone());
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait TeeErrors<T, E>: Iterator<Item = Result<T, E>> + Sized
+where
+    E: Clone,
+{
+    /// Sends a copy of every error in the iteration to a channel.
+    ///
+    /// `tee_errors(sender)` yields all elements unchanged. Every time an
+    /// `Err(error)` is yielded, a clone of `error` is sent through `sender`
+    /// first. This allows collecting errors on another thread, without
+    /// changing how the main consumer handles them.
+    ///
+    /// If the receiving end of the channel was dropped, errors are no longer
+    /// sent, and the iteration continues as usual.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::sync::mpsc::channel;
+    /// use validiter::{Ensure, TeeErrors};
+    ///
+    /// let (sender, receiver) = channel();
+    /// let valid_count = (0..5)
+    ///     .map(Ok)
+    ///     .ensure(|i| i % 2 == 0, |i, _| i)
+    ///     .tee_errors(sender)
+    ///     .filter(|res| res.is_ok())
+    ///     .count();
+    ///
+    /// assert_eq!(valid_count, 3);
+    /// assert_eq!(receiver.iter().collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    fn tee_errors(self, sender: Sender<E>) -> TeeErrorsIter<Self, T, E> {
+        TeeErrorsIter::new(self, sender)
+    }
+}
+
+impl<I, T, E> TeeErrors<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc::channel, thread};
+
+    use crate::{Ensure, TeeErrors};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_tee_errors_sends_all_errors_in_order() {
+        let (sender, receiver) = channel();
+        let collector = thread::spawn(move || receiver.iter().collect::<Vec<_>>());
+        let results = (0..6)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .tee_errors(sender)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err(TestErr::IsOdd(1, 1)),
+                Ok(2),
+                Err(TestErr::IsOdd(3, 3)),
+                Ok(4),
+                Err(TestErr::IsOdd(5, 5))
+            ]
+        );
+        assert_eq!(
+            collector.join().unwrap(),
+            vec![
+                TestErr::IsOdd(1, 1),
+                TestErr::IsOdd(3, 3),
+                TestErr::IsOdd(5, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tee_errors_with_dropped_receiver() {
+        let (sender, receiver) = channel();
+        drop(receiver);
+        let results = (0..2)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .tee_errors(sender)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Err(TestErr::IsOdd(1, 1))]);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+struct TlvRecord {
+    tag_index: usize,
+    declared: Option<usize>,
+    values: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct TlvLengthsIter<I, T, E, Tag, Len, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Tag: Fn(&T) -> bool,
+    Len: Fn(&T) -> usize,
+    Factory: Fn(usize, Option<usize>, usize) -> E,
+{
+    iter: IndexOffset<I>,
+    record: Option<TlvRecord>,
+    pending: Option<T>,
+    is_tag: Tag,
+    length_of: Len,
+    factory: Factory,
+}
+
+impl<I, T, E, Tag, Len, Factory> TlvLengthsIter<I, T, E, Tag, Len, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Tag: Fn(&T) -> bool,
+    Len: Fn(&T) -> usize,
+    Factory: Fn(usize, Option<usize>, usize) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        is_tag: Tag,
+        length_of: Len,
+        factory: Factory,
+    ) -> TlvLengthsIter<I, T, E, Tag, Len, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            record: None,
+            pending: None,
+            is_tag,
+            length_of,
+            factory,
+        }
+    }
+
+    fn close_record(&mut self) -> Option<E> {
+        let record = self.record.take()?;
+        match record.declared == Some(record.values) {
+            true => None,
+            false => Some((self.factory)(
+                record.tag_index,
+                record.declared,
+                record.values,
+            )),
+        }
+    }
+}
+
+impl<I, T, E, Tag, Len, Factory> Iterator for TlvLengthsIter<I, T, E, Tag, Len, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Tag: Fn(&T) -> bool,
+    Len: Fn(&T) -> usize,
+    Factory: Fn(usize, Option<usize>, usize) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(tag) = self.pending.take() {
+            return Some(Ok(tag));
+        }
+        match self.iter.next() {
+            Some((i, Ok(val))) if (self.is_tag)(&val) => {
+                let mismatch = self.close_record();
+                self.record = Some(TlvRecord {
+                    tag_index: i,
+                    declared: None,
+                    values: 0,
+                });
+                match mismatch {
+                    Some(err) => {
+                        self.pending = Some(val);
+                        Some(Err(err))
+                    }
+                    None => Some(Ok(val)),
+                }
+            }
+            Some((_, Ok(val))) => {
+                if let Some(record) = &mut self.record {
+                    match record.declared {
+                        Some(_) => record.values += 1,
+                        None => record.declared = Some((self.length_of)(&val)),
+                    }
+                }
+                Some(Ok(val))
+            }
+            Some((_, err)) => Some(err),
+            None => self.close_record().map(Err),
+        }
+    }
+}
+
+impl<I, T, E, Tag, Len, Factory> WithIndexOffset for TlvLengthsIter<I, T, E, Tag, Len, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Tag: Fn(&T) -> bool,
+    Len: Fn(&T) -> usize,
+    Factory: Fn(usize, Option<usize>, usize) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait TlvLengths<T, E, Tag, Len, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    Tag: Fn(&T) -> bool,
+    Len: Fn(&T) -> usize,
+    Factory: Fn(usize, Option<usize>, usize) -> E,
+{
+    /// Fails the records of a tag-length-value iteration whose number of
+    /// values is not their declared length.
+    ///
+    /// `tlv_lengths(is_tag, length_of, factory)` splits the iteration into
+    /// records, each starting at an element for which `is_tag` returns
+    /// `true`. The element right after the tag is the length of the record,
+    /// computed by `length_of`, and the elements after it, up to the next tag,
+    /// are the values of the record. All elements are yielded unchanged.
+    ///
+    /// When a record ends, at the next tag or at the end of the iteration, the
+    /// number of its values is compared to its length. If they differ, a new
+    /// error is yielded right before the next tag (or at the end), with the
+    /// value returned from calling `factory` on the index of the record's tag,
+    /// its length, and the actual number of values. A record which ends
+    /// before its length element fails with a length of `None`.
+    ///
+    /// Elements before the first tag are not part of any record. Like
+    /// [`at_least`](crate::AtLeast::at_least), the check of the last record
+    /// cannot handle short-circuiting of iterators.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored, and are not
+    /// counted as values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::TlvLengths;
+    ///
+    /// let stream = ["T", "2", "a", "b", "T", "3", "c"];
+    /// let mut iter = stream.into_iter().map(Ok).tlv_lengths(
+    ///     |s| *s == "T",
+    ///     |s| s.parse().unwrap(),
+    ///     |tag, declared, actual| (tag, declared, actual),
+    /// );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("T")));
+    /// assert_eq!(iter.next(), Some(Ok("2")));
+    /// assert_eq!(iter.next(), Some(Ok("a")));
+    /// assert_eq!(iter.next(), Some(Ok("b")));
+    /// assert_eq!(iter.next(), Some(Ok("T")));
+    /// assert_eq!(iter.next(), Some(Ok("3")));
+    /// assert_eq!(iter.next(), Some(Ok("c")));
+    /// assert_eq!(iter.next(), Some(Err((4, Some(3), 1))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn tlv_lengths(
+        self,
+        is_tag: Tag,
+        length_of: Len,
+        factory: Factory,
+    ) -> TlvLengthsIter<Self, T, E, Tag, Len, Factory> {
+        TlvLengthsIter::new(self, is_tag, length_of, factory)
+    }
+}
+
+impl<I, T, E, Tag, Len, Factory> TlvLengths<T, E, Tag, Len, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    Tag: Fn(&T) -> bool,
+    Len: Fn(&T) -> usize,
+    Factory: Fn(usize, Option<usize>, usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TlvLengths;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        WrongLength(usize, Option<usize>, usize),
+        Corrupt,
+    }
+
+    const TAG: i32 = -1;
+
+    fn check(stream: Vec<Result<i32, TestErr>>) -> Vec<Result<i32, TestErr>> {
+        stream
+            .into_iter()
+            .tlv_lengths(|i| *i == TAG, |i| *i as usize, TestErr::WrongLength)
+            .collect()
+    }
+
+    #[test]
+    fn test_tlv_lengths_back_to_back_records() {
+        let results = check(vec![
+            Ok(TAG),
+            Ok(2),
+            Ok(7),
+            Ok(8),
+            Ok(TAG),
+            Ok(1),
+            Ok(7),
+            Ok(8),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                Ok(TAG),
+                Ok(2),
+                Ok(7),
+                Ok(8),
+                Ok(TAG),
+                Ok(1),
+                Ok(7),
+                Ok(8),
+                Err(TestErr::WrongLength(4, Some(1), 2))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_tlv_lengths_mismatch_before_next_tag() {
+        let results = check(vec![
+            Ok(5),
+            Ok(TAG),
+            Ok(3),
+            Ok(0),
+            Err(TestErr::Corrupt),
+            Ok(TAG),
+            Ok(0),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                Ok(5),
+                Ok(TAG),
+                Ok(3),
+                Ok(0),
+                Err(TestErr::Corrupt),
+                Err(TestErr::WrongLength(1, Some(3), 1)),
+                Ok(TAG),
+                Ok(0)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_tlv_lengths_missing_length() {
+        let results = check(vec![Ok(TAG), Ok(TAG), Ok(0)]);
+        assert_eq!(
+            results,
+            vec![
+                Ok(TAG),
+                Err(TestErr::WrongLength(0, None, 0)),
+                Ok(TAG),
+                Ok(0)
+            ]
+        )
+    }
+}
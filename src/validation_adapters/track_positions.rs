@@ -0,0 +1,130 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct TrackPositionsIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    iter: IndexOffset<I>,
+}
+
+impl<I, T, E> TrackPositionsIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I) -> TrackPositionsIter<I, T, E> {
+        TrackPositionsIter {
+            iter: IndexOffset::new(iter),
+        }
+    }
+}
+
+impl<I, T, E> Iterator for TrackPositionsIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, (usize, E)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(i, item)| item.map_err(|err| (i, err)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E> WithIndexOffset for TrackPositionsIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait TrackPositions<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Attaches the position of every error in the iteration to the error.
+    ///
+    /// `track_positions()` wraps elements already wrapped in `Ok` unchanged,
+    /// and turns every `Err(error)` into `Err((index, error))`, where `index`
+    /// is the position of the error in the iteration it is called on. This
+    /// keeps the position of a failure available after collecting into a
+    /// `Result<Vec<T>, _>`, even if the error type itself does not carry it.
+    ///
+    /// Since the position is computed at the point where `track_positions`
+    /// is called, it should usually be the last adapter before collecting.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, TrackPositions};
+    ///
+    /// let values = [3, -1, 5]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure(|v| *v >= 0, |_, _| "negative")
+    ///     .track_positions()
+    ///     .collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert_eq!(values, Err((1, "negative")));
+    /// ```
+    fn track_positions(self) -> TrackPositionsIter<Self, T, E> {
+        TrackPositionsIter::new(self)
+    }
+}
+
+impl<I, T, E> TrackPositions<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtMost, Ensure, TrackPositions};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd,
+        TooMany,
+    }
+
+    #[test]
+    fn test_track_positions_collect_reports_index() {
+        let results = [0, 2, 4, 5, 6]
+            .into_iter()
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, |_, _| TestErr::IsOdd)
+            .track_positions()
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(results, Err((3, TestErr::IsOdd)))
+    }
+
+    #[test]
+    fn test_track_positions_every_error() {
+        let results = (0..4)
+            .map(Ok)
+            .at_most(1, |_, _| TestErr::TooMany)
+            .track_positions()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Err((1, TestErr::TooMany)),
+                Err((2, TestErr::TooMany)),
+                Err((3, TestErr::TooMany))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_track_positions_all_valid() {
+        let results = (0..3)
+            .map(Ok::<_, TestErr>)
+            .track_positions()
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(results, Ok(vec![0, 1, 2]))
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct TrimmedIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    iter: IndexOffset<I>,
+    factory: Factory,
+}
+
+impl<I, T, E, Factory> TrimmedIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    pub(crate) fn new(iter: I, factory: Factory) -> TrimmedIter<I, T, E, Factory> {
+        TrimmedIter {
+            iter: IndexOffset::new(iter),
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, Factory> Iterator for TrimmedIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let text = val.as_ref();
+                match text.trim().len() == text.len() {
+                    true => Some(Ok(val)),
+                    false => Some(Err((self.factory)(i, val))),
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, Factory> WithIndexOffset for TrimmedIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait Trimmed<T, E, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+    /// Fails any string which starts or ends with whitespace.
+    ///
+    /// `trimmed(factory)` wraps every string equal to its own
+    /// [`trim`](str::trim) in `Ok(string)`, and calls `factory` on the index
+    /// of the error and the string for any other string. Whitespace is
+    /// defined as in [`trim`](str::trim), and includes non-ASCII whitespace
+    /// such as `'\u{a0}'`. Whitespace inside the string is allowed.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::Trimmed;
+    ///
+    /// let mut iter = ["New York", "Paris\n"].into_iter().map(Ok).trimmed(|i, city| (i, city));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("New York")));
+    /// assert_eq!(iter.next(), Some(Err((1, "Paris\n"))));
+    /// ```
+    fn trimmed(self, factory: Factory) -> TrimmedIter<Self, T, E, Factory> {
+        TrimmedIter::new(self, factory)
+    }
+}
+
+impl<I, T, E, Factory> Trimmed<T, E, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: AsRef<str>,
+    Factory: Fn(usize, T) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Trimmed;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Untrimmed(usize, String),
+        Unreadable,
+    }
+
+    #[test]
+    fn test_trimmed_leading_and_trailing() {
+        let results = [" x", "x ", "x"]
+            .into_iter()
+            .map(|s| Ok(s.to_string()))
+            .trimmed(TestErr::Untrimmed)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Untrimmed(0, " x".to_string())),
+                Err(TestErr::Untrimmed(1, "x ".to_string())),
+                Ok("x".to_string())
+            ]
+        )
+    }
+
+    #[test]
+    fn test_trimmed_ignores_errors() {
+        let results = [
+            Err(TestErr::Unreadable),
+            Ok("a b".to_string()),
+            Ok("".to_string()),
+        ]
+        .into_iter()
+        .trimmed(TestErr::Untrimmed)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Unreadable),
+                Ok("a b".to_string()),
+                Ok("".to_string())
+            ]
+        )
+    }
+}
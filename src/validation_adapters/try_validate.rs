@@ -0,0 +1,116 @@
+#[derive(Debug, Clone)]
+pub struct TryValidateIter<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> TryValidateIter<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> TryValidateIter<I, F> {
+        TryValidateIter { iter, f }
+    }
+}
+
+impl<I, U, E, F> Iterator for TryValidateIter<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<U, E>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(&mut self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait TryValidate: Iterator + Sized {
+    /// Starts a validation iterator from a plain iterator and a fallible
+    /// conversion.
+    ///
+    /// `try_validate(f)` calls `f` on each element, and yields its result,
+    /// so every element becomes either `Ok(converted)` or `Err(error)`. The
+    /// validation adapters can then be chained directly on the result.
+    ///
+    /// This is the same as `map(f)`, but names the point where a validation
+    /// pipeline begins, and avoids spelling out the `Result` in the closure
+    /// when `f` is a function such as [`str::parse`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, TryValidate};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum AgeErr {
+    ///     NotANumber,
+    ///     TooOld(usize, u32),
+    /// }
+    ///
+    /// let ages = ["31", "x", "140"]
+    ///     .into_iter()
+    ///     .try_validate(|s| s.parse::<u32>().map_err(|_| AgeErr::NotANumber))
+    ///     .ensure(|age| *age < 130, AgeErr::TooOld)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     ages,
+    ///     vec![Ok(31), Err(AgeErr::NotANumber), Err(AgeErr::TooOld(2, 140))]
+    /// );
+    /// ```
+    fn try_validate<U, E, F>(self, f: F) -> TryValidateIter<Self, F>
+    where
+        F: FnMut(Self::Item) -> Result<U, E>,
+    {
+        TryValidateIter::new(self, f)
+    }
+}
+
+impl<I> TryValidate for I where I: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use crate::{Ensure, TryValidate};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Parse(ParseIntError),
+        IsNegative(usize, i32),
+    }
+
+    #[test]
+    fn test_try_validate_feeds_ensure() {
+        let results = ["1", "-2", "three", "4"]
+            .into_iter()
+            .try_validate(|s| s.parse::<i32>().map_err(TestErr::Parse))
+            .ensure(|i| *i >= 0, TestErr::IsNegative)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Err(TestErr::IsNegative(1, -2)),
+                Err(TestErr::Parse("three".parse::<i32>().unwrap_err())),
+                Ok(4)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_try_validate_stateful_mapper() {
+        let mut seen = 0;
+        let results = (0..3)
+            .try_validate(|i| {
+                seen += 1;
+                Ok::<_, TestErr>(i * 10)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(10), Ok(20)]);
+        assert_eq!(seen, 3)
+    }
+}
@@ -0,0 +1,199 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct UniqueByIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    iter: IndexOffset<I>,
+    seen: HashSet<K>,
+    key_of: M,
+    factory: Factory,
+}
+
+impl<I, T, E, K, M, Factory> UniqueByIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        key_of: M,
+        factory: Factory,
+    ) -> UniqueByIter<I, T, E, K, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            seen: HashSet::new(),
+            key_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, K, M, Factory> Iterator for UniqueByIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let key = (self.key_of)(&val);
+                match self.seen.contains(&key) {
+                    true => Some(Err((self.factory)(i, val, key))),
+                    false => {
+                        self.seen.insert(key);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, K, M, Factory> WithIndexOffset for UniqueByIter<I, T, E, K, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait UniqueBy<T, E, K, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+    /// Fails an element if the key computed by `key_of` was already seen
+    /// in the iteration.
+    ///
+    /// `unique_by(key_of, factory)` remembers the keys of all the elements
+    /// wrapped in `Ok`. The first element with a given key is wrapped in
+    /// `Ok(element)`, and any later element with the same key fails by calling
+    /// `factory` on the index of the error, the element and the repeated key.
+    /// The key can be any projection of the element, such as a tuple of
+    /// several fields for composite key uniqueness.
+    ///
+    /// Memory grows with the number of distinct keys. For infinite iterations,
+    /// see [`unique_within`](crate::UniqueWithin::unique_within).
+    ///
+    /// Elements that failed the validation are not remembered, and values
+    /// already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::UniqueBy;
+    ///
+    /// let rows = [(1, "mon", 3), (2, "mon", 5), (1, "mon", 7)];
+    /// let mut iter = rows
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .unique_by(|(user, day, _)| (*user, *day), |i, _, key| (i, key));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok((1, "mon", 3))));
+    /// assert_eq!(iter.next(), Some(Ok((2, "mon", 5))));
+    /// assert_eq!(iter.next(), Some(Err((2, (1, "mon")))));
+    /// ```
+    fn unique_by(self, key_of: M, factory: Factory) -> UniqueByIter<Self, T, E, K, M, Factory> {
+        UniqueByIter::new(self, key_of, factory)
+    }
+}
+
+impl<I, T, E, K, M, Factory> UniqueBy<T, E, K, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    K: Eq + Hash,
+    M: Fn(&T) -> K,
+    Factory: Fn(usize, T, K) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UniqueBy;
+
+    type Row = (u32, &'static str, f64);
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Duplicate(usize, Row, (u32, &'static str)),
+        Corrupt,
+    }
+
+    fn key(row: &Row) -> (u32, &'static str) {
+        (row.0, row.1)
+    }
+
+    #[test]
+    fn test_unique_by_composite_key() {
+        let results = [(1, "mon", 1.0), (1, "tue", 2.0), (1, "mon", 3.0)]
+            .into_iter()
+            .map(Ok)
+            .unique_by(key, TestErr::Duplicate)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((1, "mon", 1.0)),
+                Ok((1, "tue", 2.0)),
+                Err(TestErr::Duplicate(2, (1, "mon", 3.0), (1, "mon")))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_unique_by_distinct_keys() {
+        if (0..100)
+            .map(|i| Ok((i, "day", 0.0)))
+            .unique_by(key, TestErr::Duplicate)
+            .any(|res| res.is_err())
+        {
+            panic!("unique by failed on distinct keys")
+        }
+    }
+
+    #[test]
+    fn test_unique_by_ignores_errors() {
+        let results = [
+            Err(TestErr::Corrupt),
+            Ok((1, "mon", 0.0)),
+            Err(TestErr::Corrupt),
+        ]
+        .into_iter()
+        .unique_by(key, TestErr::Duplicate)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Corrupt),
+                Ok((1, "mon", 0.0)),
+                Err(TestErr::Corrupt)
+            ]
+        )
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct UniqueWithinIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash + Clone,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    iter: IndexOffset<I>,
+    window: usize,
+    recent: VecDeque<A>,
+    seen: HashSet<A>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, A, M, Factory> UniqueWithinIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash + Clone,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        window: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> UniqueWithinIter<I, T, E, A, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            window,
+            recent: VecDeque::new(),
+            seen: HashSet::new(),
+            extractor,
+            factory,
+        }
+    }
+
+    fn push(&mut self, key: A) {
+        if self.recent.len() == self.window {
+            if let Some(oldest) = self.recent.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(key.clone());
+        self.recent.push_back(key);
+    }
+}
+
+impl<I, T, E, A, M, Factory> Iterator for UniqueWithinIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash + Clone,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // a window of 0 can't contain repetitions
+        if self.window == 0 {
+            return self.iter.next().map(|(_, item)| item);
+        }
+
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let key = (self.extractor)(&val);
+                match self.seen.contains(&key) {
+                    true => Some(Err((self.factory)(i, val, key))),
+                    false => {
+                        self.push(key);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, A, M, Factory> WithIndexOffset for UniqueWithinIter<I, T, E, A, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash + Clone,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait UniqueWithin<T, E, A, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    A: Eq + Hash + Clone,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+    /// Fails an element if the value computed by `extractor` repeats
+    /// a value seen in the last `window` valid elements.
+    ///
+    /// `unique_within(window, extractor, factory)` remembers the values
+    /// extracted from the last `window` elements wrapped in `Ok`. If the
+    /// value extracted from the current element is one of them, `factory`
+    /// is called on the index of the error, the element and the repeated
+    /// value. Otherwise the element is wrapped in `Ok(element)`, and its value
+    /// replaces the oldest remembered value.
+    ///
+    /// Memory is bounded by `window`, so unlike a global uniqueness check,
+    /// `unique_within` is suitable for infinite iterations, at the cost of
+    /// accepting repetitions which are further than `window` elements apart.
+    /// A `window` of 0 accepts all elements.
+    ///
+    /// Elements that failed the validation are not remembered, and values
+    /// already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::UniqueWithin;
+    ///
+    /// let mut iter = [1, 2, 1, 3, 1]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .unique_within(2, |v| *v, |index, _, key| (index, key));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), Some(Err((2, 1)))); // 1 is still in the window [1, 2]
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), Some(Ok(1))); // the window is now [2, 3]
+    /// ```
+    fn unique_within(
+        self,
+        window: usize,
+        extractor: M,
+        factory: Factory,
+    ) -> UniqueWithinIter<Self, T, E, A, M, Factory> {
+        UniqueWithinIter::new(self, window, extractor, factory)
+    }
+}
+
+impl<I, T, E, A, M, Factory> UniqueWithin<T, E, A, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    A: Eq + Hash + Clone,
+    M: Fn(&T) -> A,
+    Factory: Fn(usize, T, A) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UniqueWithin;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Repeated(usize, i32, i32),
+        IsNegative(i32),
+    }
+
+    #[test]
+    fn test_unique_within_repeat_inside_window() {
+        let results = [1, 2, 3, 1]
+            .into_iter()
+            .map(Ok)
+            .unique_within(3, |i| *i, TestErr::Repeated)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok(1), Ok(2), Ok(3), Err(TestErr::Repeated(3, 1, 1))]
+        )
+    }
+
+    #[test]
+    fn test_unique_within_repeat_outside_window() {
+        if [1, 2, 3, 1]
+            .into_iter()
+            .map(Ok)
+            .unique_within(2, |i| *i, TestErr::Repeated)
+            .any(|res| res.is_err())
+        {
+            panic!("unique within failed on a repetition outside of the window")
+        }
+    }
+
+    #[test]
+    fn test_unique_within_failed_elements_are_not_remembered() {
+        let results = [1, 1, 2, 1, 3, 1]
+            .into_iter()
+            .map(Ok)
+            .unique_within(2, |i| *i % 10, TestErr::Repeated)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Err(TestErr::Repeated(1, 1, 1)),
+                Ok(2),
+                Err(TestErr::Repeated(3, 1, 1)),
+                Ok(3),
+                Ok(1)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_unique_within_zero_window() {
+        if [0, 0, 0]
+            .into_iter()
+            .map(Ok)
+            .unique_within(0, |i| *i, TestErr::Repeated)
+            .any(|res| res.is_err())
+        {
+            panic!("unique within failed with a window of 0")
+        }
+    }
+
+    #[test]
+    fn test_unique_within_ignores_errors() {
+        let results = [1, -1, 1]
+            .into_iter()
+            .map(|i| match i < 0 {
+                true => Err(TestErr::IsNegative(i)),
+                false => Ok(i),
+            })
+            .unique_within(1, |i| *i, TestErr::Repeated)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Err(TestErr::IsNegative(-1)),
+                Err(TestErr::Repeated(2, 1, 1))
+            ]
+        )
+    }
+}
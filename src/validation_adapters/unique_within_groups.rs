@@ -0,0 +1,227 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct UniqueWithinGroupsIter<I, T, E, G, K, MG, MK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: Eq + Hash,
+    K: Eq + Hash,
+    MG: Fn(&T) -> G,
+    MK: Fn(&T) -> K,
+    Factory: Fn(usize, T, G, K) -> E,
+{
+    iter: IndexOffset<I>,
+    seen: HashMap<G, HashSet<K>>,
+    group_of: MG,
+    key_of: MK,
+    factory: Factory,
+}
+
+impl<I, T, E, G, K, MG, MK, Factory> UniqueWithinGroupsIter<I, T, E, G, K, MG, MK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: Eq + Hash,
+    K: Eq + Hash,
+    MG: Fn(&T) -> G,
+    MK: Fn(&T) -> K,
+    Factory: Fn(usize, T, G, K) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        group_of: MG,
+        key_of: MK,
+        factory: Factory,
+    ) -> UniqueWithinGroupsIter<I, T, E, G, K, MG, MK, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            seen: HashMap::new(),
+            group_of,
+            key_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, G, K, MG, MK, Factory> Iterator
+    for UniqueWithinGroupsIter<I, T, E, G, K, MG, MK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: Eq + Hash,
+    K: Eq + Hash,
+    MG: Fn(&T) -> G,
+    MK: Fn(&T) -> K,
+    Factory: Fn(usize, T, G, K) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let group = (self.group_of)(&val);
+                let key = (self.key_of)(&val);
+                let repeated = self
+                    .seen
+                    .get(&group)
+                    .is_some_and(|keys| keys.contains(&key));
+                match repeated {
+                    true => Some(Err((self.factory)(i, val, group, key))),
+                    false => {
+                        self.seen.entry(group).or_default().insert(key);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, G, K, MG, MK, Factory> WithIndexOffset
+    for UniqueWithinGroupsIter<I, T, E, G, K, MG, MK, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: Eq + Hash,
+    K: Eq + Hash,
+    MG: Fn(&T) -> G,
+    MK: Fn(&T) -> K,
+    Factory: Fn(usize, T, G, K) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait UniqueWithinGroups<T, E, G, K, MG, MK, Factory>:
+    Iterator<Item = Result<T, E>> + Sized
+where
+    G: Eq + Hash,
+    K: Eq + Hash,
+    MG: Fn(&T) -> G,
+    MK: Fn(&T) -> K,
+    Factory: Fn(usize, T, G, K) -> E,
+{
+    /// Fails an element if its key was already seen in the same group.
+    ///
+    /// `unique_within_groups(group_of, key_of, factory)` computes a group
+    /// and a key for each element wrapped in `Ok`, and remembers the keys
+    /// seen in every group. The first element with a given key in a group is
+    /// wrapped in `Ok(element)`, and any later element with the same key in
+    /// the same group fails by calling `factory` on the index of the error,
+    /// the element, the group and the repeated key. The same key may appear
+    /// once in each of several groups.
+    ///
+    /// Groups do not need to be contiguous. Memory grows with the number of
+    /// distinct group and key pairs.
+    ///
+    /// Elements that failed the validation are not remembered, and values
+    /// already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::UniqueWithinGroups;
+    ///
+    /// let employees = [("sales", "dan"), ("dev", "dan"), ("sales", "dan")];
+    /// let mut iter = employees.into_iter().map(Ok).unique_within_groups(
+    ///     |(dept, _)| *dept,
+    ///     |(_, name)| *name,
+    ///     |i, _, dept, name| (i, dept, name),
+    /// );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(("sales", "dan"))));
+    /// assert_eq!(iter.next(), Some(Ok(("dev", "dan"))));
+    /// assert_eq!(iter.next(), Some(Err((2, "sales", "dan"))));
+    /// ```
+    fn unique_within_groups(
+        self,
+        group_of: MG,
+        key_of: MK,
+        factory: Factory,
+    ) -> UniqueWithinGroupsIter<Self, T, E, G, K, MG, MK, Factory> {
+        UniqueWithinGroupsIter::new(self, group_of, key_of, factory)
+    }
+}
+
+impl<I, T, E, G, K, MG, MK, Factory> UniqueWithinGroups<T, E, G, K, MG, MK, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    G: Eq + Hash,
+    K: Eq + Hash,
+    MG: Fn(&T) -> G,
+    MK: Fn(&T) -> K,
+    Factory: Fn(usize, T, G, K) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UniqueWithinGroups;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Repeated(usize, u8, char),
+        Corrupt,
+    }
+
+    fn repeated(index: usize, _: (u8, char), group: u8, key: char) -> TestErr {
+        TestErr::Repeated(index, group, key)
+    }
+
+    #[test]
+    fn test_unique_within_groups_same_key_different_groups() {
+        if [(0, 'a'), (1, 'a'), (2, 'a'), (0, 'b')]
+            .into_iter()
+            .map(Ok)
+            .unique_within_groups(|(g, _)| *g, |(_, k)| *k, repeated)
+            .any(|res| res.is_err())
+        {
+            panic!("unique within groups failed on a key repeated across groups")
+        }
+    }
+
+    #[test]
+    fn test_unique_within_groups_repeat_in_group() {
+        let results = [(0, 'a'), (1, 'a'), (0, 'b'), (1, 'a')]
+            .into_iter()
+            .map(Ok)
+            .unique_within_groups(|(g, _)| *g, |(_, k)| *k, repeated)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((0, 'a')),
+                Ok((1, 'a')),
+                Ok((0, 'b')),
+                Err(TestErr::Repeated(3, 1, 'a'))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_unique_within_groups_ignores_errors() {
+        let results = [Ok((0, 'a')), Err(TestErr::Corrupt), Ok((0, 'a'))]
+            .into_iter()
+            .unique_within_groups(|(g, _)| *g, |(_, k)| *k, repeated)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((0, 'a')),
+                Err(TestErr::Corrupt),
+                Err(TestErr::Repeated(2, 0, 'a'))
+            ]
+        )
+    }
+}
@@ -0,0 +1,228 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct ValidTransitionsIter<'a, I, T, E, S, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Eq + Hash + Clone,
+    M: Fn(&T) -> S,
+    Factory: Fn(usize, T, S, S) -> E,
+{
+    iter: IndexOffset<I>,
+    state: Option<S>,
+    allowed: &'a HashSet<(S, S)>,
+    state_of: M,
+    factory: Factory,
+}
+
+impl<'a, I, T, E, S, M, Factory> ValidTransitionsIter<'a, I, T, E, S, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Eq + Hash + Clone,
+    M: Fn(&T) -> S,
+    Factory: Fn(usize, T, S, S) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        state_of: M,
+        allowed: &'a HashSet<(S, S)>,
+        factory: Factory,
+    ) -> ValidTransitionsIter<'a, I, T, E, S, M, Factory> {
+        ValidTransitionsIter {
+            iter: IndexOffset::new(iter),
+            state: None,
+            allowed,
+            state_of,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, S, M, Factory> Iterator for ValidTransitionsIter<'_, I, T, E, S, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Eq + Hash + Clone,
+    M: Fn(&T) -> S,
+    Factory: Fn(usize, T, S, S) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let state = (self.state_of)(&val);
+                match self.state.take() {
+                    Some(prev) => {
+                        let transition = (prev, state);
+                        match self.allowed.contains(&transition) {
+                            true => {
+                                self.state = Some(transition.1);
+                                Some(Ok(val))
+                            }
+                            false => {
+                                let (prev, state) = transition;
+                                self.state = Some(prev.clone());
+                                Some(Err((self.factory)(i, val, prev, state)))
+                            }
+                        }
+                    }
+                    None => {
+                        self.state = Some(state);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, S, M, Factory> WithIndexOffset for ValidTransitionsIter<'_, I, T, E, S, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Eq + Hash + Clone,
+    M: Fn(&T) -> S,
+    Factory: Fn(usize, T, S, S) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait ValidTransitions<'a, T, E, S, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    S: Eq + Hash + Clone + 'a,
+    M: Fn(&T) -> S,
+    Factory: Fn(usize, T, S, S) -> E,
+{
+    /// Fails an iteration if its states change in a way that is not in a
+    /// set of allowed transitions.
+    ///
+    /// `valid_transitions(state_of, allowed, factory)` computes a state for
+    /// each element wrapped in `Ok`. The first valid element sets the initial
+    /// state. For every following element, if the pair
+    /// `(previous_state, state)` is in `allowed`, the element is wrapped in
+    /// `Ok(element)` and its state becomes the current state. Otherwise,
+    /// `factory` is called on the index of the error, the element, the
+    /// previous state and the illegal new state.
+    ///
+    /// Staying in the same state is a transition as well, so pairs such as
+    /// `(s, s)` must be in `allowed` if repeated states are legal.
+    ///
+    /// Elements that failed the validation do not change the current state,
+    /// and values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::HashSet;
+    /// use validiter::ValidTransitions;
+    ///
+    /// let allowed = HashSet::from([("open", "closed"), ("closed", "open")]);
+    /// let mut iter = ["open", "closed", "closed"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .valid_transitions(|s| *s, &allowed, |i, _, from, to| (i, from, to));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("open")));
+    /// assert_eq!(iter.next(), Some(Ok("closed")));
+    /// assert_eq!(iter.next(), Some(Err((2, "closed", "closed"))));
+    /// ```
+    fn valid_transitions(
+        self,
+        state_of: M,
+        allowed: &'a HashSet<(S, S)>,
+        factory: Factory,
+    ) -> ValidTransitionsIter<'a, Self, T, E, S, M, Factory> {
+        ValidTransitionsIter::new(self, state_of, allowed, factory)
+    }
+}
+
+impl<'a, I, T, E, S, M, Factory> ValidTransitions<'a, T, E, S, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    S: Eq + Hash + Clone + 'a,
+    M: Fn(&T) -> S,
+    Factory: Fn(usize, T, S, S) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::ValidTransitions;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Order {
+        Placed,
+        Paid,
+        Shipped,
+        Cancelled,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Illegal(usize, Order, Order),
+        Corrupt,
+    }
+
+    fn allowed() -> HashSet<(Order, Order)> {
+        HashSet::from([
+            (Order::Placed, Order::Paid),
+            (Order::Placed, Order::Cancelled),
+            (Order::Paid, Order::Shipped),
+        ])
+    }
+
+    fn illegal(index: usize, _: Order, from: Order, to: Order) -> TestErr {
+        TestErr::Illegal(index, from, to)
+    }
+
+    #[test]
+    fn test_valid_transitions_allowed_path() {
+        let allowed = allowed();
+        if [Order::Placed, Order::Paid, Order::Shipped]
+            .into_iter()
+            .map(Ok)
+            .valid_transitions(|s| *s, &allowed, illegal)
+            .any(|res| res.is_err())
+        {
+            panic!("valid transitions failed on an allowed path")
+        }
+    }
+
+    #[test]
+    fn test_valid_transitions_forbidden_transition() {
+        let allowed = allowed();
+        let results = [
+            Ok(Order::Placed),
+            Ok(Order::Shipped),
+            Err(TestErr::Corrupt),
+            Ok(Order::Paid),
+            Ok(Order::Cancelled),
+        ]
+        .into_iter()
+        .valid_transitions(|s| *s, &allowed, illegal)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(Order::Placed),
+                Err(TestErr::Illegal(1, Order::Placed, Order::Shipped)),
+                Err(TestErr::Corrupt),
+                Ok(Order::Paid),
+                Err(TestErr::Illegal(4, Order::Paid, Order::Cancelled))
+            ]
+        )
+    }
+}
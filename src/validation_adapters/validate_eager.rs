@@ -0,0 +1,187 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct ValidateEagerIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(&[T]) -> E,
+{
+    iter: Option<I>,
+    values: VecDeque<T>,
+    errors: VecDeque<(usize, E)>,
+    position: usize,
+    whole_test: F,
+    factory: Factory,
+}
+
+impl<I, T, E, F, Factory> ValidateEagerIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(&[T]) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        whole_test: F,
+        factory: Factory,
+    ) -> ValidateEagerIter<I, T, E, F, Factory> {
+        ValidateEagerIter {
+            iter: Some(iter),
+            values: VecDeque::new(),
+            errors: VecDeque::new(),
+            position: 0,
+            whole_test,
+            factory,
+        }
+    }
+
+    fn buffer(&mut self, iter: I) -> Option<E> {
+        let mut values = Vec::new();
+        for (i, item) in iter.enumerate() {
+            match item {
+                Ok(val) => values.push(val),
+                Err(err) => self.errors.push_back((i, err)),
+            }
+        }
+        let failure = match (self.whole_test)(&values) {
+            true => None,
+            false => Some((self.factory)(&values)),
+        };
+        self.values = values.into();
+        failure
+    }
+}
+
+impl<I, T, E, F, Factory> Iterator for ValidateEagerIter<I, T, E, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(&[T]) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            if let Some(err) = self.buffer(iter) {
+                return Some(Err(err));
+            }
+        }
+        let item = match self.errors.front() {
+            Some((i, _)) if *i == self.position => self.errors.pop_front().map(|(_, err)| Err(err)),
+            _ => self.values.pop_front().map(Ok),
+        };
+        self.position += 1;
+        item
+    }
+}
+
+pub trait ValidateEager<T, E, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(&[T]) -> E,
+{
+    /// Buffers the whole iteration, and fails it upfront if it does not
+    /// pass a validation over all of its values.
+    ///
+    /// `validate_eager(whole_test, factory)` consumes the entire underlying
+    /// iteration on the first call to `next`, and calls `whole_test` on a
+    /// slice of all the values wrapped in `Ok`. If it returns `false`, the
+    /// first element yielded is a new error, with the value returned from
+    /// calling `factory` on the same slice. Afterwards, all the buffered
+    /// elements are yielded unchanged, in their original order.
+    ///
+    /// Unlike validations reported at the end of the iteration, such as
+    /// [`at_least`](crate::AtLeast::at_least), the error can't be lost to
+    /// short-circuiting, since it comes before any element. This comes at the
+    /// cost of keeping the whole iteration in memory, so `validate_eager`
+    /// must not be used on infinite iterations.
+    ///
+    /// Values already wrapped in `Result::Err` are not passed to
+    /// `whole_test`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::ValidateEager;
+    ///
+    /// let mut iter = [3, 1, 2]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .validate_eager(|all| all.iter().sum::<i32>() == 10, |all| all.len());
+    ///
+    /// assert_eq!(iter.next(), Some(Err(3)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn validate_eager(
+        self,
+        whole_test: F,
+        factory: Factory,
+    ) -> ValidateEagerIter<Self, T, E, F, Factory> {
+        ValidateEagerIter::new(self, whole_test, factory)
+    }
+}
+
+impl<I, T, E, F, Factory> ValidateEager<T, E, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(&[T]) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidateEager;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        TooFew(usize),
+        Corrupt,
+    }
+
+    fn too_few(values: &[i32]) -> TestErr {
+        TestErr::TooFew(values.len())
+    }
+
+    #[test]
+    fn test_validate_eager_survives_short_circuit() {
+        let results = (0..3)
+            .map(Ok)
+            .validate_eager(|all| all.len() >= 5, too_few)
+            .take(1)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::TooFew(3))])
+    }
+
+    #[test]
+    fn test_validate_eager_keeps_order() {
+        let results = [Err(TestErr::Corrupt), Ok(1), Err(TestErr::Corrupt), Ok(2)]
+            .into_iter()
+            .validate_eager(|all| all.len() >= 5, too_few)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::TooFew(2)),
+                Err(TestErr::Corrupt),
+                Ok(1),
+                Err(TestErr::Corrupt),
+                Ok(2)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_validate_eager_passes() {
+        let results = (0..5)
+            .map(Ok)
+            .validate_eager(|all| all.len() >= 5, too_few)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(0), Ok(1), Ok(2), Ok(3), Ok(4)])
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct ValidateGroupsIter<I, T, E, B, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Clone,
+    B: Fn(&T) -> bool,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(usize, &[T]) -> E,
+{
+    iter: I,
+    group_index: usize,
+    group: Vec<T>,
+    pending: Vec<Result<T, E>>,
+    ready: VecDeque<Result<T, E>>,
+    exhausted: bool,
+    is_boundary: B,
+    validate_group: F,
+    factory: Factory,
+}
+
+impl<I, T, E, B, F, Factory> ValidateGroupsIter<I, T, E, B, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Clone,
+    B: Fn(&T) -> bool,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(usize, &[T]) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        is_boundary: B,
+        validate_group: F,
+        factory: Factory,
+    ) -> ValidateGroupsIter<I, T, E, B, F, Factory> {
+        ValidateGroupsIter {
+            iter,
+            group_index: 0,
+            group: Vec::new(),
+            pending: Vec::new(),
+            ready: VecDeque::new(),
+            exhausted: false,
+            is_boundary,
+            validate_group,
+            factory,
+        }
+    }
+
+    fn close_group(&mut self) {
+        if !(self.validate_group)(&self.group) {
+            self.ready
+                .push_back(Err((self.factory)(self.group_index, &self.group)));
+        }
+        self.ready.extend(self.pending.drain(..));
+        self.group.clear();
+        self.group_index += 1;
+    }
+}
+
+impl<I, T, E, B, F, Factory> Iterator for ValidateGroupsIter<I, T, E, B, F, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Clone,
+    B: Fn(&T) -> bool,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(usize, &[T]) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.ready.pop_front() {
+                return Some(item);
+            }
+            if self.exhausted {
+                return None;
+            }
+            match self.iter.next() {
+                Some(Ok(val)) => {
+                    if (self.is_boundary)(&val) && !self.group.is_empty() {
+                        self.close_group();
+                    }
+                    self.group.push(val.clone());
+                    self.pending.push(Ok(val));
+                }
+                Some(Err(e)) if self.group.is_empty() => return Some(Err(e)),
+                Some(Err(e)) => self.pending.push(Err(e)),
+                None => {
+                    self.exhausted = true;
+                    if !self.group.is_empty() {
+                        self.close_group();
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub trait ValidateGroups<T, E, B, F, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    T: Clone,
+    B: Fn(&T) -> bool,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(usize, &[T]) -> E,
+{
+    /// Fails each group of elements which does not pass a validation over
+    /// its full contents.
+    ///
+    /// `validate_groups(is_boundary, validate_group, factory)` splits the
+    /// iteration into groups, where every element for which `is_boundary`
+    /// returns `true` starts a new group. Once a group is complete, which is
+    /// known when the next boundary arrives or at the end of the iteration,
+    /// `validate_group` is called on a slice of its elements. If it returns
+    /// `false`, `factory` is called on the index of the group and the slice,
+    /// and the error is yielded right before the group's elements, so
+    /// short-circuiting consumers stop before processing an invalid group.
+    /// The elements themselves are always yielded unchanged.
+    ///
+    /// Since a group can only be validated once it is complete, its elements
+    /// are buffered (and cloned for the validation) until then.
+    ///
+    /// Values already wrapped in `Result::Err` are not part of any group,
+    /// but keep their position relative to the elements around them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::ValidateGroups;
+    ///
+    /// let lines = ["#a", "1", "2", "#b", "3"];
+    /// let mut iter = lines.into_iter().map(Ok).validate_groups(
+    ///     |line| line.starts_with('#'),
+    ///     |group| group.len() >= 3,
+    ///     |index, group| (index, group.len()),
+    /// );
+    ///
+    /// assert_eq!(iter.next(), Some(Ok("#a")));
+    /// assert_eq!(iter.next(), Some(Ok("1")));
+    /// assert_eq!(iter.next(), Some(Ok("2")));
+    /// assert_eq!(iter.next(), Some(Err((1, 2))));
+    /// assert_eq!(iter.next(), Some(Ok("#b")));
+    /// assert_eq!(iter.next(), Some(Ok("3")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn validate_groups(
+        self,
+        is_boundary: B,
+        validate_group: F,
+        factory: Factory,
+    ) -> ValidateGroupsIter<Self, T, E, B, F, Factory> {
+        ValidateGroupsIter::new(self, is_boundary, validate_group, factory)
+    }
+}
+
+impl<I, T, E, B, F, Factory> ValidateGroups<T, E, B, F, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: Clone,
+    B: Fn(&T) -> bool,
+    F: Fn(&[T]) -> bool,
+    Factory: Fn(usize, &[T]) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ValidateGroups;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadSum(usize, i32),
+        Corrupt,
+    }
+
+    fn bad_sum(index: usize, group: &[i32]) -> TestErr {
+        TestErr::BadSum(index, group.iter().sum())
+    }
+
+    #[test]
+    fn test_validate_groups_second_group_fails() {
+        let results = [0, 1, 2, 0, 5, 5]
+            .into_iter()
+            .map(Ok)
+            .validate_groups(|i| *i == 0, |g| g.iter().sum::<i32>() < 5, bad_sum)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0),
+                Ok(1),
+                Ok(2),
+                Err(TestErr::BadSum(1, 10)),
+                Ok(0),
+                Ok(5),
+                Ok(5)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_validate_groups_ok() {
+        if [0, 1, 0, 2, 0]
+            .into_iter()
+            .map(Ok)
+            .validate_groups(|i| *i == 0, |g| g.len() <= 2, bad_sum)
+            .any(|res| res.is_err())
+        {
+            panic!("validate groups failed on valid groups")
+        }
+    }
+
+    #[test]
+    fn test_validate_groups_keeps_errors_in_place() {
+        let results = [Err(TestErr::Corrupt), Ok(0), Err(TestErr::Corrupt), Ok(9)]
+            .into_iter()
+            .validate_groups(|i| *i == 0, |g| g.len() < 2, bad_sum)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Corrupt),
+                Err(TestErr::BadSum(0, 9)),
+                Ok(0),
+                Err(TestErr::Corrupt),
+                Ok(9)
+            ]
+        )
+    }
+}
@@ -0,0 +1,118 @@
+#[derive(Debug, Clone)]
+pub struct ValidationPeekableIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    iter: I,
+    peeked: Option<Option<Result<T, E>>>,
+}
+
+impl<I, T, E> ValidationPeekableIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I) -> ValidationPeekableIter<I, T, E> {
+        ValidationPeekableIter { iter, peeked: None }
+    }
+
+    /// Returns a reference to the next validation result, without
+    /// advancing the iteration.
+    ///
+    /// Like [`Peekable::peek`](std::iter::Peekable::peek), the underlying
+    /// iterator is advanced at most once, and the result is buffered until
+    /// it is consumed by `next`.
+    pub fn peek_result(&mut self) -> Option<&Result<T, E>> {
+        let iter = &mut self.iter;
+        self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+    }
+}
+
+impl<I, T, E> Iterator for ValidationPeekableIter<I, T, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(item) => item,
+            None => self.iter.next(),
+        }
+    }
+}
+
+pub trait ValidationPeekable<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Creates a validation iterator which can look at the next
+    /// validation result without consuming it.
+    ///
+    /// `validation_peekable()` is the validation counterpart of
+    /// [`Iterator::peekable`]. The returned iterator has a
+    /// [`peek_result`](ValidationPeekableIter::peek_result) method,
+    /// which returns a reference to the upcoming `Ok(element)` or `Err(error)`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, ValidationPeekable};
+    ///
+    /// let mut iter = (0..3)
+    ///     .map(Ok)
+    ///     .ensure(|i| *i != 1, |i, _| i)
+    ///     .validation_peekable();
+    ///
+    /// assert_eq!(iter.peek_result(), Some(&Ok(0)));
+    /// assert_eq!(iter.next(), Some(Ok(0)));
+    /// assert_eq!(iter.peek_result(), Some(&Err(1)));
+    /// assert_eq!(iter.peek_result(), Some(&Err(1)));
+    /// assert_eq!(iter.next(), Some(Err(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.peek_result(), None);
+    /// ```
+    fn validation_peekable(self) -> ValidationPeekableIter<Self, T, E> {
+        ValidationPeekableIter::new(self)
+    }
+}
+
+impl<I, T, E> ValidationPeekable<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ensure, ValidationPeekable};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_validation_peekable_peeked_error_is_the_consumed_error() {
+        let mut iter = (0..4)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .validation_peekable();
+        iter.next();
+        assert_eq!(iter.peek_result(), Some(&Err(TestErr::IsOdd(1, 1))));
+        assert_eq!(iter.next(), Some(Err(TestErr::IsOdd(1, 1))));
+        assert_eq!(iter.peek_result(), Some(&Ok(2)));
+    }
+
+    #[test]
+    fn test_validation_peekable_does_not_skip_elements() {
+        let mut iter = (0..5).map(Ok::<i32, TestErr>).validation_peekable();
+        let mut results = vec![];
+        while iter.peek_result().is_some() {
+            iter.peek_result();
+            results.push(iter.next().unwrap());
+        }
+        assert_eq!(results, vec![Ok(0), Ok(1), Ok(2), Ok(3), Ok(4)]);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_validation_peekable_on_empty_iteration() {
+        let mut iter = (0..0).map(Ok::<i32, TestErr>).validation_peekable();
+        assert_eq!(iter.peek_result(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,157 @@
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+pub struct WithDeadlineIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Factory: Fn(usize) -> E,
+{
+    iter: I,
+    deadline: Instant,
+    seen: usize,
+    timed_out: bool,
+    on_timeout: Factory,
+}
+
+impl<I, T, E, Factory> WithDeadlineIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Factory: Fn(usize) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        deadline: Instant,
+        on_timeout: Factory,
+    ) -> WithDeadlineIter<I, T, E, Factory> {
+        WithDeadlineIter {
+            iter,
+            deadline,
+            seen: 0,
+            timed_out: false,
+            on_timeout,
+        }
+    }
+}
+
+impl<I, T, E, Factory> Iterator for WithDeadlineIter<I, T, E, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    Factory: Fn(usize) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.timed_out {
+            return None;
+        }
+        if Instant::now() >= self.deadline {
+            self.timed_out = true;
+            return Some(Err((self.on_timeout)(self.seen)));
+        }
+        let item = self.iter.next()?;
+        self.seen += 1;
+        Some(item)
+    }
+}
+
+pub trait WithDeadline<T, E, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    Factory: Fn(usize) -> E,
+{
+    /// Stops a validation iterator which runs past a deadline.
+    ///
+    /// `with_deadline(deadline, on_timeout)` yields the elements of the
+    /// iteration unchanged, checking the time before pulling each of them.
+    /// Once `deadline` has passed, a final error is yielded with the value
+    /// returned from calling `on_timeout` on the number of elements seen so
+    /// far, and the iteration stops without pulling any more elements.
+    ///
+    /// The deadline is only checked between elements, so a single call to
+    /// the underlying iterator which blocks past the deadline will not be
+    /// interrupted - the timeout is reported right after it returns.
+    ///
+    /// Elements already wrapped in `Result::Err` are counted as seen.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use validiter::WithDeadline;
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(20);
+    /// let results = (0..)
+    ///     .map(|i| {
+    ///         std::thread::sleep(Duration::from_millis(5));
+    ///         Ok(i)
+    ///     })
+    ///     .with_deadline(deadline, |seen| seen)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(results.last(), Some(&Err(results.len() - 1)));
+    /// ```
+    fn with_deadline(
+        self,
+        deadline: Instant,
+        on_timeout: Factory,
+    ) -> WithDeadlineIter<Self, T, E, Factory> {
+        WithDeadlineIter::new(self, deadline, on_timeout)
+    }
+}
+
+impl<I, T, E, Factory> WithDeadline<T, E, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    Factory: Fn(usize) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::WithDeadline;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        TimedOut(usize),
+        Corrupt,
+    }
+
+    #[test]
+    fn test_with_deadline_stops_slow_source() {
+        let start = Instant::now();
+        let mut results = (0..1000)
+            .map(|i| {
+                thread::sleep(Duration::from_millis(2));
+                Ok(i)
+            })
+            .with_deadline(start + Duration::from_millis(30), TestErr::TimedOut)
+            .collect::<Vec<_>>();
+        assert!(start.elapsed() < Duration::from_millis(1000));
+        let seen = results.len() - 1;
+        assert_eq!(results.pop(), Some(Err(TestErr::TimedOut(seen))));
+        assert_eq!(results, (0..seen as i32).map(Ok).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_with_deadline_fast_source() {
+        let results = [Ok(1), Err(TestErr::Corrupt), Ok(2)]
+            .into_iter()
+            .with_deadline(Instant::now() + Duration::from_secs(60), TestErr::TimedOut)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok(1), Err(TestErr::Corrupt), Ok(2)])
+    }
+
+    #[test]
+    fn test_with_deadline_already_passed() {
+        let results = (0..3)
+            .map(Ok)
+            .with_deadline(Instant::now(), TestErr::TimedOut)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::TimedOut(0))])
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct WithinRollingMeanIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    iter: IndexOffset<I>,
+    window: usize,
+    tolerance: f64,
+    pos: usize,
+    value_store: Vec<f64>,
+    sum: f64,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> WithinRollingMeanIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        window: usize,
+        extractor: M,
+        tolerance: f64,
+        factory: Factory,
+    ) -> WithinRollingMeanIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            window,
+            tolerance,
+            pos: 0,
+            value_store: Vec::new(),
+            sum: 0.0,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for WithinRollingMeanIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the mean of an empty window is undefined
+        if self.window == 0 {
+            return self.iter.next().map(|(_, item)| item);
+        }
+
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                if self.pos >= self.window {
+                    let mean = self.sum / self.window as f64;
+                    match (extraction - mean).abs() <= self.tolerance {
+                        true => {
+                            let cycle_index = self.pos % self.window;
+                            self.sum += extraction - self.value_store[cycle_index];
+                            self.value_store[cycle_index] = extraction;
+                            self.pos += 1;
+                            Some(Ok(val))
+                        }
+                        false => Some(Err((self.factory)(i, val, extraction, mean))),
+                    }
+                } else {
+                    self.value_store.push(extraction);
+                    self.sum += extraction;
+                    self.pos += 1;
+                    Some(Ok(val))
+                }
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for WithinRollingMeanIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait WithinRollingMean<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    /// Fails an element if its value deviates from the mean of the last
+    /// `n` valid values by more than `tolerance`.
+    ///
+    /// `within_rolling_mean(n, extractor, tolerance, factory)` keeps the
+    /// values extracted from the last `n` valid elements, and their running
+    /// sum. The first `n` valid elements are wrapped in `Ok` and seed the
+    /// window. Each following element is wrapped in `Ok(element)` if
+    /// `|value - mean| <= tolerance`, and its value replaces the oldest value
+    /// in the window. Otherwise, `factory` is called on the index of the
+    /// error, the element, the extracted value, and the mean it failed against.
+    ///
+    /// Updates are O(1), using a running sum. A window of size 0 accepts all
+    /// elements. `NaN` values always fail once the window is full.
+    ///
+    /// Elements that failed the validation do not enter the window, and
+    /// values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::WithinRollingMean;
+    ///
+    /// let readings = [10.0, 12.0, 11.0, 30.0, 12.5];
+    /// let mut iter = readings
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .within_rolling_mean(2, |r| *r, 2.0, |i, _, r, mean| (i, r, mean));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(10.0)));
+    /// assert_eq!(iter.next(), Some(Ok(12.0)));
+    /// assert_eq!(iter.next(), Some(Ok(11.0))); // mean of [10, 12] is 11
+    /// assert_eq!(iter.next(), Some(Err((3, 30.0, 11.5)))); // mean of [12, 11]
+    /// assert_eq!(iter.next(), Some(Ok(12.5)));
+    /// ```
+    fn within_rolling_mean(
+        self,
+        n: usize,
+        extractor: M,
+        tolerance: f64,
+        factory: Factory,
+    ) -> WithinRollingMeanIter<Self, T, E, M, Factory> {
+        WithinRollingMeanIter::new(self, n, extractor, tolerance, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> WithinRollingMean<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WithinRollingMean;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Deviates(usize, f64, f64),
+        Other,
+    }
+
+    fn deviates(index: usize, _: f64, value: f64, mean: f64) -> TestErr {
+        TestErr::Deviates(index, value, mean)
+    }
+
+    #[test]
+    fn test_within_rolling_mean_spike_after_flat_run() {
+        let results = [5.0, 5.0, 5.0, 5.0, 50.0, 5.0]
+            .into_iter()
+            .map(Ok)
+            .within_rolling_mean(3, |f| *f, 1.0, deviates)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(5.0),
+                Ok(5.0),
+                Ok(5.0),
+                Ok(5.0),
+                Err(TestErr::Deviates(4, 50.0, 5.0)),
+                Ok(5.0)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_within_rolling_mean_follows_slow_trend() {
+        if (0..100)
+            .map(|i| Ok(i as f64))
+            .within_rolling_mean(4, |f| *f, 2.5, deviates)
+            .any(|res| res.is_err())
+        {
+            panic!("within rolling mean failed on a slow trend")
+        }
+    }
+
+    #[test]
+    fn test_within_rolling_mean_seeds_window() {
+        if [1.0, 100.0, -100.0]
+            .into_iter()
+            .map(Ok)
+            .within_rolling_mean(3, |f| *f, 0.0, deviates)
+            .any(|res| res.is_err())
+        {
+            panic!("within rolling mean failed while seeding the window")
+        }
+
+        if [1.0, 100.0]
+            .into_iter()
+            .map(Ok)
+            .within_rolling_mean(0, |f| *f, 0.0, deviates)
+            .any(|res| res.is_err())
+        {
+            panic!("within rolling mean failed with a window of 0")
+        }
+    }
+
+    #[test]
+    fn test_within_rolling_mean_ignores_errors() {
+        let results = [Ok(1.0), Err(TestErr::Other), Ok(1.0), Ok(f64::NAN)]
+            .into_iter()
+            .within_rolling_mean(1, |f| *f, 0.0, deviates)
+            .collect::<Vec<_>>();
+        assert_eq!(results[..3], [Ok(1.0), Err(TestErr::Other), Ok(1.0)]);
+        assert!(matches!(results[3], Err(TestErr::Deviates(3, _, _))));
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::VecDeque;
+
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct WithinRollingMedianIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    iter: IndexOffset<I>,
+    n: usize,
+    tolerance: f64,
+    window: VecDeque<f64>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> WithinRollingMedianIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        n: usize,
+        extractor: M,
+        tolerance: f64,
+        factory: Factory,
+    ) -> WithinRollingMedianIter<I, T, E, M, Factory> {
+        Self {
+            iter: IndexOffset::new(iter),
+            n,
+            tolerance,
+            window: VecDeque::new(),
+            extractor,
+            factory,
+        }
+    }
+
+    fn median(&self) -> f64 {
+        let mut sorted = self.window.iter().copied().collect::<Vec<_>>();
+        sorted.sort_by(f64::total_cmp);
+        let mid = sorted.len() / 2;
+        match sorted.len() % 2 {
+            0 => (sorted[mid - 1] + sorted[mid]) / 2.0,
+            _ => sorted[mid],
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for WithinRollingMedianIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // an empty window has no median
+        if self.n == 0 {
+            return self.iter.next().map(|(_, item)| item);
+        }
+
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                if self.window.len() == self.n {
+                    let median = self.median();
+                    let within = (extraction - median).abs() <= self.tolerance;
+                    if !within {
+                        return Some(Err((self.factory)(i, val, extraction, median)));
+                    }
+                    self.window.pop_front();
+                }
+                self.window.push_back(extraction);
+                Some(Ok(val))
+            }
+            Some((_, err)) => Some(err),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for WithinRollingMedianIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait WithinRollingMedian<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    /// Fails an element if the value computed by `extractor` deviates from
+    /// the median of the last `n` values by more than `tolerance`.
+    ///
+    /// `within_rolling_median(n, extractor, tolerance, factory)` keeps a
+    /// window of the values extracted from the last `n` valid elements. The
+    /// first `n` valid elements fill the window, and are always wrapped in
+    /// `Ok`. After that, each element is wrapped in `Ok(element)` if its
+    /// extracted value is within `tolerance` of the median of the window, and
+    /// otherwise `factory` is called on the index of the error, the element,
+    /// the extracted value, and the median.
+    ///
+    /// Unlike the mean, the median is not dragged by a single outlier, which
+    /// makes it suitable for detecting spikes in noisy data. The median is
+    /// recomputed for every element, so `n` is expected to be small. An `n`
+    /// of 0 accepts all elements.
+    ///
+    /// Elements that failed the validation do not enter the window, and
+    /// values already wrapped in `Result::Err` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::WithinRollingMedian;
+    ///
+    /// let latencies = [10.0, 12.0, 11.0, 95.0, 13.0];
+    /// let mut iter = latencies
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .within_rolling_median(3, |l| *l, 5.0, |i, _, l, median| (i, l, median));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(10.0)));
+    /// assert_eq!(iter.next(), Some(Ok(12.0)));
+    /// assert_eq!(iter.next(), Some(Ok(11.0)));
+    /// assert_eq!(iter.next(), Some(Err((3, 95.0, 11.0))));
+    /// assert_eq!(iter.next(), Some(Ok(13.0)));
+    /// ```
+    fn within_rolling_median(
+        self,
+        n: usize,
+        extractor: M,
+        tolerance: f64,
+        factory: Factory,
+    ) -> WithinRollingMedianIter<Self, T, E, M, Factory> {
+        WithinRollingMedianIter::new(self, n, extractor, tolerance, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> WithinRollingMedian<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WithinRollingMedian;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Spike(usize, f64, f64),
+        Missing,
+    }
+
+    fn spike(index: usize, _: f64, value: f64, median: f64) -> TestErr {
+        TestErr::Spike(index, value, median)
+    }
+
+    #[test]
+    fn test_within_rolling_median_single_spike() {
+        let results = [5.0, 5.0, 5.0, 5.0, 50.0, 5.0, 5.0]
+            .into_iter()
+            .map(Ok)
+            .within_rolling_median(4, |v| *v, 1.0, spike)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(5.0),
+                Ok(5.0),
+                Ok(5.0),
+                Ok(5.0),
+                Err(TestErr::Spike(4, 50.0, 5.0)),
+                Ok(5.0),
+                Ok(5.0)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_within_rolling_median_follows_trend() {
+        if (0..20)
+            .map(|i| Ok(i as f64))
+            .within_rolling_median(3, |v| *v, 2.0, spike)
+            .any(|res| res.is_err())
+        {
+            panic!("within rolling median failed on a slow trend")
+        }
+    }
+
+    #[test]
+    fn test_within_rolling_median_even_window_and_errors() {
+        let results = [Ok(1.0), Err(TestErr::Missing), Ok(3.0), Ok(2.5), Ok(0.0)]
+            .into_iter()
+            .within_rolling_median(2, |v| *v, 0.5, spike)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(1.0),
+                Err(TestErr::Missing),
+                Ok(3.0),
+                Ok(2.5),
+                Err(TestErr::Spike(4, 0.0, 2.75))
+            ]
+        )
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{validation_adapters::index_offset::IndexOffset, WithIndexOffset};
+
+#[derive(Debug, Clone)]
+pub struct WithinToleranceOfFirstIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    iter: IndexOffset<I>,
+    tolerance: f64,
+    anchor: Option<f64>,
+    extractor: M,
+    factory: Factory,
+}
+
+impl<I, T, E, M, Factory> WithinToleranceOfFirstIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    pub(crate) fn new(
+        iter: I,
+        tolerance: f64,
+        extractor: M,
+        factory: Factory,
+    ) -> WithinToleranceOfFirstIter<I, T, E, M, Factory> {
+        WithinToleranceOfFirstIter {
+            iter: IndexOffset::new(iter),
+            tolerance,
+            anchor: None,
+            extractor,
+            factory,
+        }
+    }
+}
+
+impl<I, T, E, M, Factory> Iterator for WithinToleranceOfFirstIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((i, Ok(val))) => {
+                let extraction = (self.extractor)(&val);
+                match self.anchor {
+                    Some(anchor) if (extraction - anchor).abs() > self.tolerance => {
+                        Some(Err((self.factory)(i, val, extraction, anchor)))
+                    }
+                    Some(_) => Some(Ok(val)),
+                    None => {
+                        self.anchor = Some(extraction);
+                        Some(Ok(val))
+                    }
+                }
+            }
+            Some((_, Err(e))) => Some(Err(e)),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, M, Factory> WithIndexOffset for WithinToleranceOfFirstIter<I, T, E, M, Factory>
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.iter.set_offset(offset);
+        self
+    }
+}
+
+pub trait WithinToleranceOfFirst<T, E, M, Factory>: Iterator<Item = Result<T, E>> + Sized
+where
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+    /// Fails an iteration if the values computed by `extractor` drift away
+    /// from the value of the first element by more than `tolerance`.
+    ///
+    /// `within_tolerance_of_first(tolerance, extractor, factory)` uses the
+    /// value extracted from the first valid element as an anchor. Each
+    /// following element is wrapped in `Ok(element)` if its extracted value
+    /// is within `tolerance` of the anchor, and otherwise `factory` is called
+    /// on the index of the error, the element, the extracted value, and the
+    /// anchor. The anchor never changes, so slow cumulative drift is caught
+    /// even if every single step is small.
+    ///
+    /// Values already wrapped in `Result::Err` are ignored, and cannot
+    /// become the anchor.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::WithinToleranceOfFirst;
+    ///
+    /// let readings = [20.0, 20.5, 19.2, 21.5];
+    /// let mut iter = readings
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .within_tolerance_of_first(1.0, |r| *r, |i, _, r, anchor| (i, r, anchor));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(20.0)));
+    /// assert_eq!(iter.next(), Some(Ok(20.5)));
+    /// assert_eq!(iter.next(), Some(Ok(19.2)));
+    /// assert_eq!(iter.next(), Some(Err((3, 21.5, 20.0))));
+    /// ```
+    fn within_tolerance_of_first(
+        self,
+        tolerance: f64,
+        extractor: M,
+        factory: Factory,
+    ) -> WithinToleranceOfFirstIter<Self, T, E, M, Factory> {
+        WithinToleranceOfFirstIter::new(self, tolerance, extractor, factory)
+    }
+}
+
+impl<I, T, E, M, Factory> WithinToleranceOfFirst<T, E, M, Factory> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    M: Fn(&T) -> f64,
+    Factory: Fn(usize, T, f64, f64) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WithinToleranceOfFirst;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Drifted(usize, f64, f64),
+        Missing,
+    }
+
+    fn drifted(index: usize, _: f64, value: f64, anchor: f64) -> TestErr {
+        TestErr::Drifted(index, value, anchor)
+    }
+
+    #[test]
+    fn test_within_tolerance_of_first_slow_drift() {
+        let results = [10.0, 10.5, 11.0, 11.5, 12.0, 12.5]
+            .into_iter()
+            .map(Ok)
+            .within_tolerance_of_first(2.0, |r| *r, drifted)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(10.0),
+                Ok(10.5),
+                Ok(11.0),
+                Ok(11.5),
+                Ok(12.0),
+                Err(TestErr::Drifted(5, 12.5, 10.0))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_within_tolerance_of_first_anchor_never_updates() {
+        let results = [0.0, 5.0, 0.5, -0.5]
+            .into_iter()
+            .map(Ok)
+            .within_tolerance_of_first(1.0, |r| *r, drifted)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok(0.0),
+                Err(TestErr::Drifted(1, 5.0, 0.0)),
+                Ok(0.5),
+                Ok(-0.5)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_within_tolerance_of_first_ignores_errors() {
+        let results = [Err(TestErr::Missing), Ok(3.0), Ok(3.0)]
+            .into_iter()
+            .within_tolerance_of_first(0.0, |r| *r, drifted)
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![Err(TestErr::Missing), Ok(3.0), Ok(3.0)])
+    }
+}
@@ -0,0 +1,106 @@
+pub trait AssertSumEquals<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects a validation iterator, and fails if the sum of its values
+    /// is not exactly `target`.
+    ///
+    /// `assert_sum_equals(target, extractor, factory)` collects all the
+    /// elements, while summing the values computed by `extractor`. If the
+    /// sum equals `target`, the elements are returned in a `Vec`. Otherwise,
+    /// `factory` is called on `Some(sum)` and `target`.
+    ///
+    /// The sum is accumulated in an `i64` with checked addition. If it
+    /// overflows at any point, the iteration is still collected, and
+    /// `factory` is called on `None` and `target`.
+    ///
+    /// The collection short-circuits on the first `Err(error)` in the
+    /// iteration, returning that error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::AssertSumEquals;
+    ///
+    /// let entries = [("debit", 50), ("debit", 25), ("credit", -75)];
+    /// let balanced = entries
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .assert_sum_equals(0, |(_, amount)| *amount, |sum, _| sum);
+    ///
+    /// assert_eq!(balanced.map(|entries| entries.len()), Ok(3));
+    /// ```
+    fn assert_sum_equals<M, Factory>(
+        self,
+        target: i64,
+        extractor: M,
+        factory: Factory,
+    ) -> Result<Vec<T>, E>
+    where
+        M: Fn(&T) -> i64,
+        Factory: FnOnce(Option<i64>, i64) -> E,
+    {
+        let mut sum = Some(0i64);
+        let mut elements = Vec::new();
+        for item in self {
+            let val = item?;
+            sum = sum.and_then(|sum| sum.checked_add(extractor(&val)));
+            elements.push(val);
+        }
+        match sum == Some(target) {
+            true => Ok(elements),
+            false => Err(factory(sum, target)),
+        }
+    }
+}
+
+impl<I, T, E> AssertSumEquals<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AssertSumEquals, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Unbalanced(Option<i64>, i64),
+        IsZero(usize, i64),
+    }
+
+    #[test]
+    fn test_assert_sum_equals_balanced() {
+        let result =
+            [10, -4, -6, 3]
+                .into_iter()
+                .map(Ok)
+                .assert_sum_equals(3, |v| *v, TestErr::Unbalanced);
+        assert_eq!(result, Ok(vec![10, -4, -6, 3]))
+    }
+
+    #[test]
+    fn test_assert_sum_equals_unbalanced() {
+        let result =
+            [10, -4, -5]
+                .into_iter()
+                .map(Ok)
+                .assert_sum_equals(0, |v| *v, TestErr::Unbalanced);
+        assert_eq!(result, Err(TestErr::Unbalanced(Some(1), 0)))
+    }
+
+    #[test]
+    fn test_assert_sum_equals_overflow() {
+        let result = [i64::MAX, 1, -1].into_iter().map(Ok).assert_sum_equals(
+            i64::MAX,
+            |v| *v,
+            TestErr::Unbalanced,
+        );
+        assert_eq!(result, Err(TestErr::Unbalanced(None, i64::MAX)))
+    }
+
+    #[test]
+    fn test_assert_sum_equals_short_circuits() {
+        let result = [1, 0, 2]
+            .into_iter()
+            .map(Ok)
+            .ensure(|v| *v != 0, TestErr::IsZero)
+            .assert_sum_equals(3, |v| *v, TestErr::Unbalanced);
+        assert_eq!(result, Err(TestErr::IsZero(1, 0)))
+    }
+}
@@ -0,0 +1,98 @@
+pub trait AssertSumWithin<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects a validation iterator, and fails if the sum of its values
+    /// is not within `tolerance` of `target`.
+    ///
+    /// `assert_sum_within(target, tolerance, extractor, factory)` collects
+    /// all the elements, while summing the values computed by `extractor`.
+    /// If `|sum - target| <= tolerance`, the elements are returned in a
+    /// `Vec`. Otherwise, `factory` is called on the sum and `target`.
+    ///
+    /// Since floating point sums are rarely exact, this is the counterpart of
+    /// [`assert_sum_equals`](crate::AssertSumEquals::assert_sum_equals) for
+    /// `f64` values. A `NaN` sum is never within the tolerance.
+    ///
+    /// The collection short-circuits on the first `Err(error)` in the
+    /// iteration, returning that error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::AssertSumWithin;
+    ///
+    /// let shares = [33.3, 33.3, 33.3];
+    /// let total = shares
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .assert_sum_within(100.0, 0.5, |share| *share, |sum, _| sum);
+    ///
+    /// assert_eq!(total, Ok(vec![33.3, 33.3, 33.3]));
+    /// ```
+    fn assert_sum_within<M, Factory>(
+        self,
+        target: f64,
+        tolerance: f64,
+        extractor: M,
+        factory: Factory,
+    ) -> Result<Vec<T>, E>
+    where
+        M: Fn(&T) -> f64,
+        Factory: FnOnce(f64, f64) -> E,
+    {
+        let mut sum = 0.0;
+        let mut elements = Vec::new();
+        for item in self {
+            let val = item?;
+            sum += extractor(&val);
+            elements.push(val);
+        }
+        match (sum - target).abs() <= tolerance {
+            true => Ok(elements),
+            false => Err(factory(sum, target)),
+        }
+    }
+}
+
+impl<I, T, E> AssertSumWithin<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AssertSumWithin, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadSum(f64, f64),
+        IsNegative(usize, f64),
+    }
+
+    #[test]
+    fn test_assert_sum_within_tolerance() {
+        let total =
+            [50.0, 49.9]
+                .into_iter()
+                .map(Ok)
+                .assert_sum_within(100.0, 1.0, |v| *v, TestErr::BadSum);
+        assert_eq!(total, Ok(vec![50.0, 49.9]))
+    }
+
+    #[test]
+    fn test_assert_sum_within_too_far() {
+        let total = [100.0, 50.0].into_iter().map(Ok).assert_sum_within(
+            100.0,
+            1.0,
+            |v| *v,
+            TestErr::BadSum,
+        );
+        assert_eq!(total, Err(TestErr::BadSum(150.0, 100.0)))
+    }
+
+    #[test]
+    fn test_assert_sum_within_short_circuits() {
+        let total = [50.0, -1.0, 51.0]
+            .into_iter()
+            .map(Ok)
+            .ensure(|v| *v >= 0.0, TestErr::IsNegative)
+            .assert_sum_within(100.0, 1.0, |v| *v, TestErr::BadSum);
+        assert_eq!(total, Err(TestErr::IsNegative(1, -1.0)))
+    }
+}
@@ -0,0 +1,136 @@
+/// The error returned from
+/// [`assert_within_std_dev`](crate::AssertWithinStdDev::assert_within_std_dev).
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatErr<T, E> {
+    /// The first error found in the iteration.
+    Invalid(E),
+    /// The elements lying too far from the mean, with their indices.
+    Outliers {
+        mean: f64,
+        std_dev: f64,
+        outliers: Vec<(usize, T)>,
+    },
+}
+
+pub trait AssertWithinStdDev<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects a validation iterator, and fails if some of its values
+    /// are more than `k` standard deviations away from their mean.
+    ///
+    /// `assert_within_std_dev(k, extractor)` collects all the elements, and
+    /// computes the mean and the (population) standard deviation of the
+    /// values computed by `extractor`. If all values are within
+    /// `k * std_dev` of the mean, the elements are returned in a `Vec`.
+    /// Otherwise, [`StatErr::Outliers`] is returned, holding the mean, the
+    /// standard deviation, and every element outside of that range together
+    /// with its index.
+    ///
+    /// This is a whole-iteration check, which buffers all the elements
+    /// before validating any of them. The collection short-circuits on the
+    /// first `Err(error)` in the iteration, returning
+    /// [`StatErr::Invalid(error)`](StatErr::Invalid).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{AssertWithinStdDev, StatErr};
+    ///
+    /// let readings = [10.0, 11.0, 9.0, 10.0, 10.0, 10.0, 50.0];
+    /// let result = readings
+    ///     .into_iter()
+    ///     .map(Ok::<_, ()>)
+    ///     .assert_within_std_dev(2.0, |r| *r);
+    ///
+    /// match result {
+    ///     Err(StatErr::Outliers { outliers, .. }) => assert_eq!(outliers, vec![(6, 50.0)]),
+    ///     _ => panic!("expected an outlier"),
+    /// }
+    /// ```
+    fn assert_within_std_dev<M>(self, k: f64, extractor: M) -> Result<Vec<T>, StatErr<T, E>>
+    where
+        M: Fn(&T) -> f64,
+    {
+        let elements = self
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(StatErr::Invalid)?;
+        let values = elements.iter().map(&extractor).collect::<Vec<_>>();
+        let len = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / len;
+        let std_dev = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / len).sqrt();
+        let is_outlier = |v: f64| (v - mean).abs() > k * std_dev;
+        if !values.iter().any(|v| is_outlier(*v)) {
+            return Ok(elements);
+        }
+        let outliers = elements
+            .into_iter()
+            .zip(values)
+            .enumerate()
+            .filter(|(_, (_, v))| is_outlier(*v))
+            .map(|(i, (val, _))| (i, val))
+            .collect();
+        Err(StatErr::Outliers {
+            mean,
+            std_dev,
+            outliers,
+        })
+    }
+}
+
+impl<I, T, E> AssertWithinStdDev<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AssertWithinStdDev, Ensure, StatErr};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsNegative(usize, i32),
+    }
+
+    #[test]
+    fn test_assert_within_std_dev_ok() {
+        let result = [4, 5, 6, 5, 4, 6]
+            .into_iter()
+            .map(Ok::<_, TestErr>)
+            .assert_within_std_dev(1.5, |v| *v as f64);
+        assert_eq!(result, Ok(vec![4, 5, 6, 5, 4, 6]))
+    }
+
+    #[test]
+    fn test_assert_within_std_dev_reports_outlier() {
+        let result = [0, 0, 0, 0, 0, 0, 0, 0, 90]
+            .into_iter()
+            .map(Ok::<_, TestErr>)
+            .assert_within_std_dev(2.0, |v| *v as f64);
+        match result {
+            Err(StatErr::Outliers {
+                mean,
+                std_dev,
+                outliers,
+            }) => {
+                assert_eq!(mean, 10.0);
+                assert_eq!(std_dev, 800f64.sqrt());
+                assert_eq!(outliers, vec![(8, 90)]);
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_assert_within_std_dev_short_circuits() {
+        let result = [1, -1, 100]
+            .into_iter()
+            .map(Ok)
+            .ensure(|v| *v >= 0, TestErr::IsNegative)
+            .assert_within_std_dev(0.0, |v| *v as f64);
+        assert_eq!(result, Err(StatErr::Invalid(TestErr::IsNegative(1, -1))))
+    }
+
+    #[test]
+    fn test_assert_within_std_dev_empty() {
+        let result = (0..0)
+            .map(Ok::<i32, TestErr>)
+            .assert_within_std_dev(1.0, |v| *v as f64);
+        assert_eq!(result, Ok(vec![]))
+    }
+}
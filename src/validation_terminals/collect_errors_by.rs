@@ -0,0 +1,97 @@
+use std::{collections::HashMap, hash::Hash};
+
+pub trait CollectErrorsBy<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Consumes a validation iterator, grouping its errors by a key.
+    ///
+    /// `collect_errors_by(discriminant)` computes a key for each error
+    /// wrapped in `Err`, and pushes the error into the group of that key.
+    /// Groups keep the order of the iteration. Elements wrapped in `Ok` are
+    /// discarded.
+    ///
+    /// This is useful for summarizing failures by category, for example by
+    /// the variant of an error enum.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::HashMap;
+    /// use validiter::{AtMost, CollectErrorsBy, Ensure};
+    ///
+    /// let errors = (0..6)
+    ///     .map(Ok)
+    ///     .ensure(|i| i % 2 == 0, |i, _| ("odd", i))
+    ///     .at_most(2, |i, _| ("too many", i))
+    ///     .collect_errors_by(|(kind, _)| *kind);
+    ///
+    /// assert_eq!(
+    ///     errors,
+    ///     HashMap::from([
+    ///         ("odd", vec![("odd", 1), ("odd", 3), ("odd", 5)]),
+    ///         ("too many", vec![("too many", 4)]),
+    ///     ])
+    /// );
+    /// ```
+    fn collect_errors_by<K, M>(self, discriminant: M) -> HashMap<K, Vec<E>>
+    where
+        K: Eq + Hash,
+        M: Fn(&E) -> K,
+    {
+        let mut groups: HashMap<K, Vec<E>> = HashMap::new();
+        for err in self.filter_map(Result::err) {
+            groups.entry(discriminant(&err)).or_default().push(err);
+        }
+        groups
+    }
+}
+
+impl<I, T, E> CollectErrorsBy<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, mem::discriminant};
+
+    use crate::{CollectErrorsBy, Ensure, UniqueBy};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        OutOfRange(usize, i32),
+        Duplicate(usize, i32, i32),
+    }
+
+    #[test]
+    fn test_collect_errors_by_variant() {
+        let errors = [1, 50, 2, 1, 70, 2, 80, 1]
+            .into_iter()
+            .map(Ok)
+            .ensure(|i| *i < 10, TestErr::OutOfRange)
+            .unique_by(|i| *i, TestErr::Duplicate)
+            .collect_errors_by(discriminant);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[&discriminant(&TestErr::OutOfRange(0, 0))],
+            vec![
+                TestErr::OutOfRange(1, 50),
+                TestErr::OutOfRange(4, 70),
+                TestErr::OutOfRange(6, 80)
+            ]
+        );
+        assert_eq!(
+            errors[&discriminant(&TestErr::Duplicate(0, 0, 0))],
+            vec![
+                TestErr::Duplicate(3, 1, 1),
+                TestErr::Duplicate(5, 2, 2),
+                TestErr::Duplicate(7, 1, 1)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_collect_errors_by_no_errors() {
+        let errors = (0..5)
+            .map(Ok)
+            .ensure(|i| *i < 10, TestErr::OutOfRange)
+            .collect_errors_by(|_| ());
+        assert_eq!(errors, HashMap::new())
+    }
+}
@@ -0,0 +1,88 @@
+pub trait CollectUntilError<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects the valid prefix of a validation iterator, and returns
+    /// it together with the error that ended it and the rest of the
+    /// iteration.
+    ///
+    /// `collect_until_error()` consumes elements until the first
+    /// `Err(error)` is found, and returns the elements wrapped in `Ok`
+    /// before it, `Some(error)`, and the iterator itself, positioned right
+    /// after the error. If no error is found, the whole iteration is
+    /// collected, `None` is returned for the error, and the returned
+    /// iterator is exhausted.
+    ///
+    /// This is the same as [`split_at_first_error`](crate::SplitAtFirstError::split_at_first_error),
+    /// but the remaining elements are handed back instead of being left in
+    /// a borrowed iterator, which makes it easy to resume the processing
+    /// later without losing the elements after the error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{CollectUntilError, Ensure};
+    ///
+    /// let (prefix, err, rest) = (0..6)
+    ///     .map(Ok)
+    ///     .ensure(|i| *i != 2, |index, _| index)
+    ///     .collect_until_error();
+    ///
+    /// assert_eq!(prefix, vec![0, 1]);
+    /// assert_eq!(err, Some(2));
+    ///
+    /// let (prefix, err, _) = rest.collect_until_error();
+    /// assert_eq!(prefix, vec![3, 4, 5]);
+    /// assert_eq!(err, None);
+    /// ```
+    fn collect_until_error(mut self) -> (Vec<T>, Option<E>, Self) {
+        let mut prefix = Vec::new();
+        while let Some(item) = self.next() {
+            match item {
+                Ok(val) => prefix.push(val),
+                Err(err) => return (prefix, Some(err), self),
+            }
+        }
+        (prefix, None, self)
+    }
+}
+
+impl<I, T, E> CollectUntilError<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CollectUntilError, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsMultipleOf3(usize, i32),
+    }
+
+    #[test]
+    fn test_collect_until_error_resumes() {
+        let (prefix, err, rest) = (1..10)
+            .map(Ok)
+            .ensure(|i| i % 3 != 0, TestErr::IsMultipleOf3)
+            .collect_until_error();
+        assert_eq!(prefix, vec![1, 2]);
+        assert_eq!(err, Some(TestErr::IsMultipleOf3(2, 3)));
+
+        let (prefix, err, rest) = rest.collect_until_error();
+        assert_eq!(prefix, vec![4, 5]);
+        assert_eq!(err, Some(TestErr::IsMultipleOf3(5, 6)));
+
+        assert_eq!(
+            rest.collect::<Vec<_>>(),
+            vec![Ok(7), Ok(8), Err(TestErr::IsMultipleOf3(8, 9))]
+        )
+    }
+
+    #[test]
+    fn test_collect_until_error_all_valid() {
+        let (prefix, err, mut rest) = (1..3)
+            .map(Ok)
+            .ensure(|i| i % 3 != 0, TestErr::IsMultipleOf3)
+            .collect_until_error();
+        assert_eq!(prefix, vec![1, 2]);
+        assert_eq!(err, None);
+        assert_eq!(rest.next(), None)
+    }
+}
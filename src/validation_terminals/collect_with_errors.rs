@@ -0,0 +1,80 @@
+pub trait CollectWithErrors<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Consumes a validation iterator, collecting the valid elements and
+    /// the errors together with their positions.
+    ///
+    /// `collect_with_errors()` drains the whole iteration without
+    /// short-circuiting. Elements wrapped in `Ok` are collected into the
+    /// first `Vec`, and every `Err(error)` is collected into the second
+    /// `Vec` as `(index, error)`, where `index` is the position of the error
+    /// in this iteration (after all the validations applied before it).
+    ///
+    /// This is useful for reports that need both the valid elements and a
+    /// list of the failures.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{CollectWithErrors, Ensure};
+    ///
+    /// let (valid, errors) = ["1", "x", "3", "y"]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure(|s| s.parse::<i32>().is_ok(), |_, s| s)
+    ///     .collect_with_errors();
+    ///
+    /// assert_eq!(valid, vec!["1", "3"]);
+    /// assert_eq!(errors, vec![(1, "x"), (3, "y")]);
+    /// ```
+    fn collect_with_errors(self) -> (Vec<T>, Vec<(usize, E)>) {
+        let mut valid = Vec::new();
+        let mut errors = Vec::new();
+        for (i, item) in self.enumerate() {
+            match item {
+                Ok(val) => valid.push(val),
+                Err(err) => errors.push((i, err)),
+            }
+        }
+        (valid, errors)
+    }
+}
+
+impl<I, T, E> CollectWithErrors<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtLeast, CollectWithErrors, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        TooFew(usize),
+    }
+
+    #[test]
+    fn test_collect_with_errors_positions_match_ensure() {
+        let (valid, errors) = (0..7)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .collect_with_errors();
+        assert_eq!(valid, vec![0, 2, 4, 6]);
+        assert_eq!(
+            errors,
+            vec![
+                (1, TestErr::IsOdd(1, 1)),
+                (3, TestErr::IsOdd(3, 3)),
+                (5, TestErr::IsOdd(5, 5))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_collect_with_errors_trailing_error() {
+        let (valid, errors) = (0..2)
+            .map(Ok)
+            .at_least(3, TestErr::TooFew)
+            .collect_with_errors();
+        assert_eq!(valid, vec![0, 1]);
+        assert_eq!(errors, vec![(2, TestErr::TooFew(2))])
+    }
+}
@@ -0,0 +1,77 @@
+/// The number of valid and invalid elements in an iteration,
+/// see [`counts`](crate::ValidationCounts::counts).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub ok: usize,
+    pub err: usize,
+    pub total: usize,
+}
+
+pub trait ValidationCounts<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Consumes a validation iterator, counting the elements wrapped in
+    /// `Ok` and in `Err`.
+    ///
+    /// `counts()` returns a [`Counts`] holding both counts and their sum,
+    /// which saves iterating twice when both numbers are needed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Counts, Ensure, ValidationCounts};
+    ///
+    /// let counts = (0..5)
+    ///     .map(Ok)
+    ///     .ensure(|i| i % 2 == 0, |_, _| ())
+    ///     .counts();
+    ///
+    /// assert_eq!(counts, Counts { ok: 3, err: 2, total: 5 });
+    /// ```
+    fn counts(self) -> Counts {
+        self.fold(Counts::default(), |mut counts, item| {
+            match item {
+                Ok(_) => counts.ok += 1,
+                Err(_) => counts.err += 1,
+            }
+            counts.total += 1;
+            counts
+        })
+    }
+}
+
+impl<I, T, E> ValidationCounts<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtMost, Counts, Ensure, ValidationCounts};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        TooMany(usize, i32),
+    }
+
+    #[test]
+    fn test_counts_mixed_iteration() {
+        let counts = (0..10)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .at_most(3, TestErr::TooMany)
+            .counts();
+        assert_eq!(
+            counts,
+            Counts {
+                ok: 3,
+                err: 7,
+                total: 10
+            }
+        );
+        assert_eq!(counts.ok + counts.err, counts.total);
+    }
+
+    #[test]
+    fn test_counts_empty_iteration() {
+        let counts = (0..0).map(Ok::<i32, TestErr>).counts();
+        assert_eq!(counts, Counts::default())
+    }
+}
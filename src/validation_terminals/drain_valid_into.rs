@@ -0,0 +1,84 @@
+pub trait DrainValidInto<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Extends a collection with the elements of a validation iterator,
+    /// stopping at the first error.
+    ///
+    /// `drain_valid_into(sink)` pushes every element wrapped in `Ok` into
+    /// `sink`, which can be any collection implementing [`Extend`]. When an
+    /// `Err(error)` is found, the iteration stops and the error is returned.
+    /// Elements consumed before the error remain in `sink`.
+    ///
+    /// This avoids allocating an intermediate collection when the valid
+    /// elements should be appended to an existing one.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{AtMost, DrainValidInto};
+    ///
+    /// let mut sink = vec![-1];
+    /// let result = (0..5)
+    ///     .map(Ok)
+    ///     .at_most(3, |index, _| index)
+    ///     .drain_valid_into(&mut sink);
+    ///
+    /// assert_eq!(result, Err(3));
+    /// assert_eq!(sink, vec![-1, 0, 1, 2]);
+    /// ```
+    fn drain_valid_into<C>(self, sink: &mut C) -> Result<(), E>
+    where
+        C: Extend<T>,
+    {
+        for item in self {
+            sink.extend(Some(item?));
+        }
+        Ok(())
+    }
+}
+
+impl<I, T, E> DrainValidInto<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{DrainValidInto, Ensure};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsFive(usize, i32),
+    }
+
+    #[test]
+    fn test_drain_valid_into_pre_populated_vec() {
+        let mut sink = vec![10, 11];
+        let result = (0..3)
+            .map(Ok)
+            .ensure(|i| *i != 5, TestErr::IsFive)
+            .drain_valid_into(&mut sink);
+        assert_eq!(result, Ok(()));
+        assert_eq!(sink, vec![10, 11, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_drain_valid_into_stops_at_error() {
+        let mut sink = vec![];
+        let mut source = (0..10).map(Ok).ensure(|i| *i != 5, TestErr::IsFive);
+        let result = source.by_ref().drain_valid_into(&mut sink);
+        assert_eq!(result, Err(TestErr::IsFive(5, 5)));
+        assert_eq!(sink, vec![0, 1, 2, 3, 4]);
+        assert_eq!(source.next(), Some(Ok(6)));
+    }
+
+    #[test]
+    fn test_drain_valid_into_hash_set() {
+        let mut sink = HashSet::from([0]);
+        let result = [0, 1, 1, 2]
+            .into_iter()
+            .map(Ok)
+            .ensure(|i| *i != 5, TestErr::IsFive)
+            .drain_valid_into(&mut sink);
+        assert_eq!(result, Ok(()));
+        assert_eq!(sink, HashSet::from([0, 1, 2]));
+    }
+}
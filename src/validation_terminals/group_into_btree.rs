@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+pub trait GroupIntoBTree<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects a validation iterator into a [`BTreeMap`], grouping
+    /// elements by a key.
+    ///
+    /// `group_into_btree(key_of)` computes a key for each element wrapped in
+    /// `Ok`, and pushes the element into the group of that key. Groups keep the
+    /// order of the iteration, and the map is sorted by key.
+    ///
+    /// The collection short-circuits on the first `Err(error)` in the
+    /// iteration, returning that error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use validiter::GroupIntoBTree;
+    ///
+    /// let groups = ["bb", "a", "cc", "d"]
+    ///     .into_iter()
+    ///     .map(Ok::<_, ()>)
+    ///     .group_into_btree(|s| s.len());
+    ///
+    /// assert_eq!(
+    ///     groups,
+    ///     Ok(BTreeMap::from([(1, vec!["a", "d"]), (2, vec!["bb", "cc"])]))
+    /// );
+    /// ```
+    fn group_into_btree<K, M>(self, key_of: M) -> Result<BTreeMap<K, Vec<T>>, E>
+    where
+        K: Ord,
+        M: Fn(&T) -> K,
+    {
+        let mut groups: BTreeMap<K, Vec<T>> = BTreeMap::new();
+        for item in self {
+            let val = item?;
+            groups.entry(key_of(&val)).or_default().push(val);
+        }
+        Ok(groups)
+    }
+}
+
+impl<I, T, E> GroupIntoBTree<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{Ensure, GroupIntoBTree};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsNegative(usize, i32),
+    }
+
+    #[test]
+    fn test_group_into_btree() {
+        let groups = [5, 3, 8, 1, 6]
+            .into_iter()
+            .map(Ok)
+            .ensure(|i| *i >= 0, TestErr::IsNegative)
+            .group_into_btree(|i| i % 3);
+        assert_eq!(
+            groups,
+            Ok(BTreeMap::from([
+                (0, vec![3, 6]),
+                (1, vec![1]),
+                (2, vec![5, 8])
+            ]))
+        );
+        let keys = groups.unwrap().into_keys().collect::<Vec<_>>();
+        assert_eq!(keys, vec![0, 1, 2])
+    }
+
+    #[test]
+    fn test_group_into_btree_propagates_error() {
+        let groups = [5, -3, 8, -1]
+            .into_iter()
+            .map(Ok)
+            .ensure(|i| *i >= 0, TestErr::IsNegative)
+            .group_into_btree(|i| i % 3);
+        assert_eq!(groups, Err(TestErr::IsNegative(1, -3)))
+    }
+
+    #[test]
+    fn test_group_into_btree_empty() {
+        let groups = (0..0).map(Ok::<i32, TestErr>).group_into_btree(|i| *i);
+        assert_eq!(groups, Ok(BTreeMap::new()))
+    }
+}
@@ -0,0 +1,140 @@
+/// The outcome of draining a validation iterator,
+/// see [`import_summary`](crate::ImportSummarize::import_summary).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSummary<E> {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// The first error in the iteration, and its index.
+    pub first_error: Option<(usize, E)>,
+}
+
+pub trait ImportSummarize<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Consumes a validation iterator, summarizing its results.
+    ///
+    /// `import_summary()` is a shorthand for `import_summary_with(drop)`,
+    /// for when the valid elements are not needed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, ImportSummarize, ImportSummary};
+    ///
+    /// let summary = (0..5)
+    ///     .map(Ok)
+    ///     .ensure(|i| *i != 1 && *i != 3, |_, i| i)
+    ///     .import_summary();
+    ///
+    /// assert_eq!(
+    ///     summary,
+    ///     ImportSummary { total: 5, succeeded: 3, failed: 2, first_error: Some((1, 1)) }
+    /// );
+    /// ```
+    fn import_summary(self) -> ImportSummary<E> {
+        self.import_summary_with(drop)
+    }
+
+    /// Consumes a validation iterator, passing each valid element to a sink
+    /// and summarizing the results.
+    ///
+    /// `import_summary_with(sink)` calls `sink` on every element wrapped in
+    /// `Ok`, and returns an [`ImportSummary`] holding the number of elements,
+    /// how many of them were valid and invalid, and the first error with its
+    /// index. Errors after the first are counted and dropped.
+    ///
+    /// Values are never collected, so memory use does not depend on the
+    /// length of the iteration. This fits bulk imports, where `sink` writes
+    /// the valid elements out as a side effect.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, ImportSummarize};
+    ///
+    /// let mut written = Vec::new();
+    /// let summary = ["1", "x", "3"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<i32>().map_err(|_| s))
+    ///     .import_summary_with(|n| written.push(n));
+    ///
+    /// assert_eq!(written, vec![1, 3]);
+    /// assert_eq!((summary.succeeded, summary.failed), (2, 1));
+    /// assert_eq!(summary.first_error, Some((1, "x")));
+    /// ```
+    fn import_summary_with<F>(self, mut sink: F) -> ImportSummary<E>
+    where
+        F: FnMut(T),
+    {
+        let mut summary = ImportSummary {
+            total: 0,
+            succeeded: 0,
+            failed: 0,
+            first_error: None,
+        };
+        for (i, item) in self.enumerate() {
+            match item {
+                Ok(val) => {
+                    summary.succeeded += 1;
+                    sink(val);
+                }
+                Err(err) => {
+                    summary.failed += 1;
+                    if summary.first_error.is_none() {
+                        summary.first_error = Some((i, err));
+                    }
+                }
+            }
+            summary.total += 1;
+        }
+        summary
+    }
+}
+
+impl<I, T, E> ImportSummarize<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtMost, Ensure, ImportSummarize, ImportSummary};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        TooMany(usize, i32),
+    }
+
+    #[test]
+    fn test_import_summary_mixed_iteration() {
+        let mut written = Vec::new();
+        let summary = (0..10)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0 || *i > 6, TestErr::IsOdd)
+            .at_most(4, TestErr::TooMany)
+            .import_summary_with(|i| written.push(i));
+        assert_eq!(written, vec![0, 2, 4, 6]);
+        assert_eq!(
+            summary,
+            ImportSummary {
+                total: 10,
+                succeeded: 4,
+                failed: 6,
+                first_error: Some((1, TestErr::IsOdd(1, 1)))
+            }
+        )
+    }
+
+    #[test]
+    fn test_import_summary_all_valid() {
+        let summary = (0..3).map(Ok::<_, TestErr>).import_summary();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                total: 3,
+                succeeded: 3,
+                failed: 0,
+                first_error: None
+            }
+        )
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::mpsc::{SendError, Sender};
+
+pub trait SendAll<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Forwards every item of a validation iterator into a channel.
+    ///
+    /// `send_all(sender)` sends each item, whether it is wrapped in `Ok` or
+    /// in `Err`, to the receiving end of `sender`, in order. If the receiver
+    /// was dropped, the iteration stops and the [`SendError`] holding the
+    /// unsent item is returned. Items after it are not consumed.
+    ///
+    /// This allows handing validation results to a consumer running on
+    /// another thread without collecting them first. The channel is closed
+    /// once `sender` is dropped at the end of the call, unless it was cloned.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::{sync::mpsc::channel, thread};
+    /// use validiter::{Ensure, SendAll};
+    ///
+    /// let (sender, receiver) = channel();
+    /// let consumer = thread::spawn(move || receiver.iter().collect::<Vec<_>>());
+    ///
+    /// (0..3)
+    ///     .map(Ok)
+    ///     .ensure(|i| *i != 1, |i, _| i)
+    ///     .send_all(sender)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(consumer.join().unwrap(), vec![Ok(0), Err(1), Ok(2)]);
+    /// ```
+    fn send_all(self, sender: Sender<Result<T, E>>) -> Result<(), SendError<Result<T, E>>> {
+        for item in self {
+            sender.send(item)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, T, E> SendAll<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        sync::mpsc::{channel, SendError},
+        thread,
+    };
+
+    use crate::{Ensure, SendAll};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+    }
+
+    #[test]
+    fn test_send_all_in_order_to_other_thread() {
+        let (sender, receiver) = channel();
+        let consumer = thread::spawn(move || receiver.iter().collect::<Vec<_>>());
+        let sent = (0..100)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .send_all(sender);
+        assert_eq!(sent, Ok(()));
+        let expected = (0..100)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .collect::<Vec<_>>();
+        assert_eq!(consumer.join().unwrap(), expected)
+    }
+
+    #[test]
+    fn test_send_all_disconnected() {
+        let (sender, receiver) = channel();
+        drop(receiver);
+        let pulled = Cell::new(0);
+        let sent = (0..10)
+            .inspect(|_| pulled.set(pulled.get() + 1))
+            .map(Ok::<_, TestErr>)
+            .send_all(sender);
+        assert_eq!(sent, Err(SendError(Ok(0))));
+        assert_eq!(pulled.get(), 1)
+    }
+}
@@ -0,0 +1,79 @@
+pub trait SplitAtFirstError<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects the valid prefix of a validation iterator, and the
+    /// error that ended it.
+    ///
+    /// `split_at_first_error()` consumes elements until the first
+    /// `Err(error)` is found, and returns the elements wrapped in `Ok`
+    /// before it, together with `Some(error)`. If no error is found, the
+    /// whole iteration is collected and `None` is returned instead.
+    ///
+    /// Unlike collecting into a `Result<Vec<T>, E>`, the valid prefix is
+    /// kept on failure, which allows checkpointing of processed elements.
+    /// Elements after the first error are not consumed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, SplitAtFirstError};
+    ///
+    /// let (prefix, err) = (0..10)
+    ///     .map(Ok)
+    ///     .ensure(|i| *i < 3, |index, _| index)
+    ///     .split_at_first_error();
+    ///
+    /// assert_eq!(prefix, vec![0, 1, 2]);
+    /// assert_eq!(err, Some(3));
+    /// ```
+    fn split_at_first_error(self) -> (Vec<T>, Option<E>) {
+        let mut prefix = Vec::new();
+        for item in self {
+            match item {
+                Ok(val) => prefix.push(val),
+                Err(err) => return (prefix, Some(err)),
+            }
+        }
+        (prefix, None)
+    }
+}
+
+impl<I, T, E> SplitAtFirstError<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ensure, SplitAtFirstError};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsFive(usize, i32),
+    }
+
+    #[test]
+    fn test_split_at_first_error_in_the_middle() {
+        let mut source = (0..10).map(Ok).ensure(|i| *i != 5, TestErr::IsFive);
+        let (prefix, err) = source.by_ref().split_at_first_error();
+        assert_eq!(prefix, vec![0, 1, 2, 3, 4]);
+        assert_eq!(err, Some(TestErr::IsFive(5, 5)));
+        assert_eq!(source.next(), Some(Ok(6)));
+    }
+
+    #[test]
+    fn test_split_at_first_error_all_valid() {
+        let (prefix, err) = (0..4)
+            .map(Ok)
+            .ensure(|i| *i != 5, TestErr::IsFive)
+            .split_at_first_error();
+        assert_eq!(prefix, vec![0, 1, 2, 3]);
+        assert_eq!(err, None);
+    }
+
+    #[test]
+    fn test_split_at_first_error_first_element() {
+        let (prefix, err) = (5..7)
+            .map(Ok)
+            .ensure(|i| *i != 5, TestErr::IsFive)
+            .split_at_first_error();
+        assert_eq!(prefix, Vec::<i32>::new());
+        assert_eq!(err, Some(TestErr::IsFive(0, 5)));
+    }
+}
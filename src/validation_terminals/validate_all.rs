@@ -0,0 +1,76 @@
+pub trait ValidateAll<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Consumes a validation iterator, and returns all of its errors
+    /// together with their positions.
+    ///
+    /// `validate_all()` discards every element wrapped in `Ok`, and returns
+    /// `Ok(())` if the iteration had no errors. Otherwise it returns
+    /// `Err(errors)`, where `errors` holds every `(index, error)` pair in the
+    /// order of the iteration.
+    ///
+    /// Unlike collecting into a `Result`, the iteration is never
+    /// short-circuited, so all the errors are reported.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::{Ensure, ValidateAll};
+    ///
+    /// let result = [3, -1, 5, -2]
+    ///     .into_iter()
+    ///     .map(Ok)
+    ///     .ensure(|v| *v >= 0, |_, v| v)
+    ///     .validate_all();
+    ///
+    /// assert_eq!(result, Err(vec![(1, -1), (3, -2)]));
+    /// ```
+    fn validate_all(self) -> Result<(), Vec<(usize, E)>> {
+        let errors = self
+            .enumerate()
+            .filter_map(|(i, item)| item.err().map(|err| (i, err)))
+            .collect::<Vec<_>>();
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
+
+impl<I, T, E> ValidateAll<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AtMost, Ensure, ValidateAll};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        IsOdd(usize, i32),
+        TooMany(usize, i32),
+    }
+
+    #[test]
+    fn test_validate_all_valid() {
+        let result = (0..10)
+            .map(|i| Ok(i * 2))
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .validate_all();
+        assert_eq!(result, Ok(()))
+    }
+
+    #[test]
+    fn test_validate_all_reports_every_error() {
+        let result = (0..5)
+            .map(Ok)
+            .ensure(|i| i % 2 == 0, TestErr::IsOdd)
+            .at_most(2, TestErr::TooMany)
+            .validate_all();
+        assert_eq!(
+            result,
+            Err(vec![
+                (1, TestErr::IsOdd(1, 1)),
+                (3, TestErr::IsOdd(3, 3)),
+                (4, TestErr::TooMany(4, 4))
+            ])
+        )
+    }
+}
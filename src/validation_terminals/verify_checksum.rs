@@ -0,0 +1,124 @@
+pub trait VerifyChecksum<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects an iteration that ends with a check element, and verifies
+    /// the check value against a checksum of the preceding elements.
+    ///
+    /// `verify_checksum(init, fold, extract_check, eq, factory)` folds a
+    /// running checksum, starting from `init`, over every element except the
+    /// last one. The check value is then extracted from the last element with
+    /// `extract_check`, and compared with the checksum using `eq`. If they
+    /// match, the elements preceding the check element are returned.
+    /// Otherwise, `factory` is called on the computed checksum and
+    /// `Some(check_value)`. If the iteration is empty, there is no check
+    /// element, and `factory` is called on `init` and `None`.
+    ///
+    /// The collection short-circuits on the first `Err(error)` in the
+    /// iteration, returning that error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// use validiter::VerifyChecksum;
+    ///
+    /// let record = [3u8, 4, 5, 12];
+    /// let verified = record.into_iter().map(Ok).verify_checksum(
+    ///     0u8,
+    ///     |sum, byte| sum.wrapping_add(*byte),
+    ///     |check| *check,
+    ///     |sum, check| sum == check,
+    ///     |sum, check| format!("checksum {sum} does not match {check:?}"),
+    /// );
+    ///
+    /// assert_eq!(verified, Ok(vec![3, 4, 5]));
+    /// ```
+    fn verify_checksum<S, C, Fold, Check, Eq, Factory>(
+        self,
+        init: S,
+        fold: Fold,
+        extract_check: Check,
+        eq: Eq,
+        factory: Factory,
+    ) -> Result<Vec<T>, E>
+    where
+        Fold: Fn(S, &T) -> S,
+        Check: Fn(&T) -> C,
+        Eq: Fn(&S, &C) -> bool,
+        Factory: Fn(S, Option<C>) -> E,
+    {
+        let mut checksum = init;
+        let mut body = Vec::new();
+        let mut last = None;
+        for item in self {
+            let val = item?;
+            if let Some(prev) = last.replace(val) {
+                checksum = fold(checksum, &prev);
+                body.push(prev);
+            }
+        }
+        match last {
+            Some(check_element) => {
+                let check = extract_check(&check_element);
+                match eq(&checksum, &check) {
+                    true => Ok(body),
+                    false => Err(factory(checksum, Some(check))),
+                }
+            }
+            None => Err(factory(checksum, None)),
+        }
+    }
+}
+
+impl<I, T, E> VerifyChecksum<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ensure, VerifyChecksum};
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        BadChecksum(u32, Option<u32>),
+        IsZero(usize, u32),
+    }
+
+    fn verify(values: impl Iterator<Item = Result<u32, TestErr>>) -> Result<Vec<u32>, TestErr> {
+        values.verify_checksum(
+            0,
+            |sum, v| (sum + v) % 256,
+            |check| *check,
+            |sum, check| sum == check,
+            TestErr::BadChecksum,
+        )
+    }
+
+    #[test]
+    fn test_verify_checksum_correct() {
+        let verified = verify([100, 200, 7, 51].into_iter().map(Ok));
+        assert_eq!(verified, Ok(vec![100, 200, 7]))
+    }
+
+    #[test]
+    fn test_verify_checksum_tampered() {
+        let verified = verify([100, 201, 7, 51].into_iter().map(Ok));
+        assert_eq!(verified, Err(TestErr::BadChecksum(52, Some(51))))
+    }
+
+    #[test]
+    fn test_verify_checksum_bounds() {
+        let verified = verify([0].into_iter().map(Ok));
+        assert_eq!(verified, Ok(vec![]));
+
+        let verified = verify([].into_iter().map(Ok));
+        assert_eq!(verified, Err(TestErr::BadChecksum(0, None)))
+    }
+
+    #[test]
+    fn test_verify_checksum_short_circuits_on_errors() {
+        let verified = verify(
+            [1, 0, 1]
+                .into_iter()
+                .map(Ok)
+                .ensure(|v| *v != 0, TestErr::IsZero),
+        );
+        assert_eq!(verified, Err(TestErr::IsZero(1, 0)))
+    }
+}
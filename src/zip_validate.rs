@@ -0,0 +1,201 @@
+use crate::WithIndexOffset;
+
+#[derive(Debug, Clone)]
+pub struct ZipValidateIter<A, B, T, U, E, F, Factory>
+where
+    A: Iterator<Item = Result<T, E>>,
+    B: Iterator<Item = Result<U, E>>,
+    F: Fn(&T, &U) -> bool,
+    Factory: Fn(usize, Option<T>, Option<U>) -> E,
+{
+    a: A,
+    b: B,
+    index: usize,
+    test: F,
+    factory: Factory,
+}
+
+impl<A, B, T, U, E, F, Factory> Iterator for ZipValidateIter<A, B, T, U, E, F, Factory>
+where
+    A: Iterator<Item = Result<T, E>>,
+    B: Iterator<Item = Result<U, E>>,
+    F: Fn(&T, &U) -> bool,
+    Factory: Fn(usize, Option<T>, Option<U>) -> E,
+{
+    type Item = Result<(T, U), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.index;
+        let item = match (self.a.next(), self.b.next()) {
+            (Some(Ok(a_val)), Some(Ok(b_val))) => match (self.test)(&a_val, &b_val) {
+                true => Ok((a_val, b_val)),
+                false => Err((self.factory)(i, Some(a_val), Some(b_val))),
+            },
+            (Some(Err(err)), _) | (_, Some(Err(err))) => Err(err),
+            (Some(Ok(a_val)), None) => Err((self.factory)(i, Some(a_val), None)),
+            (None, Some(Ok(b_val))) => Err((self.factory)(i, None, Some(b_val))),
+            (None, None) => return None,
+        };
+        self.index += 1;
+        Some(item)
+    }
+}
+
+impl<A, B, T, U, E, F, Factory> WithIndexOffset for ZipValidateIter<A, B, T, U, E, F, Factory>
+where
+    A: Iterator<Item = Result<T, E>>,
+    B: Iterator<Item = Result<U, E>>,
+    F: Fn(&T, &U) -> bool,
+    Factory: Fn(usize, Option<T>, Option<U>) -> E,
+{
+    fn with_index_offset(mut self, offset: usize) -> Self {
+        self.index = offset;
+        self
+    }
+}
+
+/// Validates a relationship between the elements of two iterations,
+/// pairing them by position.
+///
+/// `zip_validate(a, b, test, factory)` is the validating counterpart of
+/// [`Iterator::zip`]. For each pair of elements `(a_i, b_i)` wrapped in
+/// `Ok`, `test(&a_i, &b_i)` is called. If the test passes, the pair is
+/// yielded as `Ok((a_i, b_i))`. Otherwise, `factory` is called on the index
+/// of the pair, `Some(a_i)` and `Some(b_i)`.
+///
+/// Unlike `zip`, the iteration is not truncated when one iteration is
+/// shorter than the other. Instead, each element of the longer iteration
+/// that has no counterpart is reported by calling `factory` with `None` in
+/// place of the missing element.
+///
+/// Errors already in the iterations are yielded in place of the pair.
+/// If both elements of a pair are errors, the error from `a` is yielded,
+/// and the error from `b` is dropped.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// use validiter::zip_validate;
+///
+/// let lower = [0, 5, 2].into_iter().map(Ok);
+/// let upper = [1, 4].into_iter().map(Ok);
+/// let mut iter = zip_validate(lower, upper, |l, u| l < u, |i, l, u| (i, l, u));
+///
+/// assert_eq!(iter.next(), Some(Ok((0, 1))));
+/// assert_eq!(iter.next(), Some(Err((1, Some(5), Some(4)))));
+/// assert_eq!(iter.next(), Some(Err((2, Some(2), None))));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn zip_validate<A, B, T, U, E, F, Factory>(
+    a: A,
+    b: B,
+    test: F,
+    factory: Factory,
+) -> ZipValidateIter<A::IntoIter, B::IntoIter, T, U, E, F, Factory>
+where
+    A: IntoIterator<Item = Result<T, E>>,
+    B: IntoIterator<Item = Result<U, E>>,
+    F: Fn(&T, &U) -> bool,
+    Factory: Fn(usize, Option<T>, Option<U>) -> E,
+{
+    ZipValidateIter {
+        a: a.into_iter(),
+        b: b.into_iter(),
+        index: 0,
+        test,
+        factory,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::zip_validate;
+
+    #[derive(Debug, PartialEq)]
+    enum TestErr {
+        Mismatch(usize, Option<i32>, Option<i32>),
+        Other(i32),
+    }
+
+    #[test]
+    fn test_zip_validate_matched_pairs() {
+        let results = zip_validate(
+            (0..3).map(Ok),
+            (1..4).map(Ok),
+            |a, b| a < b,
+            TestErr::Mismatch,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(results, vec![Ok((0, 1)), Ok((1, 2)), Ok((2, 3))])
+    }
+
+    #[test]
+    fn test_zip_validate_mismatched_pairs() {
+        let results = zip_validate(
+            [0, 5, 2].map(Ok),
+            [1, 2, 3].map(Ok),
+            |a, b| a < b,
+            TestErr::Mismatch,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((0, 1)),
+                Err(TestErr::Mismatch(1, Some(5), Some(2))),
+                Ok((2, 3))
+            ]
+        )
+    }
+
+    #[test]
+    fn test_zip_validate_length_mismatch() {
+        let results = zip_validate(
+            (0..1).map(Ok),
+            (1..4).map(Ok),
+            |a, b| a < b,
+            TestErr::Mismatch,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Ok((0, 1)),
+                Err(TestErr::Mismatch(1, None, Some(2))),
+                Err(TestErr::Mismatch(2, None, Some(3)))
+            ]
+        );
+
+        let results = zip_validate(
+            (0..2).map(Ok),
+            (1..2).map(Ok),
+            |a, b| a < b,
+            TestErr::Mismatch,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![Ok((0, 1)), Err(TestErr::Mismatch(1, Some(1), None))]
+        )
+    }
+
+    #[test]
+    fn test_zip_validate_passes_errors() {
+        let results = zip_validate(
+            [Err(TestErr::Other(0)), Ok(1), Err(TestErr::Other(2))],
+            [Ok(0), Err(TestErr::Other(1)), Err(TestErr::Other(3))],
+            |a, b| a < b,
+            TestErr::Mismatch,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            vec![
+                Err(TestErr::Other(0)),
+                Err(TestErr::Other(1)),
+                Err(TestErr::Other(2))
+            ]
+        )
+    }
+}